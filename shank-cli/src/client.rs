@@ -0,0 +1,77 @@
+use std::fs;
+
+use anyhow::{format_err, Result};
+use clap::{Args, Parser};
+use log::info;
+use shank_client::{
+    python::generate_python_client, rust::generate_rust_client,
+    ts::generate_ts_client, write_generated_files,
+};
+use shank_idl::idl::Idl;
+
+use crate::try_resolve_path;
+
+#[derive(Debug, Parser)]
+pub enum ClientCommand {
+    /// Generates a standalone Rust client crate.
+    Rust(ClientOpts),
+    /// Generates a TypeScript client package.
+    Ts(ClientOpts),
+    /// Generates a Python client package.
+    Python(ClientOpts),
+}
+
+#[derive(Debug, Args)]
+pub struct ClientOpts {
+    /// Path of the IDL JSON to generate the client from.
+    #[clap(long)]
+    idl: String,
+
+    /// Output directory for the client.
+    #[clap(short, long)]
+    out: String,
+
+    /// Manually specify and override the address in the IDL
+    #[clap(short = 'p', long)]
+    program_id: Option<String>,
+}
+
+pub fn client(command: ClientCommand) -> Result<()> {
+    match command {
+        ClientCommand::Rust(opts) => {
+            let idl = read_idl(&opts)?;
+            let files = generate_rust_client(&idl)?;
+            let out_dir = try_resolve_path(Some(opts.out), "out")?;
+            info!("Writing Rust client to {}", out_dir.display());
+            write_generated_files(&out_dir, &files)
+        }
+        ClientCommand::Ts(opts) => {
+            let idl = read_idl(&opts)?;
+            let files = generate_ts_client(&idl)?;
+            let out_dir = try_resolve_path(Some(opts.out), "out")?;
+            info!("Writing TypeScript client to {}", out_dir.display());
+            write_generated_files(&out_dir, &files)
+        }
+        ClientCommand::Python(opts) => {
+            let idl = read_idl(&opts)?;
+            let files = generate_python_client(&idl)?;
+            let out_dir = try_resolve_path(Some(opts.out), "out")?;
+            info!("Writing Python client to {}", out_dir.display());
+            write_generated_files(&out_dir, &files)
+        }
+    }
+}
+
+fn read_idl(opts: &ClientOpts) -> Result<Idl> {
+    let idl_path = try_resolve_path(Some(opts.idl.clone()), "idl")?;
+    let idl_json = fs::read_to_string(&idl_path).map_err(|err| {
+        format_err!("Unable to read IDL ({}), {}", idl_path.display(), err)
+    })?;
+    let mut idl: Idl = serde_json::from_str(&idl_json).map_err(|err| {
+        format_err!("Unable to parse IDL ({}), {}", idl_path.display(), err)
+    })?;
+    if let Some(program_id) = &opts.program_id {
+        idl.metadata.address = Some(program_id.to_string());
+    }
+    Ok(idl)
+}
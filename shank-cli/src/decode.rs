@@ -0,0 +1,267 @@
+use std::{fs, path::Path, str::FromStr};
+
+use anyhow::{anyhow, format_err, Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use clap::{Args, Parser};
+use serde_json::Value;
+use shank_idl::{
+    codec::{account_layout, decode_account, decode_instruction},
+    idl::Idl,
+};
+
+use crate::try_resolve_path;
+
+#[derive(Debug, Parser)]
+pub enum DecodeCommand {
+    /// Decodes instruction data into JSON.
+    Ix(DecodeIxOpts),
+    /// Decodes account data into JSON.
+    Account(DecodeAccountOpts),
+}
+
+#[derive(Debug, Args)]
+pub struct DecodeIxOpts {
+    /// Path of the IDL JSON of the program the instruction belongs to.
+    #[clap(long)]
+    idl: String,
+
+    /// Encoding of the data, one of hex, base58 or base64
+    /// [default: detected in that order]
+    #[clap(short, long)]
+    encoding: Option<Encoding>,
+
+    /// The instruction data.
+    data: String,
+}
+
+#[derive(Debug, Args)]
+pub struct DecodeAccountOpts {
+    /// Path of the IDL JSON of the program owning the account.
+    #[clap(long)]
+    idl: String,
+
+    /// Name of the account in the IDL [default: detected via the
+    /// discriminator of the account]
+    #[clap(short = 't', long = "type", value_name = "TYPE")]
+    ty: Option<String>,
+
+    /// Encoding of the data, one of hex, base58 or base64
+    /// [default: detected in that order]
+    #[clap(short, long)]
+    encoding: Option<Encoding>,
+
+    /// Print the offset and size of each field instead of the decoded account.
+    #[clap(long)]
+    layout: bool,
+
+    /// The account data or the path of a file holding it, either the raw
+    /// data or the JSON written by `solana account --output json`.
+    data: String,
+}
+
+/// The encodings in which the data to decode can be provided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Hex,
+    Base58,
+    Base64,
+}
+
+impl Encoding {
+    const ALL: [Encoding; 3] = [Self::Hex, Self::Base58, Self::Base64];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Hex => "hex",
+            Self::Base58 => "base58",
+            Self::Base64 => "base64",
+        }
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>> {
+        let bytes = match self {
+            Self::Hex => {
+                let data = data.strip_prefix("0x").unwrap_or(data);
+                hex::decode(data).map_err(Error::new)
+            }
+            Self::Base58 => bs58::decode(data).into_vec().map_err(Error::new),
+            Self::Base64 => STANDARD.decode(data).map_err(Error::new),
+        };
+        bytes.map_err(|err| {
+            format_err!("Unable to decode the data as {}, {}", self.name(), err)
+        })
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .iter()
+            .find(|encoding| encoding.name() == s)
+            .copied()
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unknown encoding '{}', valid encodings are: hex, base58, base64",
+                    s
+                )
+            })
+    }
+}
+
+/// Decodes the data with the provided encoding or with the first one of hex,
+/// base58 and base64 that the data is valid in.
+fn decode_data(data: &str, encoding: Option<Encoding>) -> Result<Vec<u8>> {
+    let data = data.trim();
+    match encoding {
+        Some(encoding) => encoding.decode(data),
+        None => Encoding::ALL
+            .iter()
+            .find_map(|encoding| encoding.decode(data).ok())
+            .ok_or_else(|| {
+                anyhow!("Unable to decode the data as hex, base58 or base64")
+            }),
+    }
+}
+
+/// Extracts the account data from the JSON written by
+/// `solana account --output json`.
+fn account_dump_data(dump: &Value) -> Result<Vec<u8>> {
+    let data = dump
+        .get("account")
+        .unwrap_or(dump)
+        .get("data")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("The account JSON has no data"))?;
+    match (
+        data.first().and_then(Value::as_str),
+        data.get(1).and_then(Value::as_str),
+    ) {
+        (Some(data), Some("base64")) => Encoding::Base64.decode(data),
+        (Some(data), Some("base58")) => Encoding::Base58.decode(data),
+        (_, Some(encoding)) => Err(anyhow!(
+            "Unsupported account data encoding '{}', use base64 instead",
+            encoding
+        )),
+        _ => Err(anyhow!("The account JSON has no data")),
+    }
+}
+
+/// Reads the account data from the file at the provided path if it exists
+/// and otherwise decodes the data itself.
+fn read_account_data(
+    data: &str,
+    encoding: Option<Encoding>,
+) -> Result<Vec<u8>> {
+    let path = Path::new(data);
+    if !path.is_file() {
+        return decode_data(data, encoding);
+    }
+    let bytes = fs::read(path).map_err(|err| {
+        format_err!("Unable to read account data ({}), {}", data, err)
+    })?;
+    match serde_json::from_slice::<Value>(&bytes) {
+        Ok(dump) => account_dump_data(&dump),
+        Err(_) => Ok(bytes),
+    }
+}
+
+pub fn decode(command: DecodeCommand) -> Result<()> {
+    match command {
+        DecodeCommand::Ix(opts) => {
+            let idl = read_idl(&opts.idl)?;
+            let data = decode_data(&opts.data, opts.encoding)?;
+            let decoded = decode_instruction(&idl, &data)?;
+            println!("{}", serde_json::to_string_pretty(&decoded)?);
+            Ok(())
+        }
+        DecodeCommand::Account(opts) => {
+            let idl = read_idl(&opts.idl)?;
+            let data = read_account_data(&opts.data, opts.encoding)?;
+            if opts.layout {
+                let (name, fields) =
+                    account_layout(&idl, opts.ty.as_deref(), &data)?;
+                println!("{} ({} bytes)", name, data.len());
+                println!("{:<8}{:<8}FIELD", "OFFSET", "SIZE");
+                for field in &fields {
+                    println!(
+                        "{:<8}{:<8}{}{}",
+                        field.offset,
+                        field.size,
+                        field.name,
+                        if field.padding { " (padding)" } else { "" }
+                    );
+                }
+                let end = fields
+                    .iter()
+                    .map(|field| field.offset + field.size)
+                    .max()
+                    .unwrap_or(0);
+                if end < data.len() {
+                    println!("{} unused trailing bytes", data.len() - end);
+                }
+            } else {
+                let decoded = decode_account(&idl, opts.ty.as_deref(), &data)?;
+                println!("{}", serde_json::to_string_pretty(&decoded)?);
+            }
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn read_idl(idl: &str) -> Result<Idl> {
+    let idl_path = try_resolve_path(Some(idl.to_string()), "idl")?;
+    let idl_json = fs::read_to_string(&idl_path).map_err(|err| {
+        format_err!("Unable to read IDL ({}), {}", idl_path.display(), err)
+    })?;
+    serde_json::from_str(&idl_json).map_err(|err| {
+        format_err!("Unable to parse IDL ({}), {}", idl_path.display(), err)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_data_detects_encoding() {
+        assert_eq!(decode_data("0x01ff", None).unwrap(), vec![1, 255]);
+        assert_eq!(decode_data("01ff", None).unwrap(), vec![1, 255]);
+        // Not valid hex due to its odd length
+        assert_eq!(decode_data("2", None).unwrap(), vec![1]);
+        assert_eq!(decode_data("Af8=", None).unwrap(), vec![1, 255]);
+        assert!(decode_data("not encoded!", None).is_err());
+    }
+
+    #[test]
+    fn decode_data_with_encoding() {
+        assert_eq!(
+            decode_data("01ff", Some(Encoding::Base64)).unwrap(),
+            vec![211, 87, 223]
+        );
+        assert!(decode_data("0l", Some(Encoding::Base58)).is_err());
+    }
+
+    #[test]
+    fn account_dump_data_from_solana_cli_json() {
+        let dump = serde_json::json!({
+            "pubkey": "11111111111111111111111111111111",
+            "account": {
+                "lamports": 1,
+                "data": ["Af8=", "base64"],
+                "owner": "11111111111111111111111111111111",
+                "executable": false,
+                "rentEpoch": 0,
+                "space": 2
+            }
+        });
+        assert_eq!(account_dump_data(&dump).unwrap(), vec![1, 255]);
+
+        let dump = serde_json::json!({ "data": ["Af8=", "base64+zstd"] });
+        assert_eq!(
+            account_dump_data(&dump).unwrap_err().to_string(),
+            "Unsupported account data encoding 'base64+zstd', use base64 instead"
+        );
+    }
+}
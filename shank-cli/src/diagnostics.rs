@@ -0,0 +1,77 @@
+use std::{fmt::Write, fs, path::Path};
+
+use shank_idl::diagnostics::{Diagnostic, Diagnostics, SourceLocation};
+
+/// Renders the diagnostic similar to how rustc reports errors, including the
+/// offending line of code if the source file can be read.
+pub fn render_diagnostic(diagnostic: &Diagnostic) -> String {
+    render_labeled_diagnostic("error", diagnostic)
+}
+
+/// Renders the diagnostic with the given label, i.e. `warning[some-lint]`.
+pub fn render_labeled_diagnostic(
+    label: &str,
+    diagnostic: &Diagnostic,
+) -> String {
+    let mut out = format!("{}: {}\n", label, diagnostic.message);
+    if let Some(location) = &diagnostic.location {
+        let source = fs::read_to_string(&location.file).ok();
+        render_location(&mut out, location, source.as_deref());
+    }
+    out
+}
+
+/// Renders all diagnostics separated by an empty line.
+pub fn render_diagnostics(diagnostics: &Diagnostics) -> String {
+    diagnostics
+        .iter()
+        .map(render_diagnostic)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn render_location(
+    out: &mut String,
+    location: &SourceLocation,
+    source: Option<&str>,
+) {
+    let line = source.and_then(|x| x.lines().nth(location.line - 1));
+    let gutter = " ".repeat(location.line.to_string().len());
+    let located = SourceLocation {
+        file: relative_to_cwd(&location.file),
+        ..location.clone()
+    };
+
+    writeln!(out, "{}--> {}", gutter, located).unwrap();
+    let line = match line {
+        Some(line) => line,
+        None => return,
+    };
+
+    // Multi line spans are underlined until the end of the first line
+    let line_len = line.chars().count();
+    let end_column = if location.end_line == location.line {
+        location.end_column.min(line_len)
+    } else {
+        line_len
+    };
+    let underline_len = end_column.saturating_sub(location.column).max(1);
+
+    writeln!(out, "{} |", gutter).unwrap();
+    writeln!(out, "{} | {}", location.line, line).unwrap();
+    writeln!(
+        out,
+        "{} | {}{}",
+        gutter,
+        " ".repeat(location.column),
+        "^".repeat(underline_len)
+    )
+    .unwrap();
+}
+
+fn relative_to_cwd(file: &Path) -> std::path::PathBuf {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| file.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| file.to_path_buf())
+}
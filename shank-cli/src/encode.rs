@@ -0,0 +1,130 @@
+use std::{fs, path::Path, str::FromStr};
+
+use anyhow::{anyhow, format_err, Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use clap::{Args, Parser};
+use serde_json::{json, Value};
+use shank_idl::codec::encode_instruction;
+
+use crate::decode::read_idl;
+
+#[derive(Debug, Parser)]
+pub enum EncodeCommand {
+    /// Encodes an instruction from its args and accounts provided as JSON.
+    Ix(EncodeIxOpts),
+}
+
+#[derive(Debug, Args)]
+pub struct EncodeIxOpts {
+    /// Path of the IDL JSON of the program the instruction belongs to.
+    #[clap(long)]
+    idl: String,
+
+    /// Name of the instruction in the IDL.
+    #[clap(short, long)]
+    name: String,
+
+    /// JSON object of the instruction args keyed by their names or the path
+    /// of a file holding it.
+    #[clap(long)]
+    args: Option<String>,
+
+    /// JSON object of the account pubkeys keyed by their names or the path of
+    /// a file holding it.
+    #[clap(long)]
+    accounts: Option<String>,
+
+    /// Manually specify and override the address in the IDL
+    #[clap(short = 'p', long)]
+    program_id: Option<String>,
+
+    /// Output format, either json or base64
+    #[clap(short, long, default_value = "json")]
+    format: OutputFormat,
+}
+
+/// How the encoded instruction is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The program id, account metas and base64 encoded data as JSON.
+    Json,
+    /// The base64 encoded Borsh serialized instruction.
+    Base64,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "json" => Ok(Self::Json),
+            "base64" => Ok(Self::Base64),
+            _ => Err(anyhow!(
+                "Unknown output format '{}', valid formats are: json, base64",
+                s
+            )),
+        }
+    }
+}
+
+/// Parses the JSON provided inline or in the file at the provided path.
+fn read_json(json: Option<&str>, label: &str) -> Result<Value> {
+    let json = match json {
+        Some(json) => json,
+        None => return Ok(Value::Null),
+    };
+    let path = Path::new(json);
+    if path.is_file() {
+        let contents = fs::read_to_string(path).map_err(|err| {
+            format_err!("Unable to read {} ({}), {}", label, json, err)
+        })?;
+        serde_json::from_str(&contents).map_err(|err| {
+            format_err!("Unable to parse {} ({}), {}", label, json, err)
+        })
+    } else {
+        serde_json::from_str(json)
+            .map_err(|err| format_err!("Unable to parse {}, {}", label, err))
+    }
+}
+
+pub fn encode(command: EncodeCommand) -> Result<()> {
+    match command {
+        EncodeCommand::Ix(opts) => {
+            let idl = read_idl(&opts.idl)?;
+            let args = read_json(opts.args.as_deref(), "args")?;
+            let accounts = read_json(opts.accounts.as_deref(), "accounts")?;
+            let ix = encode_instruction(
+                &idl,
+                &opts.name,
+                &args,
+                &accounts,
+                opts.program_id.as_deref(),
+            )?;
+            match opts.format {
+                OutputFormat::Json => {
+                    let accounts = ix
+                        .accounts
+                        .iter()
+                        .map(|meta| {
+                            json!({
+                                "pubkey": bs58::encode(meta.pubkey).into_string(),
+                                "isSigner": meta.is_signer,
+                                "isWritable": meta.is_writable,
+                            })
+                        })
+                        .collect::<Vec<Value>>();
+                    let ix = json!({
+                        "programId": bs58::encode(ix.program_id).into_string(),
+                        "accounts": accounts,
+                        "data": STANDARD.encode(&ix.data),
+                    });
+                    println!("{}", serde_json::to_string_pretty(&ix)?);
+                }
+                OutputFormat::Base64 => {
+                    println!("{}", STANDARD.encode(ix.to_bytes()))
+                }
+            }
+            Ok(())
+        }
+    }
+}
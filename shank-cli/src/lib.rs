@@ -6,8 +6,28 @@ use std::{
 
 use anyhow::{anyhow, format_err, Result};
 use clap::Parser;
-use log::{debug, info};
-use shank_idl::{extract_idl, manifest::Manifest, ParseIdlOpts};
+use log::{debug, info, warn};
+use shank_idl::{
+    diagnostics::find_diagnostics,
+    extract_idl_partial,
+    lint::{LintConfig, LintLevel, LintRule},
+    manifest::Manifest,
+    ParseIdlOpts, PartialIdl,
+};
+
+mod client;
+mod decode;
+mod diagnostics;
+mod encode;
+mod lint;
+mod logs;
+mod pda;
+
+pub use client::{ClientCommand, ClientOpts};
+pub use decode::{DecodeAccountOpts, DecodeCommand, DecodeIxOpts, Encoding};
+pub use encode::{EncodeCommand, EncodeIxOpts, OutputFormat};
+pub use logs::{AnnotateOpts, ErrorsOpts, LogsCommand};
+pub use pda::PdaOpts;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -37,7 +57,59 @@ pub enum Command {
         /// Manually specify and override the address in the IDL
         #[clap(short = 'p', long)]
         program_id: Option<String>,
+
+        /// Write the IDL for all items that could be extracted even if others
+        /// failed to extract.
+        #[clap(long)]
+        allow_partial: bool,
+    },
+    /// Checks the shank annotations of the program crate for problems.
+    /// Exits with a non-zero code if any denied lints are found.
+    Lint {
+        /// Directory of program crate to lint.
+        #[clap(short = 'r', long)]
+        crate_root: Option<String>,
+
+        /// Allow the given lint, can be repeated.
+        #[clap(short = 'A', long = "allow", value_name = "LINT")]
+        allow: Vec<LintRule>,
+
+        /// Warn about the given lint, can be repeated.
+        #[clap(short = 'W', long = "warn", value_name = "LINT")]
+        warn: Vec<LintRule>,
+
+        /// Fail on the given lint, can be repeated.
+        #[clap(short = 'D', long = "deny", value_name = "LINT")]
+        deny: Vec<LintRule>,
+
+        /// Fail if any warnings are reported as well.
+        #[clap(long)]
+        deny_warnings: bool,
+    },
+    /// Generates a client for the program from its IDL.
+    Client {
+        #[clap(subcommand)]
+        target: ClientCommand,
     },
+    /// Decodes program data into JSON using the program's IDL.
+    Decode {
+        #[clap(subcommand)]
+        target: DecodeCommand,
+    },
+    /// Encodes program data from JSON using the program's IDL.
+    Encode {
+        #[clap(subcommand)]
+        target: EncodeCommand,
+    },
+    /// Resolves program error codes to their names and messages.
+    Errors(ErrorsOpts),
+    /// Works with transaction logs using the program's IDL.
+    Logs {
+        #[clap(subcommand)]
+        target: LogsCommand,
+    },
+    /// Derives the PDA of an account from the #[seeds] in the program's IDL.
+    Pda(PdaOpts),
 }
 
 pub fn entry(opts: Opts) -> Result<()> {
@@ -47,7 +119,33 @@ pub fn entry(opts: Opts) -> Result<()> {
             out_filename,
             crate_root,
             program_id,
-        } => idl(out_dir, out_filename, crate_root, program_id),
+            allow_partial,
+        } => idl(out_dir, out_filename, crate_root, program_id, allow_partial),
+        Command::Lint {
+            crate_root,
+            allow,
+            warn,
+            deny,
+            deny_warnings,
+        } => {
+            let mut config = LintConfig::default();
+            for (rules, level) in [
+                (allow, LintLevel::Allow),
+                (warn, LintLevel::Warn),
+                (deny, LintLevel::Deny),
+            ] {
+                for rule in rules {
+                    config.set_level(rule, level);
+                }
+            }
+            lint::lint(crate_root, &config, deny_warnings)
+        }
+        Command::Client { target } => client::client(target),
+        Command::Decode { target } => decode::decode(target),
+        Command::Encode { target } => encode::encode(target),
+        Command::Errors(opts) => logs::errors(opts),
+        Command::Logs { target } => logs::logs(target),
+        Command::Pda(opts) => pda::pda(opts),
     }
 }
 
@@ -70,11 +168,34 @@ pub fn try_resolve_path(p: Option<String>, label: &str) -> Result<PathBuf> {
     Ok(p)
 }
 
+/// Resolves the manifest of the program crate and the full path of its lib.
+pub fn resolve_lib(crate_root: &Path) -> Result<(Manifest, String)> {
+    let cargo_toml = crate_root.join("Cargo.toml");
+    if !cargo_toml.exists() {
+        return Err(anyhow!(
+            "Did not find Cargo.toml at the path: {}",
+            crate_root.display()
+        ));
+    }
+    let manifest = Manifest::from_path(&cargo_toml)?;
+    let lib_rel_path = manifest
+        .lib_rel_path()
+        .ok_or(anyhow!("Program needs to be a lib"))?;
+
+    let lib_full_path_str = crate_root.join(lib_rel_path);
+    let lib_full_path = lib_full_path_str
+        .to_str()
+        .ok_or(anyhow!("Invalid Path"))?
+        .to_string();
+    Ok((manifest, lib_full_path))
+}
+
 pub fn idl(
     out_dir: String,
     out_filename: Option<String>,
     crate_root: Option<String>,
     program_id: Option<String>,
+    allow_partial: bool,
 ) -> Result<()> {
     // Resolve input and output directories
     let crate_root = try_resolve_path(crate_root, "crate_root")?;
@@ -88,29 +209,31 @@ pub fn idl(
     })?;
 
     // Resolve info about lib for which we generate IDL
-    let cargo_toml = crate_root.join("Cargo.toml");
-    if !cargo_toml.exists() {
-        return Err(anyhow!(
-            "Did not find Cargo.toml at the path: {}",
-            crate_root.display()
-        ));
-    }
-    let manifest = Manifest::from_path(&cargo_toml)?;
-    let lib_rel_path = manifest
-        .lib_rel_path()
-        .ok_or(anyhow!("Program needs to be a lib"))?;
-
-    let lib_full_path_str = crate_root.join(lib_rel_path);
-    let lib_full_path =
-        lib_full_path_str.to_str().ok_or(anyhow!("Invalid Path"))?;
+    let (manifest, lib_full_path) = resolve_lib(&crate_root)?;
 
     // Extract IDL and convert to JSON
     let opts = ParseIdlOpts {
         program_address_override: program_id,
         ..ParseIdlOpts::default()
     };
-    let idl = extract_idl(lib_full_path, opts)?
-        .ok_or(anyhow!("No IDL could be extracted"))?;
+    let PartialIdl { idl, diagnostics } =
+        extract_idl_partial(&lib_full_path, opts)
+            .map_err(report_diagnostics)?;
+    if !diagnostics.is_empty() {
+        eprintln!("{}", diagnostics::render_diagnostics(&diagnostics));
+        if allow_partial {
+            warn!(
+                "Writing partial IDL which is missing items due to {}",
+                error_count(diagnostics.len())
+            );
+        } else {
+            return Err(format_err!(
+                "Could not extract IDL due to {}",
+                error_count(diagnostics.len())
+            ));
+        }
+    }
+    let idl = idl.ok_or(anyhow!("No IDL could be extracted"))?;
     let idl_json = idl.try_into_json()?;
 
     // Write to JSON file
@@ -127,3 +250,22 @@ pub fn idl(
 
     Ok(())
 }
+
+/// Prints located diagnostics for the errors that caused parsing the program
+/// crate to fail and replaces them with a summary error.
+fn report_diagnostics(err: anyhow::Error) -> anyhow::Error {
+    match find_diagnostics(&err) {
+        Some(found) if !found.is_empty() => {
+            eprintln!("{}", diagnostics::render_diagnostics(found));
+            format_err!("Aborting due to {}", error_count(found.len()))
+        }
+        _ => err,
+    }
+}
+
+fn error_count(count: usize) -> String {
+    match count {
+        1 => "1 previous error".to_string(),
+        count => format!("{} previous errors", count),
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::{format_err, Result};
+use log::info;
+use shank_idl::lint::{lint_file, LintConfig, LintDiagnostic, LintLevel};
+
+use crate::{
+    diagnostics::render_labeled_diagnostic, report_diagnostics, resolve_lib,
+    try_resolve_path,
+};
+
+pub fn lint(
+    crate_root: Option<String>,
+    config: &LintConfig,
+    deny_warnings: bool,
+) -> Result<()> {
+    let crate_root = try_resolve_path(crate_root, "crate_root")?;
+    let (_, lib_full_path) = resolve_lib(&crate_root)?;
+
+    let found =
+        lint_file(&lib_full_path, config).map_err(report_diagnostics)?;
+
+    let rendered = found
+        .iter()
+        .map(
+            |LintDiagnostic {
+                 rule,
+                 level,
+                 diagnostic,
+             }| {
+                let label = match level {
+                    LintLevel::Deny => format!("error[{}]", rule),
+                    _ => format!("warning[{}]", rule),
+                };
+                render_labeled_diagnostic(&label, diagnostic)
+            },
+        )
+        .collect::<Vec<String>>();
+    if !rendered.is_empty() {
+        eprintln!("{}", rendered.join("\n"));
+    }
+
+    let errors = found.iter().filter(|x| x.level == LintLevel::Deny).count();
+    let warnings = found.len() - errors;
+    if errors > 0 || (deny_warnings && warnings > 0) {
+        return Err(format_err!(
+            "Lint failed with {} error(s) and {} warning(s)",
+            errors,
+            warnings
+        ));
+    }
+
+    info!("Lint passed with {} warning(s)", warnings);
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::{
+    fs,
+    io::{self, Read},
+};
+
+use anyhow::{anyhow, format_err, Result};
+use clap::{Args, Parser};
+use shank_idl::logs::{
+    annotate_logs, describe_error, find_error, parse_error_code,
+};
+
+use crate::decode::read_idl;
+
+#[derive(Debug, Args)]
+pub struct ErrorsOpts {
+    /// Path of the IDL JSON of the program.
+    #[clap(long)]
+    idl: String,
+
+    /// Error codes to resolve, either hex prefixed with 0x or decimal
+    /// [default: all errors of the IDL]
+    codes: Vec<String>,
+}
+
+#[derive(Debug, Parser)]
+pub enum LogsCommand {
+    /// Annotates program errors and events in transaction logs.
+    Annotate(AnnotateOpts),
+}
+
+#[derive(Debug, Args)]
+pub struct AnnotateOpts {
+    /// Path of the IDL JSON of the program.
+    #[clap(long)]
+    idl: String,
+
+    /// Manually specify and override the address in the IDL
+    #[clap(short = 'p', long)]
+    program_id: Option<String>,
+
+    /// Path of the file holding the logs [default: read from stdin]
+    file: Option<String>,
+}
+
+pub fn errors(opts: ErrorsOpts) -> Result<()> {
+    let idl = read_idl(&opts.idl)?;
+    if opts.codes.is_empty() {
+        for error in idl.errors.as_deref().unwrap_or_default() {
+            println!(
+                "0x{:x} ({}) {}",
+                error.code,
+                error.code,
+                describe_error(error)
+            );
+        }
+        return Ok(());
+    }
+
+    for code in &opts.codes {
+        let code = parse_error_code(code)?;
+        let error = find_error(&idl, code).ok_or_else(|| {
+            anyhow!(
+                "No error with code 0x{:x} ({}) found in the IDL",
+                code,
+                code
+            )
+        })?;
+        println!("0x{:x} ({}) {}", code, code, describe_error(error));
+    }
+    Ok(())
+}
+
+pub fn logs(command: LogsCommand) -> Result<()> {
+    match command {
+        LogsCommand::Annotate(opts) => {
+            let idl = read_idl(&opts.idl)?;
+            let logs = match &opts.file {
+                Some(file) => fs::read_to_string(file).map_err(|err| {
+                    format_err!("Unable to read logs ({}), {}", file, err)
+                })?,
+                None => {
+                    let mut logs = String::new();
+                    io::stdin().read_to_string(&mut logs)?;
+                    logs
+                }
+            };
+            println!(
+                "{}",
+                annotate_logs(&idl, opts.program_id.as_deref(), &logs)
+            );
+            Ok(())
+        }
+    }
+}
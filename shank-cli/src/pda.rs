@@ -0,0 +1,82 @@
+use anyhow::{format_err, Result};
+use clap::Args;
+use serde_json::json;
+use shank_idl::pda::derive_account_pda;
+
+use crate::decode::read_idl;
+
+#[derive(Debug, Args)]
+pub struct PdaOpts {
+    /// Path of the IDL JSON of the program.
+    #[clap(long)]
+    idl: String,
+
+    /// Name of the account whose #[seeds] derive the PDA.
+    #[clap(short, long)]
+    account: String,
+
+    /// Value of a seed param as name=value, i.e. mint=<pubkey>.
+    #[clap(short, long = "seed", value_name = "NAME=VALUE")]
+    seeds: Vec<String>,
+
+    /// Manually specify and override the address in the IDL
+    #[clap(short = 'p', long)]
+    program_id: Option<String>,
+}
+
+fn parse_seed(seed: &str) -> Result<(String, String)> {
+    seed.split_once('=')
+        .map(|(name, value)| (name.trim().to_string(), value.to_string()))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| {
+            format_err!("Invalid seed '{}', expected name=value", seed)
+        })
+}
+
+pub fn pda(opts: PdaOpts) -> Result<()> {
+    let idl = read_idl(&opts.idl)?;
+    let values = opts
+        .seeds
+        .iter()
+        .map(|seed| parse_seed(seed))
+        .collect::<Result<Vec<_>>>()?;
+
+    let pda = derive_account_pda(
+        &idl,
+        &opts.account,
+        &values,
+        opts.program_id.as_deref(),
+    )?;
+    let json = json!({
+        "address": bs58::encode(pda.address).into_string(),
+        "bump": pda.bump,
+    });
+    println!("{}", serde_json::to_string_pretty(&json)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_seed_values() {
+        assert_eq!(
+            parse_seed("mint=So11111111111111111111111111111111111111112")
+                .unwrap(),
+            (
+                "mint".to_string(),
+                "So11111111111111111111111111111111111111112".to_string()
+            )
+        );
+        assert_eq!(
+            parse_seed("name=a=b").unwrap(),
+            ("name".to_string(), "a=b".to_string())
+        );
+        assert_eq!(
+            parse_seed("mint").unwrap_err().to_string(),
+            "Invalid seed 'mint', expected name=value"
+        );
+        assert!(parse_seed("=value").is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use heck::SnakeCase;
+use shank_idl::{
+    idl::Idl,
+    idl_field::IdlField,
+    idl_instruction::{IdlAccount, IdlAccountItem},
+    idl_type::IdlType,
+    idl_type_definition::{IdlSerialization, IdlTypeDefinitionTy},
+};
+use shank_macro_impl::well_known::well_known_account_named;
+
+/// Returns the address the account defaults to, either its fixed address or
+/// the one of the well known account with the same name.
+pub(crate) fn default_address(account: &IdlAccount) -> Option<&str> {
+    if let Some(address) = &account.address {
+        return Some(address);
+    }
+    let snake_case_name = account.name.to_snake_case();
+    well_known_account_named(&snake_case_name).map(|account| account.address)
+}
+
+/// Returns the program address of the IDL which clients need to target the
+/// program.
+pub(crate) fn program_address(idl: &Idl) -> Result<&str> {
+    idl.metadata.address.as_deref().ok_or_else(|| {
+        anyhow!(
+            "The IDL has no program address, which the client needs to declare the program id.\n\
+             Provide it via the program id option or set metadata.address in the IDL."
+        )
+    })
+}
+
+/// Flattens nested account groups into the accounts in the order they are
+/// passed to the instruction.
+pub(crate) fn flatten_accounts(items: &[IdlAccountItem]) -> Vec<&IdlAccount> {
+    items
+        .iter()
+        .flat_map(|item| match item {
+            IdlAccountItem::IdlAccount(account) => vec![account],
+            IdlAccountItem::IdlAccounts(accounts) => {
+                flatten_accounts(&accounts.accounts)
+            }
+        })
+        .collect()
+}
+
+/// Renders the string as a double quoted literal which is valid in JavaScript
+/// and Python.
+pub(crate) fn string_literal(s: &str) -> String {
+    serde_json::to_string(s).expect("strings always serialize")
+}
+
+/// Size of a field of a zero-copy type given the sizes of the zero-copy types
+/// defined in the IDL.
+fn zero_copy_size(
+    ty: &IdlType,
+    sizes: &HashMap<String, usize>,
+) -> Option<usize> {
+    match ty {
+        IdlType::Bool | IdlType::U8 | IdlType::I8 => Some(1),
+        IdlType::U16 | IdlType::I16 => Some(2),
+        IdlType::U32 | IdlType::I32 => Some(4),
+        IdlType::U64 | IdlType::I64 => Some(8),
+        IdlType::U128 | IdlType::I128 => Some(16),
+        IdlType::PublicKey => Some(32),
+        IdlType::Array(inner, len) => {
+            zero_copy_size(inner, sizes).map(|size| size * len)
+        }
+        IdlType::FixedSizeOption { inner, .. } => zero_copy_size(inner, sizes),
+        IdlType::Defined(name) => sizes.get(name).copied(),
+        _ => None,
+    }
+}
+
+/// Returns a copy of the IDL where the implicit padding of zero-copy types is
+/// represented by padding fields.
+///
+/// All fields of zero-copy types have a fixed size which is the same for Borsh
+/// and bytemuck, thus the client serializers then read the exact bytes of the
+/// `#[repr(C)]` struct.
+pub(crate) fn with_explicit_padding(idl: &Idl) -> Idl {
+    let mut idl = idl.clone();
+    let sizes = idl
+        .accounts
+        .iter()
+        .chain(idl.types.iter())
+        .filter_map(|type_definition| {
+            type_definition
+                .size
+                .map(|size| (type_definition.name.clone(), size))
+        })
+        .collect::<HashMap<String, usize>>();
+
+    let type_definitions = idl.accounts.iter_mut().chain(idl.types.iter_mut());
+    for type_definition in type_definitions {
+        if type_definition.serialization != Some(IdlSerialization::Bytemuck) {
+            continue;
+        }
+        let size = match type_definition.size {
+            Some(size) => size,
+            None => continue,
+        };
+        let fields = match &mut type_definition.ty {
+            IdlTypeDefinitionTy::Struct { fields } => fields,
+            IdlTypeDefinitionTy::Enum { .. } => continue,
+        };
+
+        let mut padded_fields = Vec::with_capacity(fields.len());
+        let mut end = Some(0);
+        for field in fields.drain(..) {
+            push_padding(&mut padded_fields, end, field.offset);
+            end = field.offset.and_then(|offset| {
+                zero_copy_size(&field.ty, &sizes).map(|size| offset + size)
+            });
+            padded_fields.push(field);
+        }
+        push_padding(&mut padded_fields, end, Some(size));
+        *fields = padded_fields;
+    }
+    idl
+}
+
+/// Adds a padding field spanning from the end of the previous field to the
+/// offset of the next one if they are known and apart.
+fn push_padding(
+    fields: &mut Vec<IdlField>,
+    end: Option<usize>,
+    next_offset: Option<usize>,
+) {
+    if let (Some(end), Some(next_offset)) = (end, next_offset) {
+        if next_offset > end {
+            fields.push(IdlField {
+                name: format!("implicitPadding{}", end),
+                ty: IdlType::Array(Box::new(IdlType::U8), next_offset - end),
+                attrs: Some(vec!["padding".to_string()]),
+                offset: Some(end),
+            });
+        }
+    }
+}
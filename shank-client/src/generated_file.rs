@@ -0,0 +1,46 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{format_err, Result};
+
+/// A file of a generated client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// Path relative to the root of the client.
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+impl GeneratedFile {
+    pub fn new(path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+/// Writes the files into the `out_dir`, creating directories as needed.
+pub fn write_generated_files(
+    out_dir: &Path,
+    files: &[GeneratedFile],
+) -> Result<()> {
+    for file in files {
+        let path = out_dir.join(&file.path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| {
+                format_err!(
+                    "Unable to create directory ({}), {}",
+                    dir.display(),
+                    err
+                )
+            })?;
+        }
+        fs::write(&path, &file.contents).map_err(|err| {
+            format_err!("Unable to write file ({}), {}", path.display(), err)
+        })?;
+    }
+    Ok(())
+}
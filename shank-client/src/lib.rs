@@ -0,0 +1,7 @@
+mod common;
+mod generated_file;
+pub mod python;
+pub mod rust;
+pub mod ts;
+
+pub use generated_file::*;
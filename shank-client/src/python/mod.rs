@@ -0,0 +1,92 @@
+use anyhow::Result;
+use heck::SnakeCase;
+use shank_idl::{idl::Idl, idl_type_definition::IdlTypeDefinition};
+
+use crate::{
+    common::{program_address, string_literal, with_explicit_padding},
+    GeneratedFile,
+};
+
+mod render_accounts;
+mod render_errors;
+mod render_instructions;
+mod render_types;
+
+use render_accounts::render_accounts;
+use render_errors::render_errors;
+use render_instructions::render_instructions;
+use render_types::{codec_name, render_type_definition};
+
+/// Borsh codecs the generated modules build on.
+const SERIALIZER_PY: &str = include_str!("serializer.py");
+
+/// Generates the files of a Python client package for the program described by
+/// the IDL. The package is placed in a `<program>_client` directory.
+///
+/// The package contains dataclasses along with codecs for the accounts and
+/// types of the program, an instruction constructor for each instruction and
+/// the errors keyed by code. It only depends on the Python standard library.
+pub fn generate_python_client(idl: &Idl) -> Result<Vec<GeneratedFile>> {
+    let idl = &with_explicit_padding(idl);
+    let program_id = program_address(idl)?;
+    let package = format!("{}_client", idl.name.to_snake_case());
+    let file = |name: &str, contents: String| {
+        GeneratedFile::new(format!("{}/{}", package, name), contents)
+    };
+
+    let init = format!(
+        "\"\"\"Client for the {name} program generated from its IDL.\"\"\"\n\
+         from . import accounts, errors, instructions, types\n\
+         from .program_id import PROGRAM_ID\n",
+        name = idl.name,
+    );
+    let program_id = format!(
+        "from .serializer import Pubkey\n\n\
+         PROGRAM_ID = Pubkey.from_string({})\n",
+        string_literal(program_id)
+    );
+    let types = format!(
+        "\"\"\"Custom types used by accounts and instructions of the program.\"\"\"\n\
+         from __future__ import annotations\n\n\
+         from dataclasses import dataclass\n\
+         from enum import IntEnum\n\
+         from typing import Dict, List, Optional, Set, Tuple, Union\n\n\
+         from . import serializer as s\n\
+         from .serializer import Pubkey\n\n\n\
+         {}",
+        idl.types
+            .iter()
+            .map(render_type_definition)
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    );
+
+    Ok(vec![
+        file("__init__.py", init),
+        file("program_id.py", program_id),
+        file("serializer.py", SERIALIZER_PY.to_string()),
+        file("types.py", types),
+        file("accounts.py", render_accounts(&idl.accounts, &idl.types)),
+        file(
+            "instructions.py",
+            render_instructions(&idl.instructions, &idl.types)?,
+        ),
+        file(
+            "errors.py",
+            render_errors(&idl.name, idl.errors.as_deref().unwrap_or_default()),
+        ),
+    ])
+}
+
+/// Renders importing all types along with their codecs.
+pub(crate) fn render_types_import(types: &[IdlTypeDefinition]) -> String {
+    if types.is_empty() {
+        return String::new();
+    }
+    let names = types
+        .iter()
+        .flat_map(|ty| vec![ty.name.to_string(), codec_name(&ty.name)])
+        .map(|name| format!("    {},\n", name))
+        .collect::<String>();
+    format!("from .types import (\n{})\n", names)
+}
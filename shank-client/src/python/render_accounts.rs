@@ -0,0 +1,54 @@
+use shank_idl::idl_type_definition::{IdlTypeDefinition, IdlTypeDefinitionTy};
+
+use super::{
+    render_types::{codec_name, render_struct, render_type_definition},
+    render_types_import,
+};
+
+fn render_account(account: &IdlTypeDefinition) -> String {
+    let name = &account.name;
+    let methods = format!(
+        r#"
+    @classmethod
+    def decode(cls, data: bytes) -> {name}:
+        """Decodes the account from its data, ignoring any trailing bytes."""
+        return s.decode({codec}, data)
+
+    def encode(self) -> bytes:
+        return s.encode({codec}, self)
+"#,
+        name = name,
+        codec = codec_name(name),
+    );
+    match &account.ty {
+        IdlTypeDefinitionTy::Struct { fields } => {
+            render_struct(name, fields, &methods)
+        }
+        // Accounts are structs, but in case the IDL was edited to contain an
+        // enum it is rendered like any other type
+        IdlTypeDefinitionTy::Enum { .. } => render_type_definition(account),
+    }
+}
+
+pub(crate) fn render_accounts(
+    accounts: &[IdlTypeDefinition],
+    types: &[IdlTypeDefinition],
+) -> String {
+    let accounts = accounts
+        .iter()
+        .map(render_account)
+        .collect::<Vec<String>>()
+        .join("\n\n");
+    format!(
+        "\"\"\"Accounts of the program.\"\"\"\n\
+         from __future__ import annotations\n\n\
+         from dataclasses import dataclass\n\
+         from typing import Dict, List, Optional, Set, Tuple\n\n\
+         from . import serializer as s\n\
+         from .serializer import Pubkey\n\
+         {types_import}\n\n\
+         {accounts}",
+        types_import = render_types_import(types),
+        accounts = accounts,
+    )
+}
@@ -0,0 +1,52 @@
+use heck::CamelCase;
+use shank_idl::idl_error_code::IdlErrorCode;
+
+use crate::common::string_literal;
+
+pub(crate) fn render_errors(
+    program_name: &str,
+    errors: &[IdlErrorCode],
+) -> String {
+    let class_name = format!("{}Error", program_name.to_camel_case());
+    let entries = errors
+        .iter()
+        .map(|error| {
+            let msg = error.msg.as_deref().unwrap_or(&error.name);
+            format!(
+                "    {}: ({}, {}),\n",
+                error.code,
+                string_literal(&error.name),
+                string_literal(msg)
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#""""Errors of the program."""
+from typing import Dict, Optional, Tuple
+
+
+class {class_name}(Exception):
+    """Error returned by the program, identified by its custom error code."""
+
+    def __init__(self, code: int, name: str, msg: str):
+        super().__init__(msg)
+        self.code = code
+        self.name = name
+        self.msg = msg
+
+
+# Name and message of each error keyed by its custom error code.
+ERRORS_BY_CODE: Dict[int, Tuple[str, str]] = {{
+{entries}}}
+
+
+def error_from_code(code: int) -> Optional[{class_name}]:
+    """Resolves the error from its custom program error code."""
+    error = ERRORS_BY_CODE.get(code)
+    return None if error is None else {class_name}(code, *error)
+"#,
+        class_name = class_name,
+        entries = entries,
+    )
+}
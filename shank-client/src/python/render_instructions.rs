@@ -0,0 +1,216 @@
+use anyhow::{bail, Result};
+use heck::ShoutySnakeCase;
+use shank_idl::{
+    idl_instruction::{IdlAccount, IdlInstruction},
+    idl_type_definition::IdlTypeDefinition,
+};
+
+use super::{
+    render_types::{python_ident, render_codec, render_py_type},
+    render_types_import,
+};
+use crate::common::{default_address, flatten_accounts, string_literal};
+
+fn render_docstring(ix: &IdlInstruction, accounts: &[&IdlAccount]) -> String {
+    let mut lines = vec![format!("Creates the {} instruction.", ix.name)];
+    if !accounts.is_empty() {
+        lines.push(String::new());
+        lines.push("    Accounts:".to_string());
+        lines
+            .extend(accounts.iter().map(|account| render_account_doc(account)));
+        lines.push("    ".to_string());
+    }
+    format!(
+        "    \"\"\"{}\"\"\"\n",
+        lines.join("\n").replace("\"\"\"", "'''")
+    )
+}
+
+/// Renders the docstring line of an account, listing its flags followed by
+/// its docs and default address if it has any.
+fn render_account_doc(account: &IdlAccount) -> String {
+    let mut flags = vec![];
+    if account.is_mut {
+        flags.push("writable");
+    }
+    if account.is_signer {
+        flags.push("signer");
+    } else if account.is_optional_signer {
+        flags.push("optional signer");
+    }
+    if account.is_optional {
+        flags.push("optional");
+    }
+    let mut docs = account.docs.clone().unwrap_or_default();
+    if let Some(address) = default_address(account) {
+        docs.push(format!("(defaults to {})", address));
+    }
+
+    let mut line = format!("        {}", python_ident(&account.name));
+    if !flags.is_empty() {
+        line.push_str(&format!(" ({})", flags.join(", ")));
+    }
+    if !docs.is_empty() {
+        line.push_str(&format!(": {}", docs.join(" ")));
+    }
+    line
+}
+
+/// Renders appending the account meta of the account to the `accounts`.
+///
+/// Optional accounts which aren't provided are omitted when the instruction
+/// uses the legacy strategy and replaced with the program id otherwise.
+fn render_account_meta(ix: &IdlInstruction, account: &IdlAccount) -> String {
+    let name = python_ident(&account.name);
+    let is_signer = if account.is_optional_signer {
+        format!("{}_is_signer", name)
+    } else {
+        python_bool(account.is_signer).to_string()
+    };
+    let meta = |pubkey: &str| {
+        format!(
+            "s.AccountMeta({}, is_signer={}, is_writable={})",
+            pubkey,
+            is_signer,
+            python_bool(account.is_mut)
+        )
+    };
+
+    if let Some(address) = default_address(account) {
+        let pubkey = format!(
+            "{name} if {name} is not None else s.Pubkey.from_string({address})",
+            name = name,
+            address = string_literal(address)
+        );
+        return format!("    accounts.append({})\n", meta(&pubkey));
+    }
+    if !account.is_optional {
+        return format!("    accounts.append({})\n", meta(&name));
+    }
+
+    if ix.legacy_optional_accounts_strategy.unwrap_or(false) {
+        format!(
+            "    if {name} is not None:\n        accounts.append({meta})\n",
+            name = name,
+            meta = meta(&name),
+        )
+    } else {
+        format!(
+            "    accounts.append(\n        {meta}\n        if {name} is not None\n        \
+             else s.AccountMeta(program_id, is_signer=False, is_writable=False)\n    )\n",
+            name = name,
+            meta = meta(&name),
+        )
+    }
+}
+
+fn python_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
+
+fn render_instruction(ix: &IdlInstruction) -> Result<String> {
+    let fn_name = format!("{}_instruction", python_ident(&ix.name));
+    let discriminant =
+        format!("{}_DISCRIMINANT", ix.name.to_shouty_snake_case());
+    let accounts = flatten_accounts(&ix.accounts);
+
+    // Accounts and args are both passed as keyword arguments
+    let mut params = Vec::new();
+    for account in &accounts {
+        let name = python_ident(&account.name);
+        let has_default =
+            default_address(account).is_some() || account.is_optional;
+        if has_default {
+            params.push((name.clone(), "Optional[Pubkey] = None".to_string()));
+        } else {
+            params.push((name.clone(), "Pubkey".to_string()));
+        }
+        if account.is_optional_signer {
+            params.push((
+                format!("{}_is_signer", name),
+                "bool = False".to_string(),
+            ));
+        }
+    }
+    for arg in &ix.args {
+        params.push((python_ident(&arg.name), render_py_type(&arg.ty)));
+    }
+    params.push(("program_id".to_string(), "Pubkey = PROGRAM_ID".to_string()));
+    for (idx, (name, _)) in params.iter().enumerate() {
+        if params[..idx].iter().any(|(other, _)| other == name) {
+            bail!(
+                "Instruction {} has multiple accounts or args named '{}'",
+                ix.name,
+                name
+            );
+        }
+    }
+
+    let params = params
+        .iter()
+        .map(|(name, ty)| format!("    {}: {},\n", name, ty))
+        .collect::<String>();
+    let account_metas = accounts
+        .iter()
+        .map(|account| render_account_meta(ix, account))
+        .collect::<String>();
+    let encode_args = ix
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "    {}.encode({}, data)\n",
+                render_codec(&arg.ty),
+                python_ident(&arg.name)
+            )
+        })
+        .collect::<String>();
+
+    Ok(format!(
+        r#"{discriminant} = {value}
+
+
+def {fn_name}(
+    *,
+{params}) -> s.Instruction:
+{docstring}    accounts: List[s.AccountMeta] = []
+{account_metas}
+    data = bytearray([{discriminant}])
+{encode_args}    return s.Instruction(program_id, accounts, bytes(data))
+"#,
+        discriminant = discriminant,
+        value = ix.discriminant.value,
+        fn_name = fn_name,
+        params = params,
+        docstring = render_docstring(ix, &accounts),
+        account_metas = account_metas,
+        encode_args = encode_args,
+    ))
+}
+
+pub(crate) fn render_instructions(
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDefinition],
+) -> Result<String> {
+    let instructions = instructions
+        .iter()
+        .map(render_instruction)
+        .collect::<Result<Vec<String>>>()?
+        .join("\n\n");
+    Ok(format!(
+        "\"\"\"Instructions of the program.\"\"\"\n\
+         from __future__ import annotations\n\n\
+         from typing import Dict, List, Optional, Set, Tuple\n\n\
+         from . import serializer as s\n\
+         from .program_id import PROGRAM_ID\n\
+         from .serializer import Pubkey\n\
+         {types_import}\n\n\
+         {instructions}",
+        types_import = render_types_import(types),
+        instructions = instructions,
+    ))
+}
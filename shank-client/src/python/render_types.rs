@@ -0,0 +1,297 @@
+use heck::{ShoutySnakeCase, SnakeCase};
+use shank_idl::{
+    idl_field::IdlField,
+    idl_type::IdlType,
+    idl_type_definition::{IdlTypeDefinition, IdlTypeDefinitionTy},
+    idl_variant::EnumFields,
+};
+
+use crate::common::string_literal;
+
+const PYTHON_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break",
+    "class", "continue", "def", "del", "elif", "else", "except", "finally",
+    "for", "from", "global", "if", "import", "in", "is", "lambda", "nonlocal",
+    "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+/// Converts the name to a snake case Python identifier, suffixing keywords
+/// with an underscore.
+pub(crate) fn python_ident(name: &str) -> String {
+    let ident = name.to_snake_case();
+    if PYTHON_KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+/// Name of the codec const of a defined type, i.e. `VAULT_KIND_CODEC`.
+pub(crate) fn codec_name(type_name: &str) -> String {
+    format!("{}_CODEC", type_name.to_shouty_snake_case())
+}
+
+/// Renders the Python type annotation of the value the [IdlType] is decoded
+/// into.
+pub(crate) fn render_py_type(ty: &IdlType) -> String {
+    match ty {
+        IdlType::Bool => "bool".to_string(),
+        IdlType::U8
+        | IdlType::U16
+        | IdlType::U32
+        | IdlType::U64
+        | IdlType::U128
+        | IdlType::I8
+        | IdlType::I16
+        | IdlType::I32
+        | IdlType::I64
+        | IdlType::I128 => "int".to_string(),
+        IdlType::String => "str".to_string(),
+        IdlType::Bytes => "bytes".to_string(),
+        IdlType::PublicKey => "Pubkey".to_string(),
+        IdlType::Defined(name) => name.to_string(),
+        IdlType::Option(inner) | IdlType::FixedSizeOption { inner, .. } => {
+            format!("Optional[{}]", render_py_type(inner))
+        }
+        IdlType::Array(inner, _) | IdlType::Vec(inner) => {
+            format!("List[{}]", render_py_type(inner))
+        }
+        IdlType::Tuple(inners) => format!(
+            "Tuple[{}]",
+            inners
+                .iter()
+                .map(render_py_type)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        IdlType::HashMap(key, val) | IdlType::BTreeMap(key, val) => {
+            format!("Dict[{}, {}]", render_py_type(key), render_py_type(val))
+        }
+        IdlType::HashSet(inner) | IdlType::BTreeSet(inner) => {
+            format!("Set[{}]", render_py_type(inner))
+        }
+    }
+}
+
+/// Renders the expression creating the codec of the [IdlType] using the
+/// serializer module imported as `s`.
+pub(crate) fn render_codec(ty: &IdlType) -> String {
+    match ty {
+        IdlType::Bool => "s.BOOL".to_string(),
+        IdlType::U8 => "s.U8".to_string(),
+        IdlType::U16 => "s.U16".to_string(),
+        IdlType::U32 => "s.U32".to_string(),
+        IdlType::U64 => "s.U64".to_string(),
+        IdlType::U128 => "s.U128".to_string(),
+        IdlType::I8 => "s.I8".to_string(),
+        IdlType::I16 => "s.I16".to_string(),
+        IdlType::I32 => "s.I32".to_string(),
+        IdlType::I64 => "s.I64".to_string(),
+        IdlType::I128 => "s.I128".to_string(),
+        IdlType::String => "s.STRING".to_string(),
+        IdlType::Bytes => "s.BYTES".to_string(),
+        IdlType::PublicKey => "s.PUBKEY".to_string(),
+        // Resolved lazily since types may be declared after their use
+        IdlType::Defined(name) => {
+            format!("s.Lazy(lambda: {})", codec_name(name))
+        }
+        IdlType::Option(inner) => format!("s.Option({})", render_codec(inner)),
+        IdlType::FixedSizeOption { inner, sentinel } => {
+            let sentinel = match sentinel {
+                Some(sentinel) => format!(
+                    "bytes([{}])",
+                    sentinel
+                        .iter()
+                        .map(u8::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                None => "None".to_string(),
+            };
+            format!("s.FixedSizeOption({}, {})", render_codec(inner), sentinel)
+        }
+        IdlType::Array(inner, size) => {
+            format!("s.Array({}, {})", render_codec(inner), size)
+        }
+        IdlType::Vec(inner) => format!("s.Vec({})", render_codec(inner)),
+        IdlType::Tuple(inners) => format!(
+            "s.Tuple({})",
+            inners
+                .iter()
+                .map(render_codec)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        IdlType::HashMap(key, val) | IdlType::BTreeMap(key, val) => {
+            format!("s.Map({}, {})", render_codec(key), render_codec(val))
+        }
+        IdlType::HashSet(inner) | IdlType::BTreeSet(inner) => {
+            format!("s.Set({})", render_codec(inner))
+        }
+    }
+}
+
+fn render_dataclass(name: &str, fields: &[IdlField], body: &str) -> String {
+    let attrs = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "    {}: {}\n",
+                python_ident(&field.name),
+                render_py_type(&field.ty)
+            )
+        })
+        .collect::<String>();
+    let attrs = if attrs.is_empty() && body.is_empty() {
+        "    pass\n".to_string()
+    } else {
+        attrs
+    };
+    format!("@dataclass\nclass {}:\n{}{}", name, attrs, body)
+}
+
+/// Renders the list of `(attribute, codec)` tuples of the fields.
+pub(crate) fn render_fields_codecs(
+    fields: &[IdlField],
+    indent: &str,
+) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}    ({}, {}),\n",
+                indent,
+                string_literal(&python_ident(&field.name)),
+                render_codec(&field.ty)
+            )
+        })
+        .collect::<String>();
+    format!("[\n{}{}]", fields, indent)
+}
+
+/// Renders a struct as a dataclass including the `body`, followed by its codec.
+pub(crate) fn render_struct(
+    name: &str,
+    fields: &[IdlField],
+    body: &str,
+) -> String {
+    format!(
+        "{dataclass}\n\n{codec} = s.Struct({name}, {fields})\n",
+        dataclass = render_dataclass(name, fields, body),
+        codec = codec_name(name),
+        name = name,
+        fields = render_fields_codecs(fields, ""),
+    )
+}
+
+/// Renders the type along with its codec const.
+///
+/// Enums without any data are rendered as `IntEnum`s while all others are
+/// represented by a dataclass per variant and a `Union` of them.
+pub(crate) fn render_type_definition(
+    type_definition: &IdlTypeDefinition,
+) -> String {
+    let name = &type_definition.name;
+    let codec = codec_name(name);
+    match &type_definition.ty {
+        IdlTypeDefinitionTy::Struct { fields } => {
+            render_struct(name, fields, "")
+        }
+        IdlTypeDefinitionTy::Enum { variants }
+            if variants.iter().all(|variant| variant.fields.is_none()) =>
+        {
+            let variant_values = variants
+                .iter()
+                .enumerate()
+                .map(|(idx, variant)| {
+                    format!("    {} = {}\n", variant.name, idx)
+                })
+                .collect::<String>();
+            let variant_values = if variant_values.is_empty() {
+                "    pass\n".to_string()
+            } else {
+                variant_values
+            };
+            format!(
+                "class {name}(IntEnum):\n{variants}\n\n{codec} = s.ScalarEnum({name})\n",
+                name = name,
+                variants = variant_values,
+                codec = codec,
+            )
+        }
+        IdlTypeDefinitionTy::Enum { variants } => {
+            let variant_fields = |fields: &Option<EnumFields>| match fields {
+                None => vec![],
+                Some(EnumFields::Named(fields)) => fields.clone(),
+                Some(EnumFields::Tuple(tys)) => vec![IdlField {
+                    name: "fields".to_string(),
+                    ty: IdlType::Tuple(tys.clone()),
+                    attrs: None,
+                    offset: None,
+                }],
+            };
+            let classes = variants
+                .iter()
+                .map(|variant| {
+                    render_dataclass(
+                        &format!("{}{}", name, variant.name),
+                        &variant_fields(&variant.fields),
+                        "",
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n\n");
+            let union = variants
+                .iter()
+                .map(|variant| format!("{}{}", name, variant.name))
+                .collect::<Vec<String>>()
+                .join(", ");
+            let variant_codecs = variants
+                .iter()
+                .map(|variant| {
+                    let fields = match &variant.fields {
+                        None => "None".to_string(),
+                        fields => render_fields_codecs(
+                            &variant_fields(fields),
+                            "    ",
+                        ),
+                    };
+                    format!("    ({}{}, {}),\n", name, variant.name, fields)
+                })
+                .collect::<String>();
+            format!(
+                "{classes}\n\n{name} = Union[{union}]\n\n\
+                 {codec} = s.DataEnum([\n{variant_codecs}])\n",
+                classes = classes,
+                name = name,
+                union = union,
+                codec = codec,
+                variant_codecs = variant_codecs,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_py_type_and_codec_composites() {
+        let ty = IdlType::HashMap(
+            Box::new(IdlType::String),
+            Box::new(IdlType::Tuple(vec![IdlType::U64, IdlType::PublicKey])),
+        );
+        assert_eq!(render_py_type(&ty), "Dict[str, Tuple[int, Pubkey]]");
+        assert_eq!(
+            render_codec(&ty),
+            "s.Map(s.STRING, s.Tuple(s.U64, s.PUBKEY))"
+        );
+    }
+
+    #[test]
+    fn python_ident_escapes_keywords() {
+        assert_eq!(python_ident("from"), "from_");
+        assert_eq!(python_ident("newAuthority"), "new_authority");
+    }
+}
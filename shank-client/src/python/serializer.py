@@ -0,0 +1,415 @@
+"""Borsh encoding and decoding used by the generated client."""
+import struct as _struct
+from dataclasses import dataclass
+from typing import Any, Callable, List, Optional as _Optional, Sequence
+
+_B58_ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+
+
+class Pubkey:
+    """A 32 byte public key which is displayed base58 encoded."""
+
+    __slots__ = ("_bytes",)
+
+    def __init__(self, value: bytes):
+        if len(value) != 32:
+            raise ValueError(f"Pubkey needs 32 bytes but got {len(value)}")
+        self._bytes = bytes(value)
+
+    @classmethod
+    def from_string(cls, value: str) -> "Pubkey":
+        num = 0
+        for char in value:
+            num = num * 58 + _B58_ALPHABET.index(char)
+        decoded = num.to_bytes((num.bit_length() + 7) // 8, "big")
+        leading_zeros = len(value) - len(value.lstrip("1"))
+        return cls(b"\0" * leading_zeros + decoded)
+
+    def __bytes__(self) -> bytes:
+        return self._bytes
+
+    def __str__(self) -> str:
+        num = int.from_bytes(self._bytes, "big")
+        encoded = ""
+        while num:
+            num, rem = divmod(num, 58)
+            encoded = _B58_ALPHABET[rem] + encoded
+        leading_zeros = len(self._bytes) - len(self._bytes.lstrip(b"\0"))
+        return "1" * leading_zeros + encoded
+
+    def __repr__(self) -> str:
+        return f"Pubkey({self})"
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, Pubkey) and self._bytes == other._bytes
+
+    def __hash__(self) -> int:
+        return hash(self._bytes)
+
+
+@dataclass
+class AccountMeta:
+    pubkey: Pubkey
+    is_signer: bool
+    is_writable: bool
+
+
+@dataclass
+class Instruction:
+    program_id: Pubkey
+    accounts: List[AccountMeta]
+    data: bytes
+
+
+class Reader:
+    def __init__(self, data: bytes):
+        self.data = bytes(data)
+        self.offset = 0
+
+    def read(self, length: int) -> bytes:
+        if self.offset + length > len(self.data):
+            raise ValueError(
+                f"Unexpected end of data reading {length} bytes at offset {self.offset}"
+            )
+        value = self.data[self.offset : self.offset + length]
+        self.offset += length
+        return value
+
+
+class Codec:
+    def encode(self, value: Any, out: bytearray) -> None:
+        raise NotImplementedError
+
+    def decode(self, reader: Reader) -> Any:
+        raise NotImplementedError
+
+    def sort_key(self, value: Any) -> Any:
+        """Orders values the way the Rust type derives `Ord`, which Borsh uses to
+        sort map keys and set items."""
+        raise TypeError(f"{type(self).__name__} cannot be used as map key or set item")
+
+
+def _sorted_by_key(codec: Codec, items: List[Any], key: Callable[[Any], Any]) -> List[Any]:
+    """Sorts the map keys or set items the way Borsh does, rejecting duplicates."""
+    keyed = sorted(((codec.sort_key(key(item)), item) for item in items), key=lambda k: k[0])
+    for (prev, _), (sort_key, item) in zip(keyed, keyed[1:]):
+        if prev == sort_key:
+            raise ValueError(f"Duplicate map key or set item {key(item)!r}")
+    return [item for _, item in keyed]
+
+
+def encode(codec: Codec, value: Any) -> bytes:
+    out = bytearray()
+    codec.encode(value, out)
+    return bytes(out)
+
+
+def decode(codec: Codec, data: bytes) -> Any:
+    """Decodes the value from the start of the data ignoring trailing bytes."""
+    return codec.decode(Reader(data))
+
+
+class _Fixed(Codec):
+    def __init__(self, fmt: str):
+        self._struct = _struct.Struct(fmt)
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        out += self._struct.pack(value)
+
+    def decode(self, reader: Reader) -> Any:
+        return self._struct.unpack(reader.read(self._struct.size))[0]
+
+    def sort_key(self, value: Any) -> Any:
+        return value
+
+
+class _Int128(Codec):
+    def __init__(self, signed: bool):
+        self._signed = signed
+
+    def encode(self, value: int, out: bytearray) -> None:
+        out += value.to_bytes(16, "little", signed=self._signed)
+
+    def decode(self, reader: Reader) -> int:
+        return int.from_bytes(reader.read(16), "little", signed=self._signed)
+
+    def sort_key(self, value: int) -> Any:
+        return value
+
+
+U8 = _Fixed("<B")
+U16 = _Fixed("<H")
+U32 = _Fixed("<I")
+U64 = _Fixed("<Q")
+U128 = _Int128(signed=False)
+I8 = _Fixed("<b")
+I16 = _Fixed("<h")
+I32 = _Fixed("<i")
+I64 = _Fixed("<q")
+I128 = _Int128(signed=True)
+
+
+class _Bool(Codec):
+    def encode(self, value: bool, out: bytearray) -> None:
+        U8.encode(1 if value else 0, out)
+
+    def decode(self, reader: Reader) -> bool:
+        value = U8.decode(reader)
+        if value > 1:
+            raise ValueError(f"Invalid bool value {value}")
+        return value == 1
+
+    def sort_key(self, value: bool) -> Any:
+        return int(value)
+
+
+class _Bytes(Codec):
+    def encode(self, value: bytes, out: bytearray) -> None:
+        U32.encode(len(value), out)
+        out += value
+
+    def decode(self, reader: Reader) -> bytes:
+        return reader.read(U32.decode(reader))
+
+    def sort_key(self, value: bytes) -> Any:
+        return bytes(value)
+
+
+class _String(Codec):
+    def encode(self, value: str, out: bytearray) -> None:
+        BYTES.encode(value.encode("utf-8"), out)
+
+    def decode(self, reader: Reader) -> str:
+        return BYTES.decode(reader).decode("utf-8")
+
+    def sort_key(self, value: str) -> Any:
+        # Rust compares the UTF-8 bytes
+        return value.encode("utf-8")
+
+
+class _Pubkey(Codec):
+    def encode(self, value: Pubkey, out: bytearray) -> None:
+        out += bytes(value)
+
+    def decode(self, reader: Reader) -> Pubkey:
+        return Pubkey(reader.read(32))
+
+    def sort_key(self, value: Pubkey) -> Any:
+        return bytes(value)
+
+
+BOOL = _Bool()
+BYTES = _Bytes()
+STRING = _String()
+PUBKEY = _Pubkey()
+
+
+class Lazy(Codec):
+    """Defers resolving the codec which allows types to reference each other."""
+
+    def __init__(self, resolve: Callable[[], Codec]):
+        self._resolve = resolve
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        self._resolve().encode(value, out)
+
+    def decode(self, reader: Reader) -> Any:
+        return self._resolve().decode(reader)
+
+    def sort_key(self, value: Any) -> Any:
+        return self._resolve().sort_key(value)
+
+
+class Option(Codec):
+    def __init__(self, inner: Codec):
+        self._inner = inner
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        if value is None:
+            U8.encode(0, out)
+        else:
+            U8.encode(1, out)
+            self._inner.encode(value, out)
+
+    def decode(self, reader: Reader) -> Any:
+        return None if U8.decode(reader) == 0 else self._inner.decode(reader)
+
+    def sort_key(self, value: Any) -> Any:
+        # None orders before Some
+        return () if value is None else (self._inner.sort_key(value),)
+
+
+class FixedSizeOption(Codec):
+    """Option stored as the raw inner value, `None` is represented by the sentinel bytes."""
+
+    def __init__(self, inner: Codec, sentinel: _Optional[bytes]):
+        self._inner = inner
+        self._sentinel = sentinel
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        if value is not None:
+            self._inner.encode(value, out)
+        elif self._sentinel is not None:
+            out += self._sentinel
+        else:
+            raise ValueError("Cannot encode None of a fixed size option without sentinel")
+
+    def decode(self, reader: Reader) -> Any:
+        start = reader.offset
+        value = self._inner.decode(reader)
+        if self._sentinel is not None and reader.data[start : reader.offset] == self._sentinel:
+            return None
+        return value
+
+    def sort_key(self, value: Any) -> Any:
+        return () if value is None else (self._inner.sort_key(value),)
+
+
+class Array(Codec):
+    def __init__(self, inner: Codec, length: int):
+        self._inner = inner
+        self._length = length
+
+    def encode(self, value: Sequence[Any], out: bytearray) -> None:
+        if len(value) != self._length:
+            raise ValueError(f"Expected array of length {self._length} but got {len(value)}")
+        for item in value:
+            self._inner.encode(item, out)
+
+    def decode(self, reader: Reader) -> List[Any]:
+        return [self._inner.decode(reader) for _ in range(self._length)]
+
+    def sort_key(self, value: Sequence[Any]) -> Any:
+        return tuple(self._inner.sort_key(item) for item in value)
+
+
+class Vec(Codec):
+    def __init__(self, inner: Codec):
+        self._inner = inner
+
+    def encode(self, value: Sequence[Any], out: bytearray) -> None:
+        U32.encode(len(value), out)
+        for item in value:
+            self._inner.encode(item, out)
+
+    def decode(self, reader: Reader) -> List[Any]:
+        return [self._inner.decode(reader) for _ in range(U32.decode(reader))]
+
+    def sort_key(self, value: Sequence[Any]) -> Any:
+        return tuple(self._inner.sort_key(item) for item in value)
+
+
+class Tuple(Codec):
+    def __init__(self, *items: Codec):
+        self._items = items
+
+    def encode(self, value: Sequence[Any], out: bytearray) -> None:
+        for item, codec in zip(value, self._items):
+            codec.encode(item, out)
+
+    def decode(self, reader: Reader) -> tuple:
+        return tuple(codec.decode(reader) for codec in self._items)
+
+    def sort_key(self, value: Sequence[Any]) -> Any:
+        return tuple(codec.sort_key(item) for item, codec in zip(value, self._items))
+
+
+class Map(Codec):
+    def __init__(self, key: Codec, value: Codec):
+        self._key = key
+        self._entries = Vec(Tuple(key, value))
+
+    def encode(self, value: dict, out: bytearray) -> None:
+        # Borsh writes the entries sorted by key
+        entries = _sorted_by_key(self._key, list(value.items()), lambda entry: entry[0])
+        self._entries.encode(entries, out)
+
+    def decode(self, reader: Reader) -> dict:
+        return dict(self._entries.decode(reader))
+
+
+class Set(Codec):
+    def __init__(self, inner: Codec):
+        self._inner = inner
+        self._items = Vec(inner)
+
+    def encode(self, value: set, out: bytearray) -> None:
+        # Borsh writes the items sorted
+        self._items.encode(_sorted_by_key(self._inner, list(value), lambda item: item), out)
+
+    def decode(self, reader: Reader) -> set:
+        return set(self._items.decode(reader))
+
+    def sort_key(self, value: set) -> Any:
+        return tuple(sorted(self._inner.sort_key(item) for item in value))
+
+
+class Struct(Codec):
+    """Encodes the attributes of the dataclass in the order of the fields."""
+
+    def __init__(self, cls: type, fields: List[tuple]):
+        self._cls = cls
+        self._fields = fields
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        for name, codec in self._fields:
+            codec.encode(getattr(value, name), out)
+
+    def decode(self, reader: Reader) -> Any:
+        return self._cls(**{name: codec.decode(reader) for name, codec in self._fields})
+
+    def sort_key(self, value: Any) -> Any:
+        return tuple(codec.sort_key(getattr(value, name)) for name, codec in self._fields)
+
+
+class ScalarEnum(Codec):
+    """Enum without any data which is represented by the index of its variant."""
+
+    def __init__(self, cls: type):
+        self._cls = cls
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        U8.encode(int(value), out)
+
+    def decode(self, reader: Reader) -> Any:
+        return self._cls(U8.decode(reader))
+
+    def sort_key(self, value: Any) -> Any:
+        return int(value)
+
+
+class DataEnum(Codec):
+    """Enum whose variants are represented by a dataclass each.
+
+    Variants are given as the dataclass along with its fields or `None` if it
+    holds no data.
+    """
+
+    def __init__(self, variants: List[tuple]):
+        self._variants = [
+            (cls, Struct(cls, fields) if fields is not None else None)
+            for cls, fields in variants
+        ]
+
+    def encode(self, value: Any, out: bytearray) -> None:
+        for idx, (cls, codec) in enumerate(self._variants):
+            if type(value) is cls:
+                U8.encode(idx, out)
+                if codec is not None:
+                    codec.encode(value, out)
+                return
+        raise ValueError(f"Invalid enum variant {value!r}")
+
+    def decode(self, reader: Reader) -> Any:
+        idx = U8.decode(reader)
+        if idx >= len(self._variants):
+            raise ValueError(f"Invalid enum variant {idx}")
+        cls, codec = self._variants[idx]
+        return cls() if codec is None else codec.decode(reader)
+
+    def sort_key(self, value: Any) -> Any:
+        # Variants are ordered by their index and then their data
+        for idx, (cls, codec) in enumerate(self._variants):
+            if type(value) is cls:
+                return (idx, () if codec is None else codec.sort_key(value))
+        raise ValueError(f"Invalid enum variant {value!r}")
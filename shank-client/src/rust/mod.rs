@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
+use heck::{KebabCase, SnakeCase};
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_idl::idl::Idl;
+use shank_macro_impl::{syn, well_known::WELL_KNOWN_ACCOUNTS};
+
+use crate::{
+    common::{flatten_accounts, program_address, with_explicit_padding},
+    GeneratedFile,
+};
+
+mod render_accounts;
+mod render_errors;
+mod render_instructions;
+mod render_types;
+
+use render_accounts::render_accounts;
+use render_errors::render_errors;
+use render_instructions::render_instructions;
+use render_types::render_type_definition;
+
+/// Dependencies of the crates which provide the ids the builders default
+/// well known accounts to.
+const DEFAULT_PUBKEY_DEPENDENCIES: [(&str, &str); 5] = [
+    (
+        "spl_token",
+        r#"spl-token = { version = "4.0", features = ["no-entrypoint"] }"#,
+    ),
+    (
+        "spl_token_2022",
+        r#"spl-token-2022 = { version = "0.9", features = ["no-entrypoint"] }"#,
+    ),
+    (
+        "spl_associated_token_account",
+        r#"spl-associated-token-account = { version = "2.2", features = ["no-entrypoint"] }"#,
+    ),
+    (
+        "mpl_token_metadata",
+        r#"mpl-token-metadata = { version = "3.0" }"#,
+    ),
+    (
+        "mpl_token_auth_rules",
+        r#"mpl-token-auth-rules = { version = "1.4", features = ["no-entrypoint"] }"#,
+    ),
+];
+
+/// Generates the files of a Rust client crate for the program described by the
+/// IDL, including its `Cargo.toml`.
+///
+/// The crate contains the accounts and types of the program, its errors and
+/// an instruction enum along with builders to create each instruction.
+pub fn generate_rust_client(idl: &Idl) -> Result<Vec<GeneratedFile>> {
+    let idl = &with_explicit_padding(idl);
+    let program_id = program_address(idl)?;
+
+    let types = idl.types.iter().map(render_type_definition);
+    let types = quote! {
+        #![doc = " Custom types used by accounts and instructions of the program."]
+
+        use borsh::{BorshDeserialize, BorshSerialize};
+        #[allow(unused_imports)]
+        use solana_program::pubkey::Pubkey;
+
+        #(#types)*
+    };
+    let accounts = render_accounts(&idl.accounts)?;
+    let instructions = render_instructions(&idl.name, &idl.instructions)?;
+    let errors =
+        render_errors(&idl.name, idl.errors.as_deref().unwrap_or_default());
+
+    let lib = quote! {
+        //! Client for the program generated from its IDL.
+
+        pub mod accounts;
+        pub mod errors;
+        pub mod instructions;
+        pub mod types;
+
+        solana_program::declare_id!(#program_id);
+    };
+
+    Ok(vec![
+        GeneratedFile::new("Cargo.toml", render_manifest(idl)),
+        rust_file("src/lib.rs", lib)?,
+        rust_file("src/accounts.rs", accounts)?,
+        rust_file("src/errors.rs", errors)?,
+        rust_file("src/instructions.rs", instructions)?,
+        rust_file("src/types.rs", types)?,
+    ])
+}
+
+fn rust_file(path: &str, tokens: TokenStream) -> Result<GeneratedFile> {
+    let file = syn::parse2::<syn::File>(tokens).map_err(|err| {
+        anyhow!("Generated invalid Rust code for {}: {}", path, err)
+    })?;
+    Ok(GeneratedFile::new(path, prettyplease::unparse(&file)))
+}
+
+fn render_manifest(idl: &Idl) -> String {
+    // The builders default some well known accounts to ids of other crates
+    // unless the IDL provides their address
+    let account_names = idl
+        .instructions
+        .iter()
+        .flat_map(|ix| flatten_accounts(&ix.accounts))
+        .filter(|account| account.address.is_none())
+        .map(|account| account.name.to_snake_case())
+        .collect::<BTreeSet<String>>();
+    let default_pubkey_crates = WELL_KNOWN_ACCOUNTS
+        .iter()
+        .filter(|account| account_names.contains(account.name))
+        .filter_map(|account| account.path.split("::").next())
+        .collect::<BTreeSet<&str>>();
+    let extra_dependencies = DEFAULT_PUBKEY_DEPENDENCIES
+        .iter()
+        .filter(|(krate, _)| default_pubkey_crates.contains(krate))
+        .map(|(_, dependency)| format!("{}\n", dependency))
+        .collect::<String>();
+
+    let version = if idl.version.is_empty() {
+        "0.1.0"
+    } else {
+        idl.version.as_str()
+    };
+
+    format!(
+        r#"[package]
+name = "{name}-client"
+description = "Client for the {name} program"
+version = "{version}"
+edition = "2021"
+
+[dependencies]
+borsh = "0.10"
+solana-program = "1.16"
+thiserror = "1.0"
+{extra_dependencies}"#,
+        name = idl.name.to_kebab_case(),
+        version = version,
+        extra_dependencies = extra_dependencies,
+    )
+}
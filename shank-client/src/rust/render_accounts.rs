@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use anyhow::{Error, Result};
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use shank_idl::{
+    idl_seed::{IdlSeed, IdlSeedsProgram},
+    idl_type::IdlType,
+    idl_type_definition::IdlTypeDefinition,
+};
+use shank_macro_impl::parsed_struct::{
+    Seed, Seeds, SeedsProgram, StructAttr, StructAttrs,
+};
+use shank_render::pda::render_pda_and_seeds_impl;
+
+use super::render_types::{render_idl_type, render_type_definition};
+
+/// Converts the IDL seeds back into the model shank-render renders PDA fns from.
+fn struct_attrs_from_seeds(
+    seeds: &[IdlSeed],
+    seeds_program: Option<&IdlSeedsProgram>,
+) -> StructAttrs {
+    let seeds = seeds
+        .iter()
+        .map(|seed| match seed {
+            IdlSeed::Constant { value } => Seed::Literal(value.to_string()),
+            IdlSeed::ProgramId => Seed::ProgramId,
+            IdlSeed::Variable {
+                name,
+                description,
+                ty,
+            } => {
+                let ty = match ty {
+                    IdlType::PublicKey => None,
+                    ty => Some(render_idl_type(ty).to_string()),
+                };
+                Seed::Param(name.to_snake_case(), description.to_string(), ty)
+            }
+        })
+        .collect();
+    let mut attrs = HashSet::new();
+    attrs.insert(StructAttr::Seeds(Seeds(seeds)));
+    if let Some(program) = seeds_program {
+        let program = match program {
+            IdlSeedsProgram::Address { address } => {
+                SeedsProgram::Address(address.to_string())
+            }
+            IdlSeedsProgram::Path { path } => {
+                SeedsProgram::Path(path.to_string())
+            }
+        };
+        attrs.insert(StructAttr::SeedsProgram(program));
+    }
+    StructAttrs(attrs)
+}
+
+fn render_account(account: &IdlTypeDefinition) -> Result<TokenStream> {
+    let name = format_ident!("{}", account.name);
+    let strct = render_type_definition(account);
+
+    let pda_impl = match &account.seeds {
+        Some(seeds) => render_pda_and_seeds_impl(
+            &struct_attrs_from_seeds(seeds, account.seeds_program.as_ref()),
+            &name,
+            false,
+        )
+        .map_err(|err| {
+            Error::msg(format!(
+                "Failed to render PDA fns of account {}: {}",
+                account.name, err
+            ))
+        })?,
+        None => TokenStream::new(),
+    };
+
+    Ok(quote! {
+        #strct
+
+        impl #name {
+            /// Deserializes the account from its data, ignoring any trailing bytes.
+            pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+                let mut data = data;
+                Self::deserialize(&mut data)
+            }
+        }
+
+        #pda_impl
+    })
+}
+
+pub(crate) fn render_accounts(
+    accounts: &[IdlTypeDefinition],
+) -> Result<TokenStream> {
+    let accounts = accounts
+        .iter()
+        .map(render_account)
+        .collect::<Result<Vec<TokenStream>>>()?;
+    Ok(quote! {
+        #![doc = " Accounts of the program."]
+
+        #[allow(unused_imports)]
+        use crate::types::*;
+        use borsh::{BorshDeserialize, BorshSerialize};
+        #[allow(unused_imports)]
+        use solana_program::pubkey::Pubkey;
+
+        #(#accounts)*
+    })
+}
@@ -0,0 +1,71 @@
+use heck::CamelCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use shank_idl::idl_error_code::IdlErrorCode;
+
+pub(crate) fn render_errors(
+    program_name: &str,
+    errors: &[IdlErrorCode],
+) -> TokenStream {
+    let enum_ident = format_ident!("{}Error", program_name.to_camel_case());
+
+    let variants = errors.iter().map(|error| {
+        let name = format_ident!("{}", error.name);
+        let code = Literal::u32_unsuffixed(error.code);
+        let msg = error.msg.clone().unwrap_or_else(|| error.name.clone());
+        let doc = format!(" {:#x} - {}", error.code, msg);
+        quote! {
+            #[doc = #doc]
+            #[error(#msg)]
+            #name = #code
+        }
+    });
+    let from_code_arms = errors.iter().map(|error| {
+        let name = format_ident!("{}", error.name);
+        let code = Literal::u32_unsuffixed(error.code);
+        quote! { #code => Some(Self::#name) }
+    });
+
+    // Errors without any variants cannot be cast to u32
+    if errors.is_empty() {
+        return quote! {
+            #![doc = " Errors of the program."]
+
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #enum_ident {}
+
+            impl #enum_ident {
+                /// Resolves the error from its custom program error code.
+                pub fn from_code(_code: u32) -> Option<Self> {
+                    None
+                }
+            }
+        };
+    }
+
+    quote! {
+        #![doc = " Errors of the program."]
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+        #[repr(u32)]
+        pub enum #enum_ident {
+            #(#variants),*
+        }
+
+        impl #enum_ident {
+            /// Resolves the error from its custom program error code.
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    #(#from_code_arms,)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<#enum_ident> for solana_program::program_error::ProgramError {
+            fn from(err: #enum_ident) -> Self {
+                solana_program::program_error::ProgramError::Custom(err as u32)
+            }
+        }
+    }
+}
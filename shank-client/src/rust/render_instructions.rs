@@ -0,0 +1,220 @@
+use std::convert::TryFrom;
+
+use anyhow::{Error, Result};
+use heck::{CamelCase, SnakeCase};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use shank_idl::idl_instruction::{IdlAccount, IdlInstruction};
+use shank_macro_impl::{
+    builder::{Builder, BuilderVariant},
+    instruction::{
+        AccountDefault, InstructionAccount, InstructionVariantFields,
+    },
+    parsed_struct::CratePaths,
+    syn::Ident,
+    types::RustType,
+};
+use shank_render::builder::render_builders_impl;
+
+use super::render_types::{is_ident_type, render_idl_type};
+use crate::common::flatten_accounts;
+
+/// Name of the type used for the arg in the instruction enum and builders.
+/// The builders only support types which are a single identifier, thus others
+/// are aliased.
+fn arg_type_name(ix: &IdlInstruction, idx: usize) -> String {
+    let arg = &ix.args[idx];
+    if is_ident_type(&arg.ty) {
+        render_idl_type(&arg.ty).to_string()
+    } else {
+        format!("{}{}", ix.name, arg.name.to_camel_case())
+    }
+}
+
+fn render_arg_aliases(ix: &IdlInstruction) -> Vec<TokenStream> {
+    ix.args
+        .iter()
+        .enumerate()
+        .filter(|(_, arg)| !is_ident_type(&arg.ty))
+        .map(|(idx, arg)| {
+            let alias = format_ident!("{}", arg_type_name(ix, idx));
+            let ty = render_idl_type(&arg.ty);
+            quote! { pub type #alias = #ty; }
+        })
+        .collect()
+}
+
+/// The default of the account in the builder, PDAs are only derived when all
+/// their seeds are accounts since the builder args are unnamed.
+fn account_default(
+    ix: &IdlInstruction,
+    account: &IdlAccount,
+) -> Option<AccountDefault> {
+    if let Some(address) = &account.address {
+        return Some(AccountDefault::Address(address.clone()));
+    }
+    let pda = account.pda.as_ref()?;
+    let accounts = flatten_accounts(&ix.accounts);
+    let seeds_are_accounts = pda
+        .seeds
+        .iter()
+        .all(|seed| accounts.iter().any(|account| &account.name == seed));
+    if !seeds_are_accounts {
+        return None;
+    }
+    Some(AccountDefault::Pda {
+        account: format!("crate::accounts::{}", pda.account),
+        seeds: pda.seeds.iter().map(|seed| seed.to_snake_case()).collect(),
+    })
+}
+
+fn builder_variant(ix: &IdlInstruction) -> Result<BuilderVariant> {
+    let field_tys = (0..ix.args.len())
+        .map(|idx| {
+            RustType::try_from(arg_type_name(ix, idx).as_str())
+                .map_err(Error::from)
+        })
+        .collect::<Result<Vec<RustType>>>()?;
+
+    let accounts = flatten_accounts(&ix.accounts)
+        .into_iter()
+        .map(|account| InstructionAccount {
+            ident: Ident::new("account", Span::call_site()),
+            index: None,
+            name: account.name.to_snake_case(),
+            writable: account.is_mut,
+            signer: account.is_signer,
+            optional_signer: account.is_optional_signer,
+            desc: account.docs.as_ref().map(|docs| docs.join(" ")),
+            optional: account.is_optional,
+            default: account_default(ix, account),
+        })
+        .collect();
+
+    Ok(BuilderVariant {
+        ident: format_ident!("{}", ix.name),
+        field_tys: InstructionVariantFields::Unnamed(field_tys),
+        accounts,
+        arguments: vec![],
+        discriminant: ix.discriminant.value as usize,
+    })
+}
+
+fn render_variant_docs(ix: &IdlInstruction) -> TokenStream {
+    let accounts = flatten_accounts(&ix.accounts);
+    if accounts.is_empty() {
+        return TokenStream::new();
+    }
+    let mut docs = vec![" Accounts:".to_string(), String::new()];
+    for (idx, account) in accounts.iter().enumerate() {
+        let mut flags = Vec::new();
+        if account.is_mut {
+            flags.push("writable");
+        }
+        if account.is_signer {
+            flags.push("signer");
+        }
+        if account.is_optional_signer {
+            flags.push("optional signer");
+        }
+        if account.is_optional {
+            flags.push("optional");
+        }
+        let desc = account
+            .docs
+            .as_ref()
+            .map(|docs| format!(": {}", docs.join(" ")))
+            .unwrap_or_default();
+        docs.push(format!(
+            " {}. `[{}]` {}{}",
+            idx,
+            flags.join(", "),
+            account.name.to_snake_case(),
+            desc
+        ));
+    }
+    quote! { #(#[doc = #docs])* }
+}
+
+pub(crate) fn render_instructions(
+    program_name: &str,
+    instructions: &[IdlInstruction],
+) -> Result<TokenStream> {
+    let enum_ident =
+        format_ident!("{}Instruction", program_name.to_camel_case());
+
+    let aliases = instructions.iter().flat_map(render_arg_aliases);
+
+    let variants = instructions.iter().map(|ix| {
+        let docs = render_variant_docs(ix);
+        let name = format_ident!("{}", ix.name);
+        if ix.args.is_empty() {
+            quote! { #docs #name }
+        } else {
+            let tys = (0..ix.args.len())
+                .map(|idx| format_ident!("{}", arg_type_name(ix, idx)));
+            quote! { #docs #name(#(#tys),*) }
+        }
+    });
+
+    // The discriminant is written explicitly since it doesn't have to match the variant index
+    let serialize_arms = instructions.iter().map(|ix| {
+        let name = format_ident!("{}", ix.name);
+        let discriminant = Literal::u8_suffixed(ix.discriminant.value);
+        if ix.args.is_empty() {
+            quote! { Self::#name => #discriminant.serialize(writer) }
+        } else {
+            let args = (0..ix.args.len())
+                .map(|idx| format_ident!("arg{}", idx))
+                .collect::<Vec<Ident>>();
+            quote! {
+                Self::#name(#(#args),*) => {
+                    #discriminant.serialize(writer)?;
+                    #(#args.serialize(writer)?;)*
+                    Ok(())
+                }
+            }
+        }
+    });
+
+    let builder = Builder {
+        ident: enum_ident.clone(),
+        variants: instructions
+            .iter()
+            .map(builder_variant)
+            .collect::<Result<Vec<BuilderVariant>>>()?,
+        crate_paths: CratePaths::default(),
+    };
+    let builders = render_builders_impl(&builder).map_err(Error::from)?;
+
+    Ok(quote! {
+        #![doc = " Instructions of the program and builders to create them."]
+
+        #[allow(unused_imports)]
+        use crate::types::*;
+        use borsh::BorshSerialize;
+        #[allow(unused_imports)]
+        use solana_program::pubkey::Pubkey;
+
+        #(#aliases)*
+
+        /// Instructions of the program along with their arguments.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #enum_ident {
+            #(#variants),*
+        }
+
+        impl BorshSerialize for #enum_ident {
+            fn serialize<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> std::io::Result<()> {
+                match self {
+                    #(#serialize_arms),*
+                }
+            }
+        }
+
+        #builders
+    })
+}
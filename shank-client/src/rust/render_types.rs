@@ -0,0 +1,211 @@
+use heck::SnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use shank_idl::{
+    idl_field::IdlField,
+    idl_type::IdlType,
+    idl_type_definition::{IdlTypeDefinition, IdlTypeDefinitionTy},
+    idl_variant::{EnumFields, IdlEnumVariant},
+};
+
+/// Renders the Rust type which is serialized the same way as the [IdlType].
+///
+/// `FixedSizeOption`s are rendered as their inner type since they are stored
+/// as the raw value with a sentinel representing `None`.
+pub(crate) fn render_idl_type(ty: &IdlType) -> TokenStream {
+    match ty {
+        IdlType::Array(inner, size) => {
+            let inner = render_idl_type(inner);
+            let size = Literal::usize_unsuffixed(*size);
+            quote! { [#inner; #size] }
+        }
+        IdlType::Bool => quote! { bool },
+        IdlType::Bytes => quote! { Vec<u8> },
+        IdlType::Defined(name) => {
+            let ident = format_ident!("{}", name);
+            quote! { #ident }
+        }
+        IdlType::I128 => quote! { i128 },
+        IdlType::I16 => quote! { i16 },
+        IdlType::I32 => quote! { i32 },
+        IdlType::I64 => quote! { i64 },
+        IdlType::I8 => quote! { i8 },
+        IdlType::Option(inner) => {
+            let inner = render_idl_type(inner);
+            quote! { Option<#inner> }
+        }
+        IdlType::FixedSizeOption { inner, .. } => render_idl_type(inner),
+        IdlType::Tuple(inners) => {
+            let inners = inners.iter().map(render_idl_type);
+            quote! { (#(#inners),*) }
+        }
+        IdlType::PublicKey => quote! { Pubkey },
+        IdlType::String => quote! { String },
+        IdlType::U128 => quote! { u128 },
+        IdlType::U16 => quote! { u16 },
+        IdlType::U32 => quote! { u32 },
+        IdlType::U64 => quote! { u64 },
+        IdlType::U8 => quote! { u8 },
+        IdlType::Vec(inner) => {
+            let inner = render_idl_type(inner);
+            quote! { Vec<#inner> }
+        }
+        IdlType::HashMap(key, val) => {
+            let (key, val) = (render_idl_type(key), render_idl_type(val));
+            quote! { std::collections::HashMap<#key, #val> }
+        }
+        IdlType::BTreeMap(key, val) => {
+            let (key, val) = (render_idl_type(key), render_idl_type(val));
+            quote! { std::collections::BTreeMap<#key, #val> }
+        }
+        IdlType::HashSet(inner) => {
+            let inner = render_idl_type(inner);
+            quote! { std::collections::HashSet<#inner> }
+        }
+        IdlType::BTreeSet(inner) => {
+            let inner = render_idl_type(inner);
+            quote! { std::collections::BTreeSet<#inner> }
+        }
+    }
+}
+
+/// Returns `true` if the type renders as a single identifier, i.e. `u64` or `Pubkey`.
+pub(crate) fn is_ident_type(ty: &IdlType) -> bool {
+    matches!(
+        ty,
+        IdlType::Bool
+            | IdlType::Defined(_)
+            | IdlType::I128
+            | IdlType::I16
+            | IdlType::I32
+            | IdlType::I64
+            | IdlType::I8
+            | IdlType::PublicKey
+            | IdlType::String
+            | IdlType::U128
+            | IdlType::U16
+            | IdlType::U32
+            | IdlType::U64
+            | IdlType::U8
+    )
+}
+
+fn render_field_docs(ty: &IdlType) -> TokenStream {
+    match ty {
+        IdlType::FixedSizeOption {
+            sentinel: Some(sentinel),
+            ..
+        } => {
+            let doc = match sentinel.first() {
+                Some(first) if sentinel.iter().all(|byte| byte == first) => {
+                    format!(
+                        " `None` is represented by `[{}; {}]`.",
+                        first,
+                        sentinel.len()
+                    )
+                }
+                _ => format!(" `None` is represented by `{:?}`.", sentinel),
+            };
+            quote! { #[doc = #doc] }
+        }
+        _ => TokenStream::new(),
+    }
+}
+
+fn render_named_fields(fields: &[IdlField], vis: TokenStream) -> TokenStream {
+    let fields = fields.iter().map(|field| {
+        let docs = render_field_docs(&field.ty);
+        let name = format_ident!("{}", field.name.to_snake_case());
+        let ty = render_idl_type(&field.ty);
+        quote! {
+            #docs
+            #vis #name: #ty
+        }
+    });
+    quote! { { #(#fields),* } }
+}
+
+fn render_variant(variant: &IdlEnumVariant) -> TokenStream {
+    let name = format_ident!("{}", variant.name);
+    match &variant.fields {
+        None => quote! { #name },
+        Some(EnumFields::Named(fields)) => {
+            let fields = render_named_fields(fields, TokenStream::new());
+            quote! { #name #fields }
+        }
+        Some(EnumFields::Tuple(tys)) => {
+            let tys = tys.iter().map(render_idl_type);
+            quote! { #name(#(#tys),*) }
+        }
+    }
+}
+
+/// Renders the struct or enum of the type definition deriving Borsh serialization.
+pub(crate) fn render_type_definition(
+    type_definition: &IdlTypeDefinition,
+) -> TokenStream {
+    let name = format_ident!("{}", type_definition.name);
+    let derives = quote! {
+        #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    };
+    match &type_definition.ty {
+        IdlTypeDefinitionTy::Struct { fields } => {
+            let fields = render_named_fields(fields, quote! { pub });
+            quote! {
+                #derives
+                pub struct #name #fields
+            }
+        }
+        IdlTypeDefinitionTy::Enum { variants } => {
+            let variants = variants.iter().map(render_variant);
+            quote! {
+                #derives
+                pub enum #name {
+                    #(#variants),*
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_renders(ty: IdlType, expected: &str) {
+        let expected = expected.parse::<TokenStream>().unwrap().to_string();
+        assert_eq!(render_idl_type(&ty).to_string(), expected);
+    }
+
+    #[test]
+    fn render_idl_type_composites() {
+        assert_renders(IdlType::Bytes, "Vec<u8>");
+        assert_renders(IdlType::Array(Box::new(IdlType::U8), 32), "[u8; 32]");
+        assert_renders(
+            IdlType::Option(Box::new(IdlType::Defined("Data".to_string()))),
+            "Option<Data>",
+        );
+        assert_renders(
+            IdlType::Tuple(vec![IdlType::PublicKey, IdlType::U64]),
+            "(Pubkey, u64)",
+        );
+        assert_renders(
+            IdlType::HashMap(
+                Box::new(IdlType::String),
+                Box::new(IdlType::Vec(Box::new(IdlType::I32))),
+            ),
+            "std::collections::HashMap<String, Vec<i32> >",
+        );
+    }
+
+    #[test]
+    fn render_idl_type_fixed_size_option_as_inner() {
+        assert_renders(
+            IdlType::FixedSizeOption {
+                inner: Box::new(IdlType::U64),
+                sentinel: Some(vec![0xff; 8]),
+            },
+            "u64",
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use heck::KebabCase;
+use shank_idl::{idl::Idl, idl_type_definition::IdlTypeDefinition};
+
+use crate::{
+    common::{program_address, string_literal, with_explicit_padding},
+    GeneratedFile,
+};
+
+mod render_accounts;
+mod render_errors;
+mod render_instructions;
+mod render_types;
+
+use render_accounts::render_accounts;
+use render_errors::render_errors;
+use render_instructions::render_instructions;
+use render_types::{render_type_definition, serializer_name};
+
+/// Borsh serializers the generated modules build on.
+const SERIALIZER_TS: &str = include_str!("serializer.ts");
+
+/// Generates the files of a TypeScript client package for the program
+/// described by the IDL, including its `package.json`.
+///
+/// The package contains the accounts along with fetchers, the types of the
+/// program, an instruction creator for each instruction and an error map keyed
+/// by the error code. All of them only depend on `@solana/web3.js`.
+pub fn generate_ts_client(idl: &Idl) -> Result<Vec<GeneratedFile>> {
+    let idl = &with_explicit_padding(idl);
+    let program_id = program_address(idl)?;
+
+    let types = format!(
+        "import {{ PublicKey }} from \"@solana/web3.js\";\n\
+         import * as s from \"./serializer\";\n\n{}",
+        idl.types
+            .iter()
+            .map(render_type_definition)
+            .collect::<Vec<String>>()
+            .join("\n")
+    );
+    let errors =
+        render_errors(&idl.name, idl.errors.as_deref().unwrap_or_default());
+    let program_id = format!(
+        "import {{ PublicKey }} from \"@solana/web3.js\";\n\n\
+         export const PROGRAM_ID = new PublicKey({});\n",
+        string_literal(program_id)
+    );
+    let index = r#"export * from "./accounts";
+export * from "./errors";
+export * from "./instructions";
+export * from "./programId";
+export * from "./types";
+export * as serializer from "./serializer";
+"#;
+
+    Ok(vec![
+        GeneratedFile::new("package.json", render_package_json(idl)),
+        GeneratedFile::new("tsconfig.json", TSCONFIG_JSON),
+        GeneratedFile::new("src/index.ts", index),
+        GeneratedFile::new("src/programId.ts", program_id),
+        GeneratedFile::new("src/serializer.ts", SERIALIZER_TS),
+        GeneratedFile::new("src/types.ts", types),
+        GeneratedFile::new(
+            "src/accounts.ts",
+            render_accounts(&idl.accounts, &idl.types),
+        ),
+        GeneratedFile::new(
+            "src/instructions.ts",
+            render_instructions(&idl.instructions, &idl.types),
+        ),
+        GeneratedFile::new("src/errors.ts", errors),
+    ])
+}
+
+/// Renders importing all types along with their serializers.
+pub(crate) fn render_types_import(types: &[IdlTypeDefinition]) -> String {
+    if types.is_empty() {
+        return String::new();
+    }
+    let names = types
+        .iter()
+        .flat_map(|ty| vec![ty.name.to_string(), serializer_name(&ty.name)])
+        .map(|name| format!("  {},\n", name))
+        .collect::<String>();
+    format!("import {{\n{}}} from \"./types\";\n", names)
+}
+
+fn render_package_json(idl: &Idl) -> String {
+    let name = idl.name.to_kebab_case();
+    let version = if idl.version.is_empty() {
+        "0.1.0"
+    } else {
+        idl.version.as_str()
+    };
+    format!(
+        r#"{{
+  "name": {package_name},
+  "version": {version},
+  "description": {description},
+  "main": "dist/index.js",
+  "types": "dist/index.d.ts",
+  "files": ["dist"],
+  "scripts": {{
+    "build": "tsc"
+  }},
+  "dependencies": {{
+    "@solana/web3.js": "^1.87.0"
+  }},
+  "devDependencies": {{
+    "@types/node": "^20.0.0",
+    "typescript": "^5.0.0"
+  }}
+}}
+"#,
+        package_name = string_literal(&format!("{}-client", name)),
+        version = string_literal(version),
+        description =
+            string_literal(&format!("Client for the {} program", name)),
+    )
+}
+
+const TSCONFIG_JSON: &str = r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "module": "commonjs",
+    "declaration": true,
+    "strict": true,
+    "esModuleInterop": true,
+    "skipLibCheck": true,
+    "outDir": "dist",
+    "rootDir": "src"
+  },
+  "include": ["src"]
+}
+"#;
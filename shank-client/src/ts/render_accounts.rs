@@ -0,0 +1,63 @@
+use shank_idl::idl_type_definition::IdlTypeDefinition;
+
+use super::{
+    render_types::{render_type_definition, serializer_name},
+    render_types_import,
+};
+
+fn render_account(account: &IdlTypeDefinition) -> String {
+    let name = &account.name;
+    format!(
+        r#"{type_definition}
+/** Decodes the {name} account from its data, ignoring any trailing bytes. */
+export function decode{name}(data: Uint8Array): {name} {{
+  return s.deserialize({serializer}, data);
+}}
+
+/** Fetches and decodes the {name} account, failing if it doesn't exist. */
+export async function fetch{name}(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<{name}> {{
+  const account = await fetchMaybe{name}(connection, address, commitment);
+  if (account == null) {{
+    throw new Error(`{name} account ${{address.toBase58()}} not found`);
+  }}
+  return account;
+}}
+
+/** Fetches and decodes the {name} account, resolving `null` if it doesn't exist. */
+export async function fetchMaybe{name}(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<{name} | null> {{
+  const info = await connection.getAccountInfo(address, commitment);
+  return info == null ? null : decode{name}(info.data);
+}}
+"#,
+        type_definition = render_type_definition(account),
+        name = name,
+        serializer = serializer_name(name),
+    )
+}
+
+pub(crate) fn render_accounts(
+    accounts: &[IdlTypeDefinition],
+    types: &[IdlTypeDefinition],
+) -> String {
+    let accounts = accounts
+        .iter()
+        .map(render_account)
+        .collect::<Vec<String>>()
+        .join("\n");
+    format!(
+        "import {{ Commitment, Connection, PublicKey }} from \"@solana/web3.js\";\n\
+         import * as s from \"./serializer\";\n\
+         {types_import}\n\
+         {accounts}",
+        types_import = render_types_import(types),
+        accounts = accounts,
+    )
+}
@@ -0,0 +1,52 @@
+use heck::CamelCase;
+use shank_idl::idl_error_code::IdlErrorCode;
+
+use crate::common::string_literal;
+
+pub(crate) fn render_errors(
+    program_name: &str,
+    errors: &[IdlErrorCode],
+) -> String {
+    let class_name = format!("{}Error", program_name.to_camel_case());
+    let entries = errors
+        .iter()
+        .map(|error| {
+            let msg = error.msg.as_deref().unwrap_or(&error.name);
+            format!(
+                "    [{code}, {{ name: {name}, msg: {msg} }}],\n",
+                code = error.code,
+                name = string_literal(&error.name),
+                msg = string_literal(msg),
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"/** Error returned by the program, identified by its custom error code. */
+export class {class_name} extends Error {{
+  constructor(
+    readonly code: number,
+    readonly errorName: string,
+    message: string
+  ) {{
+    super(message);
+    this.name = {class_name_string};
+  }}
+}}
+
+/** Name and message of each error keyed by its custom error code. */
+export const ERRORS_BY_CODE: ReadonlyMap<number, {{ name: string; msg: string }}> =
+  new Map([
+{entries}  ]);
+
+/** Resolves the error from its custom program error code. */
+export function errorFromCode(code: number): {class_name} | null {{
+  const error = ERRORS_BY_CODE.get(code);
+  return error == null ? null : new {class_name}(code, error.name, error.msg);
+}}
+"#,
+        class_name = class_name,
+        class_name_string = string_literal(&class_name),
+        entries = entries,
+    )
+}
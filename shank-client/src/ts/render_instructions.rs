@@ -0,0 +1,214 @@
+use heck::MixedCase;
+use shank_idl::{
+    idl_instruction::{IdlAccount, IdlInstruction},
+    idl_type_definition::IdlTypeDefinition,
+};
+
+use super::{
+    render_types::{render_struct_serializer, render_ts_fields},
+    render_types_import,
+};
+use crate::common::{default_address, flatten_accounts, string_literal};
+
+/// Renders the lines as a JSDoc comment.
+fn render_doc(lines: &[String], indent: &str) -> String {
+    let lines = lines
+        .iter()
+        .map(|line| line.replace("*/", "*\\/"))
+        .collect::<Vec<String>>();
+    match lines.as_slice() {
+        [] => String::new(),
+        [line] => format!("{}/** {} */\n", indent, line),
+        lines => format!(
+            "{indent}/**\n{lines}{indent} */\n",
+            indent = indent,
+            lines = lines
+                .iter()
+                .map(|line| format!("{} * {}\n", indent, line))
+                .collect::<String>()
+        ),
+    }
+}
+
+fn render_accounts_type(
+    ix: &IdlInstruction,
+    accounts: &[&IdlAccount],
+) -> String {
+    let fields = accounts
+        .iter()
+        .map(|account| {
+            let mut docs = account.docs.clone().unwrap_or_default();
+            let default = default_address(account);
+            if let Some(address) = default {
+                docs.push(format!("Defaults to `{}`", address));
+            }
+            let docs = render_doc(&docs, "  ");
+            let optional = if account.is_optional || default.is_some() {
+                "?"
+            } else {
+                ""
+            };
+            let signer_flag = if account.is_optional_signer {
+                format!(
+                    "  /** Whether {} signs the transaction. */\n  {}IsSigner?: boolean;\n",
+                    account.name, account.name
+                )
+            } else {
+                String::new()
+            };
+            format!(
+                "{}  {}{}: PublicKey;\n{}",
+                docs, account.name, optional, signer_flag
+            )
+        })
+        .collect::<String>();
+    format!(
+        "/** Accounts of the {name} instruction. */\n\
+         export type {name}InstructionAccounts = {{\n{fields}}};\n",
+        name = ix.name,
+        fields = fields,
+    )
+}
+
+/// Renders pushing the account meta of the account onto the `keys`.
+///
+/// Optional accounts which aren't provided are omitted when the instruction
+/// uses the legacy strategy and replaced with the program id otherwise.
+fn render_account_meta(ix: &IdlInstruction, account: &IdlAccount) -> String {
+    let name = &account.name;
+    let is_signer = if account.is_optional_signer {
+        format!("accounts.{}IsSigner ?? false", name)
+    } else {
+        account.is_signer.to_string()
+    };
+    let meta = |pubkey: &str| {
+        format!(
+            "{{ pubkey: {}, isWritable: {}, isSigner: {} }}",
+            pubkey, account.is_mut, is_signer
+        )
+    };
+
+    if let Some(address) = default_address(account) {
+        let pubkey = format!(
+            "accounts.{} ?? new PublicKey({})",
+            name,
+            string_literal(address)
+        );
+        return format!("  keys.push({});\n", meta(&pubkey));
+    }
+    if !account.is_optional {
+        return format!(
+            "  keys.push({});\n",
+            meta(&format!("accounts.{}", name))
+        );
+    }
+
+    let provided = format!("accounts.{}", name);
+    if ix.legacy_optional_accounts_strategy.unwrap_or(false) {
+        format!(
+            "  if ({provided} != null) {{\n    keys.push({meta});\n  }}\n",
+            provided = provided,
+            meta = meta(&provided),
+        )
+    } else {
+        format!(
+            "  keys.push(\n    {provided} == null\n      \
+             ? {{ pubkey: programId, isWritable: false, isSigner: false }}\n      \
+             : {meta}\n  );\n",
+            provided = provided,
+            meta = meta(&provided),
+        )
+    }
+}
+
+fn render_instruction(ix: &IdlInstruction) -> String {
+    let name = &ix.name;
+    let fn_name = format!("create{}Instruction", name);
+    let prefix = format!("{}Instruction", name.to_mixed_case());
+    let accounts = flatten_accounts(&ix.accounts);
+    let has_args = !ix.args.is_empty();
+
+    let args = if has_args {
+        format!(
+            "export type {name}InstructionArgs = {{\n{fields}}};\n\n\
+             export const {prefix}ArgsSerializer: s.Serializer<{name}InstructionArgs> = {serializer};\n\n",
+            name = name,
+            prefix = prefix,
+            fields = render_ts_fields(&ix.args, "  "),
+            serializer = render_struct_serializer(
+                &format!("{}InstructionArgs", name),
+                &ix.args
+            ),
+        )
+    } else {
+        String::new()
+    };
+
+    let account_metas = accounts
+        .iter()
+        .map(|account| render_account_meta(ix, account))
+        .collect::<String>();
+
+    let (args_param, serialize_args) = if has_args {
+        (
+            format!("  args: {}InstructionArgs,\n", name),
+            format!("  {}ArgsSerializer.serialize(writer, args);\n", prefix),
+        )
+    } else {
+        (String::new(), String::new())
+    };
+
+    format!(
+        r#"// -----------------
+// {name}
+// -----------------
+{accounts_type}
+{args}export const {prefix}Discriminator = {discriminant};
+
+/** Creates the {name} instruction. */
+export function {fn_name}(
+  accounts: {name}InstructionAccounts,
+{args_param}  programId: PublicKey = PROGRAM_ID
+): TransactionInstruction {{
+  const keys: AccountMeta[] = [];
+{account_metas}
+  const writer = new s.Writer();
+  s.u8.serialize(writer, {prefix}Discriminator);
+{serialize_args}  return new TransactionInstruction({{
+    programId,
+    keys,
+    data: Buffer.from(writer.toBytes()),
+  }});
+}}
+"#,
+        name = name,
+        accounts_type = render_accounts_type(ix, &accounts),
+        args = args,
+        prefix = prefix,
+        discriminant = ix.discriminant.value,
+        fn_name = fn_name,
+        args_param = args_param,
+        account_metas = account_metas,
+        serialize_args = serialize_args,
+    )
+}
+
+pub(crate) fn render_instructions(
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDefinition],
+) -> String {
+    let instructions = instructions
+        .iter()
+        .map(render_instruction)
+        .collect::<Vec<String>>()
+        .join("\n");
+    format!(
+        "import {{\n  AccountMeta,\n  PublicKey,\n  TransactionInstruction,\n}} from \"@solana/web3.js\";\n\
+         import {{ PROGRAM_ID }} from \"./programId\";\n\
+         import * as s from \"./serializer\";\n\
+         {types_import}\n\
+         {instructions}",
+        types_import = render_types_import(types),
+        instructions = instructions,
+    )
+}
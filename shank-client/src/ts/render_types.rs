@@ -0,0 +1,300 @@
+use heck::MixedCase;
+use shank_idl::{
+    idl_field::IdlField,
+    idl_type::IdlType,
+    idl_type_definition::{IdlTypeDefinition, IdlTypeDefinitionTy},
+    idl_variant::EnumFields,
+};
+
+use crate::common::string_literal;
+
+/// Name of the serializer const of a defined type, i.e. `vaultKindSerializer`.
+pub(crate) fn serializer_name(type_name: &str) -> String {
+    format!("{}Serializer", type_name.to_mixed_case())
+}
+
+/// Renders the TypeScript type the [IdlType] is deserialized into.
+///
+/// Integers which don't fit into a `number` are represented as `bigint`.
+pub(crate) fn render_ts_type(ty: &IdlType) -> String {
+    match ty {
+        IdlType::Bool => "boolean".to_string(),
+        IdlType::U8
+        | IdlType::U16
+        | IdlType::U32
+        | IdlType::I8
+        | IdlType::I16
+        | IdlType::I32 => "number".to_string(),
+        IdlType::U64 | IdlType::U128 | IdlType::I64 | IdlType::I128 => {
+            "bigint".to_string()
+        }
+        IdlType::String => "string".to_string(),
+        IdlType::Bytes => "Uint8Array".to_string(),
+        IdlType::PublicKey => "PublicKey".to_string(),
+        IdlType::Defined(name) => name.to_string(),
+        IdlType::Option(inner) | IdlType::FixedSizeOption { inner, .. } => {
+            format!("{} | null", render_ts_type(inner))
+        }
+        IdlType::Array(inner, _) | IdlType::Vec(inner) => {
+            format!("Array<{}>", render_ts_type(inner))
+        }
+        IdlType::Tuple(inners) => format!(
+            "[{}]",
+            inners
+                .iter()
+                .map(render_ts_type)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        IdlType::HashMap(key, val) | IdlType::BTreeMap(key, val) => {
+            format!("Map<{}, {}>", render_ts_type(key), render_ts_type(val))
+        }
+        IdlType::HashSet(inner) | IdlType::BTreeSet(inner) => {
+            format!("Set<{}>", render_ts_type(inner))
+        }
+    }
+}
+
+/// Renders the expression creating the serializer of the [IdlType] using the
+/// serializer module imported as `s`.
+pub(crate) fn render_serializer(ty: &IdlType) -> String {
+    match ty {
+        IdlType::Bool => "s.bool".to_string(),
+        IdlType::U8 => "s.u8".to_string(),
+        IdlType::U16 => "s.u16".to_string(),
+        IdlType::U32 => "s.u32".to_string(),
+        IdlType::U64 => "s.u64".to_string(),
+        IdlType::U128 => "s.u128".to_string(),
+        IdlType::I8 => "s.i8".to_string(),
+        IdlType::I16 => "s.i16".to_string(),
+        IdlType::I32 => "s.i32".to_string(),
+        IdlType::I64 => "s.i64".to_string(),
+        IdlType::I128 => "s.i128".to_string(),
+        IdlType::String => "s.string".to_string(),
+        IdlType::Bytes => "s.bytes".to_string(),
+        IdlType::PublicKey => "s.publicKey".to_string(),
+        // Resolved lazily since types may be declared after their use
+        IdlType::Defined(name) => {
+            format!("s.lazy(() => {})", serializer_name(name))
+        }
+        IdlType::Option(inner) => {
+            format!("s.option({})", render_serializer(inner))
+        }
+        IdlType::FixedSizeOption { inner, sentinel } => {
+            let sentinel = match sentinel {
+                Some(sentinel) => format!(
+                    "new Uint8Array([{}])",
+                    sentinel
+                        .iter()
+                        .map(u8::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                None => "null".to_string(),
+            };
+            format!(
+                "s.fixedSizeOption({}, {})",
+                render_serializer(inner),
+                sentinel
+            )
+        }
+        IdlType::Array(inner, size) => {
+            format!("s.array({}, {})", render_serializer(inner), size)
+        }
+        IdlType::Vec(inner) => format!("s.vec({})", render_serializer(inner)),
+        IdlType::Tuple(inners) => format!(
+            "s.tuple<{}>([{}])",
+            render_ts_type(ty),
+            inners
+                .iter()
+                .map(render_serializer)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        IdlType::HashMap(key, val) | IdlType::BTreeMap(key, val) => format!(
+            "s.map({}, {})",
+            render_serializer(key),
+            render_serializer(val)
+        ),
+        IdlType::HashSet(inner) | IdlType::BTreeSet(inner) => {
+            format!("s.set({})", render_serializer(inner))
+        }
+    }
+}
+
+/// Renders the fields of an object type, one per line with the given indent.
+pub(crate) fn render_ts_fields(fields: &[IdlField], indent: &str) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}{}: {};\n",
+                indent,
+                field.name,
+                render_ts_type(&field.ty)
+            )
+        })
+        .collect()
+}
+
+/// Renders the serializer of a struct with the given fields.
+pub(crate) fn render_struct_serializer(
+    ty_name: &str,
+    fields: &[IdlField],
+) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "  [{}, {}],\n",
+                string_literal(&field.name),
+                render_serializer(&field.ty)
+            )
+        })
+        .collect::<String>();
+    format!("s.struct<{}>([\n{}])", ty_name, fields)
+}
+
+fn render_enum_variant_type(name: &str, fields: &Option<EnumFields>) -> String {
+    let kind = format!("__kind: {}", string_literal(name));
+    match fields {
+        None => format!("{{ {} }}", kind),
+        Some(EnumFields::Named(fields)) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    format!("; {}: {}", field.name, render_ts_type(&field.ty))
+                })
+                .collect::<String>();
+            format!("{{ {}{} }}", kind, fields)
+        }
+        Some(EnumFields::Tuple(tys)) => {
+            format!(
+                "{{ {}; fields: {} }}",
+                kind,
+                render_ts_type(&IdlType::Tuple(tys.clone()))
+            )
+        }
+    }
+}
+
+fn render_enum_variant_serializer(fields: &Option<EnumFields>) -> String {
+    match fields {
+        None => "null".to_string(),
+        Some(EnumFields::Named(fields)) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "[{}, {}]",
+                        string_literal(&field.name),
+                        render_serializer(&field.ty)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("s.struct<any>([{}])", fields)
+        }
+        Some(EnumFields::Tuple(tys)) => format!(
+            "s.struct<any>([[\"fields\", {}]])",
+            render_serializer(&IdlType::Tuple(tys.clone()))
+        ),
+    }
+}
+
+/// Renders the type along with its serializer const.
+///
+/// Enums without any data are rendered as TypeScript enums while all others
+/// become unions of objects tagged by `__kind`.
+pub(crate) fn render_type_definition(
+    type_definition: &IdlTypeDefinition,
+) -> String {
+    let name = &type_definition.name;
+    let serializer = serializer_name(name);
+    match &type_definition.ty {
+        IdlTypeDefinitionTy::Struct { fields } => format!(
+            "export type {name} = {{\n{fields}}};\n\n\
+             export const {serializer}: s.Serializer<{name}> = {struct_serializer};\n",
+            name = name,
+            fields = render_ts_fields(fields, "  "),
+            serializer = serializer,
+            struct_serializer = render_struct_serializer(name, fields),
+        ),
+        IdlTypeDefinitionTy::Enum { variants }
+            if variants.iter().all(|variant| variant.fields.is_none()) =>
+        {
+            let variant_names = variants
+                .iter()
+                .map(|variant| format!("  {},\n", variant.name))
+                .collect::<String>();
+            format!(
+                "export enum {name} {{\n{variants}}}\n\n\
+                 export const {serializer}: s.Serializer<{name}> = s.scalarEnum<{name}>({len});\n",
+                name = name,
+                variants = variant_names,
+                serializer = serializer,
+                len = variants.len(),
+            )
+        }
+        IdlTypeDefinitionTy::Enum { variants } => {
+            let variant_types = variants
+                .iter()
+                .map(|variant| {
+                    format!(
+                        "  | {}\n",
+                        render_enum_variant_type(&variant.name, &variant.fields)
+                    )
+                })
+                .collect::<String>();
+            let variant_serializers = variants
+                .iter()
+                .map(|variant| {
+                    format!(
+                        "  [{}, {}],\n",
+                        string_literal(&variant.name),
+                        render_enum_variant_serializer(&variant.fields)
+                    )
+                })
+                .collect::<String>();
+            format!(
+                "export type {name} =\n{variant_types};\n\n\
+                 export const {serializer}: s.Serializer<{name}> = s.dataEnum<{name}>([\n{variant_serializers}]);\n",
+                name = name,
+                variant_types = variant_types.trim_end(),
+                serializer = serializer,
+                variant_serializers = variant_serializers,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_ts_type_and_serializer_composites() {
+        let ty = IdlType::HashMap(
+            Box::new(IdlType::String),
+            Box::new(IdlType::Tuple(vec![IdlType::U64, IdlType::PublicKey])),
+        );
+        assert_eq!(render_ts_type(&ty), "Map<string, [bigint, PublicKey]>");
+        assert_eq!(
+            render_serializer(&ty),
+            "s.map(s.string, s.tuple<[bigint, PublicKey]>([s.u64, s.publicKey]))"
+        );
+    }
+
+    #[test]
+    fn render_serializer_fixed_size_option_with_sentinel() {
+        let ty = IdlType::FixedSizeOption {
+            inner: Box::new(IdlType::U16),
+            sentinel: Some(vec![0xff, 0xff]),
+        };
+        assert_eq!(render_ts_type(&ty), "number | null");
+        assert_eq!(
+            render_serializer(&ty),
+            "s.fixedSizeOption(s.u16, new Uint8Array([255, 255]))"
+        );
+    }
+}
@@ -0,0 +1,412 @@
+// Borsh compatible serializers used by the generated client.
+import { PublicKey } from "@solana/web3.js";
+
+export type Serializer<T> = {
+  serialize: (writer: Writer, value: T) => void;
+  deserialize: (reader: Reader) => T;
+  /**
+   * Orders values the way the Rust type derives `Ord`, which Borsh uses to
+   * sort map keys and set items.
+   */
+  compare?: (a: T, b: T) => number;
+};
+
+export class Writer {
+  private buffer = new Uint8Array(256);
+  private length = 0;
+
+  writeBytes(bytes: Uint8Array) {
+    if (this.length + bytes.length > this.buffer.length) {
+      const grown = new Uint8Array(
+        Math.max(this.buffer.length * 2, this.length + bytes.length)
+      );
+      grown.set(this.buffer.subarray(0, this.length));
+      this.buffer = grown;
+    }
+    this.buffer.set(bytes, this.length);
+    this.length += bytes.length;
+  }
+
+  toBytes(): Uint8Array {
+    return this.buffer.slice(0, this.length);
+  }
+}
+
+export class Reader {
+  offset = 0;
+
+  constructor(readonly data: Uint8Array) {}
+
+  readBytes(length: number): Uint8Array {
+    if (this.offset + length > this.data.length) {
+      throw new Error(
+        `Unexpected end of data reading ${length} bytes at offset ${this.offset}`
+      );
+    }
+    const bytes = this.data.subarray(this.offset, this.offset + length);
+    this.offset += length;
+    return bytes;
+  }
+}
+
+export function serialize<T>(serializer: Serializer<T>, value: T): Uint8Array {
+  const writer = new Writer();
+  serializer.serialize(writer, value);
+  return writer.toBytes();
+}
+
+/** Deserializes the value from the start of the data ignoring trailing bytes. */
+export function deserialize<T>(serializer: Serializer<T>, data: Uint8Array): T {
+  return serializer.deserialize(new Reader(data));
+}
+
+function compareNumbers<T extends number | bigint>(a: T, b: T): number {
+  return a < b ? -1 : a > b ? 1 : 0;
+}
+
+function compareBytes(a: Uint8Array, b: Uint8Array): number {
+  return compareSeqs(compareNumbers, Array.from(a), Array.from(b));
+}
+
+function compareSeqs<T>(
+  compare: (a: T, b: T) => number,
+  a: T[],
+  b: T[]
+): number {
+  for (let idx = 0; idx < Math.min(a.length, b.length); idx++) {
+    const ordering = compare(a[idx], b[idx]);
+    if (ordering !== 0) return ordering;
+  }
+  return a.length - b.length;
+}
+
+/** Returns the first of the orderings which isn't equal. */
+function compareInOrder(orderings: (() => number)[]): number {
+  for (const ordering of orderings) {
+    const result = ordering();
+    if (result !== 0) return result;
+  }
+  return 0;
+}
+
+function comparer<T>(serializer: Serializer<T>): (a: T, b: T) => number {
+  const { compare } = serializer;
+  if (compare == null) {
+    throw new Error("The type cannot be used as map key or set item");
+  }
+  return compare;
+}
+
+/** Sorts map keys or set items the way Borsh does, rejecting duplicates. */
+function sortedBy<T, K>(
+  serializer: Serializer<K>,
+  items: T[],
+  key: (item: T) => K
+): T[] {
+  const compare = comparer(serializer);
+  const sorted = items.slice().sort((a, b) => compare(key(a), key(b)));
+  sorted.forEach((item, idx) => {
+    if (idx > 0 && compare(key(sorted[idx - 1]), key(item)) === 0) {
+      throw new Error(`Duplicate map key or set item ${String(key(item))}`);
+    }
+  });
+  return sorted;
+}
+
+function fixed<T extends number | bigint>(
+  size: number,
+  get: (view: DataView) => T,
+  set: (view: DataView, value: T) => void
+): Serializer<T> {
+  return {
+    serialize: (writer, value) => {
+      const bytes = new Uint8Array(size);
+      set(new DataView(bytes.buffer), value);
+      writer.writeBytes(bytes);
+    },
+    deserialize: (reader) => {
+      const bytes = reader.readBytes(size);
+      return get(new DataView(bytes.buffer, bytes.byteOffset, size));
+    },
+    compare: compareNumbers,
+  };
+}
+
+export const u8 = fixed<number>(1, (v) => v.getUint8(0), (v, n) => v.setUint8(0, n));
+export const i8 = fixed<number>(1, (v) => v.getInt8(0), (v, n) => v.setInt8(0, n));
+export const u16 = fixed<number>(2, (v) => v.getUint16(0, true), (v, n) => v.setUint16(0, n, true));
+export const i16 = fixed<number>(2, (v) => v.getInt16(0, true), (v, n) => v.setInt16(0, n, true));
+export const u32 = fixed<number>(4, (v) => v.getUint32(0, true), (v, n) => v.setUint32(0, n, true));
+export const i32 = fixed<number>(4, (v) => v.getInt32(0, true), (v, n) => v.setInt32(0, n, true));
+export const u64 = fixed<bigint>(8, (v) => v.getBigUint64(0, true), (v, n) => v.setBigUint64(0, n, true));
+export const i64 = fixed<bigint>(8, (v) => v.getBigInt64(0, true), (v, n) => v.setBigInt64(0, n, true));
+
+const U64_MASK = (BigInt(1) << BigInt(64)) - BigInt(1);
+
+export const u128: Serializer<bigint> = {
+  serialize: (writer, value) => {
+    u64.serialize(writer, value & U64_MASK);
+    u64.serialize(writer, (value >> BigInt(64)) & U64_MASK);
+  },
+  deserialize: (reader) => {
+    const low = u64.deserialize(reader);
+    const high = u64.deserialize(reader);
+    return (high << BigInt(64)) | low;
+  },
+  compare: compareNumbers,
+};
+
+export const i128: Serializer<bigint> = {
+  serialize: (writer, value) => u128.serialize(writer, BigInt.asUintN(128, value)),
+  deserialize: (reader) => BigInt.asIntN(128, u128.deserialize(reader)),
+  compare: compareNumbers,
+};
+
+export const bool: Serializer<boolean> = {
+  serialize: (writer, value) => u8.serialize(writer, value ? 1 : 0),
+  deserialize: (reader) => {
+    const value = u8.deserialize(reader);
+    if (value > 1) throw new Error(`Invalid bool value ${value}`);
+    return value === 1;
+  },
+  compare: (a, b) => Number(a) - Number(b),
+};
+
+export const bytes: Serializer<Uint8Array> = {
+  serialize: (writer, value) => {
+    u32.serialize(writer, value.length);
+    writer.writeBytes(value);
+  },
+  deserialize: (reader) => reader.readBytes(u32.deserialize(reader)).slice(),
+  compare: compareBytes,
+};
+
+export const string: Serializer<string> = {
+  serialize: (writer, value) =>
+    bytes.serialize(writer, new TextEncoder().encode(value)),
+  deserialize: (reader) => new TextDecoder().decode(bytes.deserialize(reader)),
+  // Rust compares the UTF-8 bytes which differs from comparing UTF-16 units
+  compare: (a, b) =>
+    compareBytes(new TextEncoder().encode(a), new TextEncoder().encode(b)),
+};
+
+export const publicKey: Serializer<PublicKey> = {
+  serialize: (writer, value) => writer.writeBytes(value.toBytes()),
+  deserialize: (reader) => new PublicKey(reader.readBytes(32)),
+  compare: (a, b) => compareBytes(a.toBytes(), b.toBytes()),
+};
+
+/** Defers resolving the serializer which allows types to reference each other. */
+export function lazy<T>(resolve: () => Serializer<T>): Serializer<T> {
+  return {
+    serialize: (writer, value) => resolve().serialize(writer, value),
+    deserialize: (reader) => resolve().deserialize(reader),
+    compare: (a, b) => comparer(resolve())(a, b),
+  };
+}
+
+export function option<T>(inner: Serializer<T>): Serializer<T | null> {
+  return {
+    serialize: (writer, value) => {
+      if (value == null) {
+        u8.serialize(writer, 0);
+      } else {
+        u8.serialize(writer, 1);
+        inner.serialize(writer, value);
+      }
+    },
+    deserialize: (reader) =>
+      u8.deserialize(reader) === 0 ? null : inner.deserialize(reader),
+    compare: (a, b) => compareOptions(inner, a, b),
+  };
+}
+
+/** Orders `None` before `Some` and compares the values of both `Some`. */
+function compareOptions<T>(
+  inner: Serializer<T>,
+  a: T | null,
+  b: T | null
+): number {
+  if (a == null || b == null) return Number(a != null) - Number(b != null);
+  return comparer(inner)(a, b);
+}
+
+/**
+ * Option which is stored as the raw inner value, `None` is represented by the
+ * `sentinel` bytes.
+ */
+export function fixedSizeOption<T>(
+  inner: Serializer<T>,
+  sentinel: Uint8Array | null
+): Serializer<T | null> {
+  const isSentinel = (value: Uint8Array) => {
+    if (sentinel == null) return false;
+    const expected = sentinel;
+    return (
+      value.length === expected.length &&
+      value.every((byte, idx) => byte === expected[idx])
+    );
+  };
+  return {
+    serialize: (writer, value) => {
+      if (value != null) {
+        inner.serialize(writer, value);
+      } else if (sentinel != null) {
+        writer.writeBytes(sentinel);
+      } else {
+        throw new Error("Cannot serialize None of a fixed size option without sentinel");
+      }
+    },
+    deserialize: (reader) => {
+      const start = reader.offset;
+      const value = inner.deserialize(reader);
+      return isSentinel(reader.data.subarray(start, reader.offset)) ? null : value;
+    },
+    compare: (a, b) => compareOptions(inner, a, b),
+  };
+}
+
+export function array<T>(inner: Serializer<T>, length: number): Serializer<T[]> {
+  return {
+    serialize: (writer, value) => {
+      if (value.length !== length) {
+        throw new Error(`Expected array of length ${length} but got ${value.length}`);
+      }
+      value.forEach((item) => inner.serialize(writer, item));
+    },
+    deserialize: (reader) =>
+      Array.from({ length }, () => inner.deserialize(reader)),
+    compare: (a, b) => compareSeqs(comparer(inner), a, b),
+  };
+}
+
+export function vec<T>(inner: Serializer<T>): Serializer<T[]> {
+  return {
+    serialize: (writer, value) => {
+      u32.serialize(writer, value.length);
+      value.forEach((item) => inner.serialize(writer, item));
+    },
+    deserialize: (reader) =>
+      Array.from({ length: u32.deserialize(reader) }, () =>
+        inner.deserialize(reader)
+      ),
+    compare: (a, b) => compareSeqs(comparer(inner), a, b),
+  };
+}
+
+export function tuple<T extends unknown[]>(
+  items: { [K in keyof T]: Serializer<T[K]> }
+): Serializer<T> {
+  const serializers = items as unknown as Serializer<any>[];
+  return {
+    serialize: (writer, value) =>
+      serializers.forEach((item, idx) => item.serialize(writer, value[idx])),
+    deserialize: (reader) =>
+      serializers.map((item) => item.deserialize(reader)) as T,
+    compare: (a, b) =>
+      compareInOrder(
+        serializers.map((item, idx) => () => comparer(item)(a[idx], b[idx]))
+      ),
+  };
+}
+
+export function map<K, V>(
+  key: Serializer<K>,
+  value: Serializer<V>
+): Serializer<Map<K, V>> {
+  const entries = vec(tuple<[K, V]>([key, value]));
+  return {
+    // Borsh writes the entries sorted by key
+    serialize: (writer, entryMap) =>
+      entries.serialize(
+        writer,
+        sortedBy(key, Array.from(entryMap.entries()), ([entryKey]) => entryKey)
+      ),
+    deserialize: (reader) => new Map(entries.deserialize(reader)),
+  };
+}
+
+export function set<T>(inner: Serializer<T>): Serializer<Set<T>> {
+  const items = vec(inner);
+  return {
+    // Borsh writes the items sorted
+    serialize: (writer, itemSet) =>
+      items.serialize(
+        writer,
+        sortedBy(inner, Array.from(itemSet), (item) => item)
+      ),
+    deserialize: (reader) => new Set(items.deserialize(reader)),
+    compare: (a, b) => {
+      const compare = comparer(inner);
+      return compareSeqs(
+        compare,
+        Array.from(a).sort(compare),
+        Array.from(b).sort(compare)
+      );
+    },
+  };
+}
+
+export function struct<T extends object>(
+  fields: [keyof T & string, Serializer<any>][]
+): Serializer<T> {
+  return {
+    serialize: (writer, value) =>
+      fields.forEach(([name, field]) => field.serialize(writer, value[name])),
+    deserialize: (reader) => {
+      const value: Record<string, unknown> = {};
+      fields.forEach(([name, field]) => {
+        value[name] = field.deserialize(reader);
+      });
+      return value as T;
+    },
+    compare: (a, b) =>
+      compareInOrder(
+        fields.map(([name, field]) => () => comparer(field)(a[name], b[name]))
+      ),
+  };
+}
+
+/** Enum without any data which is represented by the index of its variant. */
+export function scalarEnum<T extends number>(variants: number): Serializer<T> {
+  return {
+    serialize: (writer, value) => u8.serialize(writer, value),
+    deserialize: (reader) => {
+      const value = u8.deserialize(reader);
+      if (value >= variants) throw new Error(`Invalid enum variant ${value}`);
+      return value as T;
+    },
+    compare: compareNumbers,
+  };
+}
+
+/**
+ * Enum whose variants may hold data, represented as objects tagged by `__kind`.
+ * Variants without data have no serializer.
+ */
+export function dataEnum<T extends { __kind: string }>(
+  variants: [T['__kind'], Serializer<any> | null][]
+): Serializer<T> {
+  return {
+    serialize: (writer, value) => {
+      const idx = variants.findIndex(([kind]) => kind === value.__kind);
+      if (idx < 0) throw new Error(`Invalid enum variant ${value.__kind}`);
+      u8.serialize(writer, idx);
+      variants[idx][1]?.serialize(writer, value);
+    },
+    deserialize: (reader) => {
+      const idx = u8.deserialize(reader);
+      if (idx >= variants.length) throw new Error(`Invalid enum variant ${idx}`);
+      const [kind, data] = variants[idx];
+      return { __kind: kind, ...(data?.deserialize(reader) ?? {}) } as T;
+    },
+    // Variants are ordered by their index and then their data
+    compare: (a, b) => {
+      const idx = variants.findIndex(([kind]) => kind === a.__kind);
+      const otherIdx = variants.findIndex(([kind]) => kind === b.__kind);
+      if (idx !== otherIdx) return idx - otherIdx;
+      const data = variants[idx][1];
+      return data == null ? 0 : comparer(data)(a, b);
+    },
+  };
+}
@@ -0,0 +1,86 @@
+declare_id!("Vau1t11111111111111111111111111111111111111");
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub enum VaultKind {
+    Personal,
+    Shared { members: u8 },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct DepositArgs {
+    pub amount: u64,
+    pub memo: Option<String>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct Schedule {
+    pub frequency: Frequency,
+    pub limits: HashMap<String, u64>,
+    pub window: (i64, i64),
+}
+
+#[derive(ShankAccount)]
+pub struct Allowance {
+    pub vault: Pubkey,
+    pub delegate: OptionalPubkey,
+    pub remaining: OptionalU64,
+    pub schedule: Option<Schedule>,
+}
+
+#[derive(ShankAccount)]
+#[seeds("vault", program_id, owner("The owner of the vault"))]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub kind: VaultKind,
+    pub balances: Vec<u64>,
+    pub label: [u8; 8],
+}
+
+/// Read via bytemuck, thus clients need to skip the implicit padding
+#[derive(Clone, Copy, ShankAccount)]
+#[shank(zero_copy)]
+#[repr(C)]
+pub struct VaultStats {
+    pub paused: u8,
+    pub deposits: u64,
+    pub last_slot: u32,
+}
+
+#[derive(ShankInstruction)]
+pub enum VaultInstruction {
+    /// Creates the vault of the owner
+    #[account(0, writable, name = "vault", desc = "The vault PDA")]
+    #[account(1, signer, writable, name = "owner", desc = "The owner paying for the vault")]
+    #[account(2, name = "system_program", desc = "The system program")]
+    Create(VaultKind),
+
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "owner")]
+    #[account(2, optional, name = "referrer")]
+    Deposit(DepositArgs),
+
+    #[account(0, writable, name = "vault", pda = Vault, seeds(owner))]
+    #[account(1, signer, name = "owner")]
+    #[account(2, name = "memo_program", address = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")]
+    Close,
+
+    #[legacy_optional_accounts_strategy]
+    #[account(0, writable, name = "allowance")]
+    #[account(1, optional_signer, name = "owner")]
+    #[account(2, optional, name = "delegate")]
+    SetAllowance { amount: u64, schedule: Option<Schedule> },
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum VaultError {
+    #[error("Vault is not owned by the signer")]
+    InvalidOwner,
+    #[error("Vault has no balance left")]
+    Empty,
+}
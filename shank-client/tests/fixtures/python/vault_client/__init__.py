@@ -0,0 +1,3 @@
+"""Client for the vault program generated from its IDL."""
+from . import accounts, errors, instructions, types
+from .program_id import PROGRAM_ID
@@ -0,0 +1,92 @@
+"""Accounts of the program."""
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Dict, List, Optional, Set, Tuple
+
+from . import serializer as s
+from .serializer import Pubkey
+from .types import (
+    DepositArgs,
+    DEPOSIT_ARGS_CODEC,
+    Schedule,
+    SCHEDULE_CODEC,
+    VaultKind,
+    VAULT_KIND_CODEC,
+    Frequency,
+    FREQUENCY_CODEC,
+)
+
+
+@dataclass
+class Allowance:
+    vault: Pubkey
+    delegate: Optional[Pubkey]
+    remaining: Optional[int]
+    schedule: Optional[Schedule]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Allowance:
+        """Decodes the account from its data, ignoring any trailing bytes."""
+        return s.decode(ALLOWANCE_CODEC, data)
+
+    def encode(self) -> bytes:
+        return s.encode(ALLOWANCE_CODEC, self)
+
+
+ALLOWANCE_CODEC = s.Struct(Allowance, [
+    ("vault", s.PUBKEY),
+    ("delegate", s.FixedSizeOption(s.PUBKEY, bytes([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]))),
+    ("remaining", s.FixedSizeOption(s.U64, bytes([255, 255, 255, 255, 255, 255, 255, 255]))),
+    ("schedule", s.Option(s.Lazy(lambda: SCHEDULE_CODEC))),
+])
+
+
+@dataclass
+class Vault:
+    owner: Pubkey
+    kind: VaultKind
+    balances: List[int]
+    label: List[int]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Vault:
+        """Decodes the account from its data, ignoring any trailing bytes."""
+        return s.decode(VAULT_CODEC, data)
+
+    def encode(self) -> bytes:
+        return s.encode(VAULT_CODEC, self)
+
+
+VAULT_CODEC = s.Struct(Vault, [
+    ("owner", s.PUBKEY),
+    ("kind", s.Lazy(lambda: VAULT_KIND_CODEC)),
+    ("balances", s.Vec(s.U64)),
+    ("label", s.Array(s.U8, 8)),
+])
+
+
+@dataclass
+class VaultStats:
+    paused: int
+    implicit_padding1: List[int]
+    deposits: int
+    last_slot: int
+    implicit_padding20: List[int]
+
+    @classmethod
+    def decode(cls, data: bytes) -> VaultStats:
+        """Decodes the account from its data, ignoring any trailing bytes."""
+        return s.decode(VAULT_STATS_CODEC, data)
+
+    def encode(self) -> bytes:
+        return s.encode(VAULT_STATS_CODEC, self)
+
+
+VAULT_STATS_CODEC = s.Struct(VaultStats, [
+    ("paused", s.U8),
+    ("implicit_padding1", s.Array(s.U8, 7)),
+    ("deposits", s.U64),
+    ("last_slot", s.U32),
+    ("implicit_padding20", s.Array(s.U8, 4)),
+])
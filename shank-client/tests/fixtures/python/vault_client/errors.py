@@ -0,0 +1,25 @@
+"""Errors of the program."""
+from typing import Dict, Optional, Tuple
+
+
+class VaultError(Exception):
+    """Error returned by the program, identified by its custom error code."""
+
+    def __init__(self, code: int, name: str, msg: str):
+        super().__init__(msg)
+        self.code = code
+        self.name = name
+        self.msg = msg
+
+
+# Name and message of each error keyed by its custom error code.
+ERRORS_BY_CODE: Dict[int, Tuple[str, str]] = {
+    0: ("InvalidOwner", "Vault is not owned by the signer"),
+    1: ("Empty", "Vault has no balance left"),
+}
+
+
+def error_from_code(code: int) -> Optional[VaultError]:
+    """Resolves the error from its custom program error code."""
+    error = ERRORS_BY_CODE.get(code)
+    return None if error is None else VaultError(code, *error)
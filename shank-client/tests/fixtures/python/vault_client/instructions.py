@@ -0,0 +1,136 @@
+"""Instructions of the program."""
+from __future__ import annotations
+
+from typing import Dict, List, Optional, Set, Tuple
+
+from . import serializer as s
+from .program_id import PROGRAM_ID
+from .serializer import Pubkey
+from .types import (
+    DepositArgs,
+    DEPOSIT_ARGS_CODEC,
+    Schedule,
+    SCHEDULE_CODEC,
+    VaultKind,
+    VAULT_KIND_CODEC,
+    Frequency,
+    FREQUENCY_CODEC,
+)
+
+
+CREATE_DISCRIMINANT = 0
+
+
+def create_instruction(
+    *,
+    vault: Pubkey,
+    owner: Pubkey,
+    system_program: Optional[Pubkey] = None,
+    vault_kind: VaultKind,
+    program_id: Pubkey = PROGRAM_ID,
+) -> s.Instruction:
+    """Creates the Create instruction.
+
+    Accounts:
+        vault (writable): The vault PDA
+        owner (writable, signer): The owner paying for the vault
+        system_program: The system program (defaults to 11111111111111111111111111111111)
+    """
+    accounts: List[s.AccountMeta] = []
+    accounts.append(s.AccountMeta(vault, is_signer=False, is_writable=True))
+    accounts.append(s.AccountMeta(owner, is_signer=True, is_writable=True))
+    accounts.append(s.AccountMeta(system_program if system_program is not None else s.Pubkey.from_string("11111111111111111111111111111111"), is_signer=False, is_writable=False))
+
+    data = bytearray([CREATE_DISCRIMINANT])
+    s.Lazy(lambda: VAULT_KIND_CODEC).encode(vault_kind, data)
+    return s.Instruction(program_id, accounts, bytes(data))
+
+
+DEPOSIT_DISCRIMINANT = 1
+
+
+def deposit_instruction(
+    *,
+    vault: Pubkey,
+    owner: Pubkey,
+    referrer: Optional[Pubkey] = None,
+    deposit_args: DepositArgs,
+    program_id: Pubkey = PROGRAM_ID,
+) -> s.Instruction:
+    """Creates the Deposit instruction.
+
+    Accounts:
+        vault (writable)
+        owner (signer)
+        referrer (optional)
+    """
+    accounts: List[s.AccountMeta] = []
+    accounts.append(s.AccountMeta(vault, is_signer=False, is_writable=True))
+    accounts.append(s.AccountMeta(owner, is_signer=True, is_writable=False))
+    accounts.append(
+        s.AccountMeta(referrer, is_signer=False, is_writable=False)
+        if referrer is not None
+        else s.AccountMeta(program_id, is_signer=False, is_writable=False)
+    )
+
+    data = bytearray([DEPOSIT_DISCRIMINANT])
+    s.Lazy(lambda: DEPOSIT_ARGS_CODEC).encode(deposit_args, data)
+    return s.Instruction(program_id, accounts, bytes(data))
+
+
+CLOSE_DISCRIMINANT = 2
+
+
+def close_instruction(
+    *,
+    vault: Pubkey,
+    owner: Pubkey,
+    memo_program: Optional[Pubkey] = None,
+    program_id: Pubkey = PROGRAM_ID,
+) -> s.Instruction:
+    """Creates the Close instruction.
+
+    Accounts:
+        vault (writable)
+        owner (signer)
+        memo_program: (defaults to MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr)
+    """
+    accounts: List[s.AccountMeta] = []
+    accounts.append(s.AccountMeta(vault, is_signer=False, is_writable=True))
+    accounts.append(s.AccountMeta(owner, is_signer=True, is_writable=False))
+    accounts.append(s.AccountMeta(memo_program if memo_program is not None else s.Pubkey.from_string("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"), is_signer=False, is_writable=False))
+
+    data = bytearray([CLOSE_DISCRIMINANT])
+    return s.Instruction(program_id, accounts, bytes(data))
+
+
+SET_ALLOWANCE_DISCRIMINANT = 3
+
+
+def set_allowance_instruction(
+    *,
+    allowance: Pubkey,
+    owner: Pubkey,
+    owner_is_signer: bool = False,
+    delegate: Optional[Pubkey] = None,
+    amount: int,
+    schedule: Optional[Schedule],
+    program_id: Pubkey = PROGRAM_ID,
+) -> s.Instruction:
+    """Creates the SetAllowance instruction.
+
+    Accounts:
+        allowance (writable)
+        owner (optional signer)
+        delegate (optional)
+    """
+    accounts: List[s.AccountMeta] = []
+    accounts.append(s.AccountMeta(allowance, is_signer=False, is_writable=True))
+    accounts.append(s.AccountMeta(owner, is_signer=owner_is_signer, is_writable=False))
+    if delegate is not None:
+        accounts.append(s.AccountMeta(delegate, is_signer=False, is_writable=False))
+
+    data = bytearray([SET_ALLOWANCE_DISCRIMINANT])
+    s.U64.encode(amount, data)
+    s.Option(s.Lazy(lambda: SCHEDULE_CODEC)).encode(schedule, data)
+    return s.Instruction(program_id, accounts, bytes(data))
@@ -0,0 +1,3 @@
+from .serializer import Pubkey
+
+PROGRAM_ID = Pubkey.from_string("Vau1t11111111111111111111111111111111111111")
@@ -0,0 +1,63 @@
+"""Custom types used by accounts and instructions of the program."""
+from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import IntEnum
+from typing import Dict, List, Optional, Set, Tuple, Union
+
+from . import serializer as s
+from .serializer import Pubkey
+
+
+@dataclass
+class DepositArgs:
+    amount: int
+    memo: Optional[str]
+
+
+DEPOSIT_ARGS_CODEC = s.Struct(DepositArgs, [
+    ("amount", s.U64),
+    ("memo", s.Option(s.STRING)),
+])
+
+
+@dataclass
+class Schedule:
+    frequency: Frequency
+    limits: Dict[str, int]
+    window: Tuple[int, int]
+
+
+SCHEDULE_CODEC = s.Struct(Schedule, [
+    ("frequency", s.Lazy(lambda: FREQUENCY_CODEC)),
+    ("limits", s.Map(s.STRING, s.U64)),
+    ("window", s.Tuple(s.I64, s.I64)),
+])
+
+
+@dataclass
+class VaultKindPersonal:
+    pass
+
+
+@dataclass
+class VaultKindShared:
+    members: int
+
+
+VaultKind = Union[VaultKindPersonal, VaultKindShared]
+
+VAULT_KIND_CODEC = s.DataEnum([
+    (VaultKindPersonal, None),
+    (VaultKindShared, [
+        ("members", s.U8),
+    ]),
+])
+
+
+class Frequency(IntEnum):
+    Daily = 0
+    Weekly = 1
+
+
+FREQUENCY_CODEC = s.ScalarEnum(Frequency)
@@ -0,0 +1,124 @@
+//! Accounts of the program.
+#[allow(unused_imports)]
+use crate::types::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+#[allow(unused_imports)]
+use solana_program::pubkey::Pubkey;
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Allowance {
+    pub vault: Pubkey,
+    /// `None` is represented by `[0; 32]`.
+    pub delegate: Pubkey,
+    /// `None` is represented by `[255; 8]`.
+    pub remaining: u64,
+    pub schedule: Option<Schedule>,
+}
+impl Allowance {
+    /// Deserializes the account from its data, ignoring any trailing bytes.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub kind: VaultKind,
+    pub balances: Vec<u64>,
+    pub label: [u8; 8],
+}
+impl Vault {
+    /// Deserializes the account from its data, ignoring any trailing bytes.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+impl Vault {
+    #[allow(unused, clippy::needless_lifetimes)]
+    pub fn shank_seeds<'a>(
+        program_id: &'a ::solana_program::pubkey::Pubkey,
+        owner: &'a ::solana_program::pubkey::Pubkey,
+    ) -> [&'a [u8]; 3usize] {
+        [b"vault", program_id.as_ref(), owner.as_ref()]
+    }
+    #[allow(unused, clippy::needless_lifetimes)]
+    pub fn shank_seeds_with_bump<'a>(
+        program_id: &'a ::solana_program::pubkey::Pubkey,
+        owner: &'a ::solana_program::pubkey::Pubkey,
+        bump: &'a [u8; 1],
+    ) -> [&'a [u8]; 4usize] {
+        [b"vault", program_id.as_ref(), owner.as_ref(), bump]
+    }
+    #[allow(unused)]
+    pub fn shank_pda(
+        program_id: &::solana_program::pubkey::Pubkey,
+        owner: &::solana_program::pubkey::Pubkey,
+    ) -> (::solana_program::pubkey::Pubkey, u8) {
+        let seeds = Self::shank_seeds(program_id, owner);
+        ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+    }
+    #[allow(unused)]
+    pub fn shank_pda_with_bump(
+        program_id: &::solana_program::pubkey::Pubkey,
+        owner: &::solana_program::pubkey::Pubkey,
+        bump: u8,
+    ) -> Result<
+        (::solana_program::pubkey::Pubkey, u8),
+        ::solana_program::pubkey::PubkeyError,
+    > {
+        let bump_arg = &[bump];
+        let seeds = Self::shank_seeds_with_bump(program_id, owner, bump_arg);
+        let pda = ::solana_program::pubkey::Pubkey::create_program_address(
+            &seeds,
+            program_id,
+        )?;
+        Ok((pda, bump))
+    }
+    #[allow(unused)]
+    pub fn shank_find_pda(
+        owner: &::solana_program::pubkey::Pubkey,
+    ) -> (::solana_program::pubkey::Pubkey, u8) {
+        Self::shank_pda(&crate::ID, owner)
+    }
+    #[allow(unused)]
+    pub fn shank_create_pda(
+        program_id: &::solana_program::pubkey::Pubkey,
+        owner: &::solana_program::pubkey::Pubkey,
+        bump: u8,
+    ) -> Result<
+        ::solana_program::pubkey::Pubkey,
+        ::solana_program::pubkey::PubkeyError,
+    > {
+        let bump_arg = &[bump];
+        let seeds = Self::shank_seeds_with_bump(program_id, owner, bump_arg);
+        ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+    }
+    #[allow(unused)]
+    pub fn shank_verify_pda(
+        pda: &::solana_program::pubkey::Pubkey,
+        program_id: &::solana_program::pubkey::Pubkey,
+        owner: &::solana_program::pubkey::Pubkey,
+        bump: u8,
+    ) -> bool {
+        match Self::shank_create_pda(program_id, owner, bump) {
+            Ok(address) => &address == pda,
+            Err(_) => false,
+        }
+    }
+}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct VaultStats {
+    pub paused: u8,
+    pub implicit_padding1: [u8; 7],
+    pub deposits: u64,
+    pub last_slot: u32,
+    pub implicit_padding20: [u8; 4],
+}
+impl VaultStats {
+    /// Deserializes the account from its data, ignoring any trailing bytes.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
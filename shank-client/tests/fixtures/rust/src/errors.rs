@@ -0,0 +1,26 @@
+//! Errors of the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[repr(u32)]
+pub enum VaultError {
+    /// 0x0 - Vault is not owned by the signer
+    #[error("Vault is not owned by the signer")]
+    InvalidOwner = 0,
+    /// 0x1 - Vault has no balance left
+    #[error("Vault has no balance left")]
+    Empty = 1,
+}
+impl VaultError {
+    /// Resolves the error from its custom program error code.
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::InvalidOwner),
+            1 => Some(Self::Empty),
+            _ => None,
+        }
+    }
+}
+impl From<VaultError> for solana_program::program_error::ProgramError {
+    fn from(err: VaultError) -> Self {
+        solana_program::program_error::ProgramError::Custom(err as u32)
+    }
+}
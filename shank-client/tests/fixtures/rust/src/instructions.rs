@@ -0,0 +1,567 @@
+//! Instructions of the program and builders to create them.
+#[allow(unused_imports)]
+use crate::types::*;
+use borsh::BorshSerialize;
+#[allow(unused_imports)]
+use solana_program::pubkey::Pubkey;
+pub type SetAllowanceSchedule = Option<Schedule>;
+/// Instructions of the program along with their arguments.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VaultInstruction {
+    /// Accounts:
+    ///
+    /// 0. `[writable]` vault: The vault PDA
+    /// 1. `[writable, signer]` owner: The owner paying for the vault
+    /// 2. `[]` system_program: The system program
+    Create(VaultKind),
+    /// Accounts:
+    ///
+    /// 0. `[writable]` vault
+    /// 1. `[signer]` owner
+    /// 2. `[optional]` referrer
+    Deposit(DepositArgs),
+    /// Accounts:
+    ///
+    /// 0. `[writable]` vault
+    /// 1. `[signer]` owner
+    /// 2. `[]` memo_program
+    Close,
+    /// Accounts:
+    ///
+    /// 0. `[writable]` allowance
+    /// 1. `[optional signer]` owner
+    /// 2. `[optional]` delegate
+    SetAllowance(u64, SetAllowanceSchedule),
+}
+impl BorshSerialize for VaultInstruction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::Create(arg0) => {
+                0u8.serialize(writer)?;
+                arg0.serialize(writer)?;
+                Ok(())
+            }
+            Self::Deposit(arg0) => {
+                1u8.serialize(writer)?;
+                arg0.serialize(writer)?;
+                Ok(())
+            }
+            Self::Close => 2u8.serialize(writer),
+            Self::SetAllowance(arg0, arg1) => {
+                3u8.serialize(writer)?;
+                arg0.serialize(writer)?;
+                arg1.serialize(writer)?;
+                Ok(())
+            }
+        }
+    }
+}
+pub mod builders {
+    use super::*;
+    /// Trait that defines the interface for creating an instruction.
+    pub trait InstructionBuilder {
+        fn instruction(&self) -> ::solana_program::instruction::Instruction;
+    }
+    pub struct Create {
+        pub vault: ::solana_program::pubkey::Pubkey,
+        pub owner: ::solana_program::pubkey::Pubkey,
+        pub system_program: ::solana_program::pubkey::Pubkey,
+        pub args: VaultKind,
+    }
+    impl InstructionBuilder for Create {
+        fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(self.vault, false),
+                    ::solana_program::instruction::AccountMeta::new(self.owner, true),
+                    ::solana_program::instruction::AccountMeta::new_readonly(self
+                    .system_program, false),
+                ],
+                data: VaultInstruction::Create(self.args.clone()).try_to_vec().unwrap(),
+            }
+        }
+    }
+    pub struct CreateBuilder {
+        pub vault: Option<::solana_program::pubkey::Pubkey>,
+        pub owner: Option<::solana_program::pubkey::Pubkey>,
+        pub system_program: Option<::solana_program::pubkey::Pubkey>,
+    }
+    impl CreateBuilder {
+        pub fn new() -> Box<CreateBuilder> {
+            Box::new(CreateBuilder {
+                vault: None,
+                owner: None,
+                system_program: None,
+            })
+        }
+        pub fn vault(&mut self, vault: ::solana_program::pubkey::Pubkey) -> &mut Self {
+            self.vault = Some(vault);
+            self
+        }
+        pub fn owner(&mut self, owner: ::solana_program::pubkey::Pubkey) -> &mut Self {
+            self.owner = Some(owner);
+            self
+        }
+        pub fn system_program(
+            &mut self,
+            system_program: ::solana_program::pubkey::Pubkey,
+        ) -> &mut Self {
+            self.system_program = Some(system_program);
+            self
+        }
+        pub fn build(
+            &mut self,
+            args: VaultKind,
+        ) -> Result<Box<Create>, Box<dyn std::error::Error>> {
+            Ok(
+                Box::new(Create {
+                    vault: self.vault.ok_or(concat!(stringify!(vault), " is not set"))?,
+                    owner: self.owner.ok_or(concat!(stringify!(owner), " is not set"))?,
+                    system_program: self
+                        .system_program
+                        .unwrap_or(::solana_program::system_program::ID),
+                    args,
+                }),
+            )
+        }
+    }
+    pub struct CreateCpi<'a, 'b> {
+        /// The account info of the program being invoked.
+        pub __program: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub vault: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub owner: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub system_program: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub args: VaultKind,
+    }
+    impl<'a, 'b> CreateCpi<'a, 'b> {
+        pub fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(* self.vault.key,
+                    false), ::solana_program::instruction::AccountMeta::new(* self.owner
+                    .key, true),
+                    ::solana_program::instruction::AccountMeta::new_readonly(* self
+                    .system_program.key, false),
+                ],
+                data: VaultInstruction::Create(self.args.clone()).try_to_vec().unwrap(),
+            }
+        }
+        pub fn invoke(
+            &self,
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            self.invoke_signed(&[])
+        }
+        pub fn invoke_signed(
+            &self,
+            signers_seeds: &[&[&[u8]]],
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            let mut account_infos = Vec::with_capacity(4usize);
+            account_infos.push(self.__program.clone());
+            account_infos.push(self.vault.clone());
+            account_infos.push(self.owner.clone());
+            account_infos.push(self.system_program.clone());
+            ::solana_program::program::invoke_signed(
+                &self.instruction(),
+                &account_infos,
+                signers_seeds,
+            )
+        }
+    }
+    pub struct Deposit {
+        pub vault: ::solana_program::pubkey::Pubkey,
+        pub owner: ::solana_program::pubkey::Pubkey,
+        pub referrer: Option<::solana_program::pubkey::Pubkey>,
+        pub args: DepositArgs,
+    }
+    impl InstructionBuilder for Deposit {
+        fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(self.vault, false),
+                    ::solana_program::instruction::AccountMeta::new_readonly(self.owner,
+                    true), ::solana_program::instruction::AccountMeta::new_readonly(self
+                    .referrer.unwrap_or(crate ::ID), false),
+                ],
+                data: VaultInstruction::Deposit(self.args.clone()).try_to_vec().unwrap(),
+            }
+        }
+    }
+    pub struct DepositBuilder {
+        pub vault: Option<::solana_program::pubkey::Pubkey>,
+        pub owner: Option<::solana_program::pubkey::Pubkey>,
+        pub referrer: Option<::solana_program::pubkey::Pubkey>,
+    }
+    impl DepositBuilder {
+        pub fn new() -> Box<DepositBuilder> {
+            Box::new(DepositBuilder {
+                vault: None,
+                owner: None,
+                referrer: None,
+            })
+        }
+        pub fn vault(&mut self, vault: ::solana_program::pubkey::Pubkey) -> &mut Self {
+            self.vault = Some(vault);
+            self
+        }
+        pub fn owner(&mut self, owner: ::solana_program::pubkey::Pubkey) -> &mut Self {
+            self.owner = Some(owner);
+            self
+        }
+        pub fn referrer(
+            &mut self,
+            referrer: ::solana_program::pubkey::Pubkey,
+        ) -> &mut Self {
+            self.referrer = Some(referrer);
+            self
+        }
+        pub fn build(
+            &mut self,
+            args: DepositArgs,
+        ) -> Result<Box<Deposit>, Box<dyn std::error::Error>> {
+            Ok(
+                Box::new(Deposit {
+                    vault: self.vault.ok_or(concat!(stringify!(vault), " is not set"))?,
+                    owner: self.owner.ok_or(concat!(stringify!(owner), " is not set"))?,
+                    referrer: self.referrer,
+                    args,
+                }),
+            )
+        }
+    }
+    pub struct DepositCpi<'a, 'b> {
+        /// The account info of the program being invoked.
+        pub __program: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub vault: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub owner: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub referrer: Option<&'b ::solana_program::account_info::AccountInfo<'a>>,
+        pub args: DepositArgs,
+    }
+    impl<'a, 'b> DepositCpi<'a, 'b> {
+        pub fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(* self.vault.key,
+                    false), ::solana_program::instruction::AccountMeta::new_readonly(*
+                    self.owner.key, true), if let Some(referrer) = self.referrer {
+                    ::solana_program::instruction::AccountMeta::new_readonly(* referrer
+                    .key, false) } else {
+                    ::solana_program::instruction::AccountMeta::new_readonly(crate ::ID,
+                    false) },
+                ],
+                data: VaultInstruction::Deposit(self.args.clone()).try_to_vec().unwrap(),
+            }
+        }
+        pub fn invoke(
+            &self,
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            self.invoke_signed(&[])
+        }
+        pub fn invoke_signed(
+            &self,
+            signers_seeds: &[&[&[u8]]],
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            let mut account_infos = Vec::with_capacity(4usize);
+            account_infos.push(self.__program.clone());
+            account_infos.push(self.vault.clone());
+            account_infos.push(self.owner.clone());
+            if let Some(referrer) = self.referrer {
+                account_infos.push(referrer.clone());
+            }
+            ::solana_program::program::invoke_signed(
+                &self.instruction(),
+                &account_infos,
+                signers_seeds,
+            )
+        }
+    }
+    pub struct Close {
+        pub vault: ::solana_program::pubkey::Pubkey,
+        pub owner: ::solana_program::pubkey::Pubkey,
+        pub memo_program: ::solana_program::pubkey::Pubkey,
+    }
+    impl InstructionBuilder for Close {
+        fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(self.vault, false),
+                    ::solana_program::instruction::AccountMeta::new_readonly(self.owner,
+                    true), ::solana_program::instruction::AccountMeta::new_readonly(self
+                    .memo_program, false),
+                ],
+                data: VaultInstruction::Close.try_to_vec().unwrap(),
+            }
+        }
+    }
+    pub struct CloseBuilder {
+        pub vault: Option<::solana_program::pubkey::Pubkey>,
+        pub owner: Option<::solana_program::pubkey::Pubkey>,
+        pub memo_program: Option<::solana_program::pubkey::Pubkey>,
+    }
+    impl CloseBuilder {
+        pub fn new() -> Box<CloseBuilder> {
+            Box::new(CloseBuilder {
+                vault: None,
+                owner: None,
+                memo_program: None,
+            })
+        }
+        pub fn vault(&mut self, vault: ::solana_program::pubkey::Pubkey) -> &mut Self {
+            self.vault = Some(vault);
+            self
+        }
+        pub fn owner(&mut self, owner: ::solana_program::pubkey::Pubkey) -> &mut Self {
+            self.owner = Some(owner);
+            self
+        }
+        pub fn memo_program(
+            &mut self,
+            memo_program: ::solana_program::pubkey::Pubkey,
+        ) -> &mut Self {
+            self.memo_program = Some(memo_program);
+            self
+        }
+        pub fn build(&mut self) -> Result<Box<Close>, Box<dyn std::error::Error>> {
+            Ok(
+                Box::new(Close {
+                    vault: match self.vault {
+                        Some(vault) => vault,
+                        None => {
+                            crate::accounts::Vault::shank_find_pda(
+                                    &self
+                                        .owner
+                                        .ok_or(concat!(stringify!(owner), " is not set"))?,
+                                )
+                                .0
+                        }
+                    },
+                    owner: self.owner.ok_or(concat!(stringify!(owner), " is not set"))?,
+                    memo_program: self
+                        .memo_program
+                        .unwrap_or(
+                            ::solana_program::pubkey::Pubkey::new_from_array([
+                                5u8,
+                                74u8,
+                                83u8,
+                                90u8,
+                                153u8,
+                                41u8,
+                                33u8,
+                                6u8,
+                                77u8,
+                                36u8,
+                                232u8,
+                                113u8,
+                                96u8,
+                                218u8,
+                                56u8,
+                                124u8,
+                                124u8,
+                                53u8,
+                                181u8,
+                                221u8,
+                                188u8,
+                                146u8,
+                                187u8,
+                                129u8,
+                                228u8,
+                                31u8,
+                                168u8,
+                                64u8,
+                                65u8,
+                                5u8,
+                                68u8,
+                                141u8,
+                            ]),
+                        ),
+                }),
+            )
+        }
+    }
+    pub struct CloseCpi<'a, 'b> {
+        /// The account info of the program being invoked.
+        pub __program: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub vault: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub owner: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub memo_program: &'b ::solana_program::account_info::AccountInfo<'a>,
+    }
+    impl<'a, 'b> CloseCpi<'a, 'b> {
+        pub fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(* self.vault.key,
+                    false), ::solana_program::instruction::AccountMeta::new_readonly(*
+                    self.owner.key, true),
+                    ::solana_program::instruction::AccountMeta::new_readonly(* self
+                    .memo_program.key, false),
+                ],
+                data: VaultInstruction::Close.try_to_vec().unwrap(),
+            }
+        }
+        pub fn invoke(
+            &self,
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            self.invoke_signed(&[])
+        }
+        pub fn invoke_signed(
+            &self,
+            signers_seeds: &[&[&[u8]]],
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            let mut account_infos = Vec::with_capacity(4usize);
+            account_infos.push(self.__program.clone());
+            account_infos.push(self.vault.clone());
+            account_infos.push(self.owner.clone());
+            account_infos.push(self.memo_program.clone());
+            ::solana_program::program::invoke_signed(
+                &self.instruction(),
+                &account_infos,
+                signers_seeds,
+            )
+        }
+    }
+    pub struct SetAllowance {
+        pub allowance: ::solana_program::pubkey::Pubkey,
+        pub owner: ::solana_program::pubkey::Pubkey,
+        pub delegate: Option<::solana_program::pubkey::Pubkey>,
+        pub owner_signer: bool,
+        pub args: u64,
+        pub args1: SetAllowanceSchedule,
+    }
+    impl InstructionBuilder for SetAllowance {
+        fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(self.allowance,
+                    false), ::solana_program::instruction::AccountMeta::new_readonly(self
+                    .owner, self.owner_signer),
+                    ::solana_program::instruction::AccountMeta::new_readonly(self
+                    .delegate.unwrap_or(crate ::ID), false),
+                ],
+                data: VaultInstruction::SetAllowance(
+                        self.args.clone(),
+                        self.args1.clone(),
+                    )
+                    .try_to_vec()
+                    .unwrap(),
+            }
+        }
+    }
+    pub struct SetAllowanceBuilder {
+        pub allowance: Option<::solana_program::pubkey::Pubkey>,
+        pub owner: Option<::solana_program::pubkey::Pubkey>,
+        pub delegate: Option<::solana_program::pubkey::Pubkey>,
+        pub owner_signer: bool,
+    }
+    impl SetAllowanceBuilder {
+        pub fn new() -> Box<SetAllowanceBuilder> {
+            Box::new(SetAllowanceBuilder {
+                allowance: None,
+                owner: None,
+                delegate: None,
+                owner_signer: false,
+            })
+        }
+        pub fn allowance(
+            &mut self,
+            allowance: ::solana_program::pubkey::Pubkey,
+        ) -> &mut Self {
+            self.allowance = Some(allowance);
+            self
+        }
+        pub fn owner(
+            &mut self,
+            owner: ::solana_program::pubkey::Pubkey,
+            signer: bool,
+        ) -> &mut Self {
+            self.owner = Some(owner);
+            self.owner_signer = signer;
+            self
+        }
+        pub fn delegate(
+            &mut self,
+            delegate: ::solana_program::pubkey::Pubkey,
+        ) -> &mut Self {
+            self.delegate = Some(delegate);
+            self
+        }
+        pub fn build(
+            &mut self,
+            args: u64,
+            args1: SetAllowanceSchedule,
+        ) -> Result<Box<SetAllowance>, Box<dyn std::error::Error>> {
+            Ok(
+                Box::new(SetAllowance {
+                    allowance: self
+                        .allowance
+                        .ok_or(concat!(stringify!(allowance), " is not set"))?,
+                    owner: self.owner.ok_or(concat!(stringify!(owner), " is not set"))?,
+                    delegate: self.delegate,
+                    owner_signer: self.owner_signer,
+                    args,
+                    args1,
+                }),
+            )
+        }
+    }
+    pub struct SetAllowanceCpi<'a, 'b> {
+        /// The account info of the program being invoked.
+        pub __program: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub allowance: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub owner: &'b ::solana_program::account_info::AccountInfo<'a>,
+        pub delegate: Option<&'b ::solana_program::account_info::AccountInfo<'a>>,
+        pub owner_signer: bool,
+        pub args: u64,
+        pub args1: SetAllowanceSchedule,
+    }
+    impl<'a, 'b> SetAllowanceCpi<'a, 'b> {
+        pub fn instruction(&self) -> ::solana_program::instruction::Instruction {
+            ::solana_program::instruction::Instruction {
+                program_id: crate::ID,
+                accounts: vec![
+                    ::solana_program::instruction::AccountMeta::new(* self.allowance.key,
+                    false), ::solana_program::instruction::AccountMeta::new_readonly(*
+                    self.owner.key, self.owner_signer), if let Some(delegate) = self
+                    .delegate {
+                    ::solana_program::instruction::AccountMeta::new_readonly(* delegate
+                    .key, false) } else {
+                    ::solana_program::instruction::AccountMeta::new_readonly(crate ::ID,
+                    false) },
+                ],
+                data: VaultInstruction::SetAllowance(
+                        self.args.clone(),
+                        self.args1.clone(),
+                    )
+                    .try_to_vec()
+                    .unwrap(),
+            }
+        }
+        pub fn invoke(
+            &self,
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            self.invoke_signed(&[])
+        }
+        pub fn invoke_signed(
+            &self,
+            signers_seeds: &[&[&[u8]]],
+        ) -> Result<(), ::solana_program::program_error::ProgramError> {
+            let mut account_infos = Vec::with_capacity(4usize);
+            account_infos.push(self.__program.clone());
+            account_infos.push(self.allowance.clone());
+            account_infos.push(self.owner.clone());
+            if let Some(delegate) = self.delegate {
+                account_infos.push(delegate.clone());
+            }
+            ::solana_program::program::invoke_signed(
+                &self.instruction(),
+                &account_infos,
+                signers_seeds,
+            )
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! Client for the program generated from its IDL.
+pub mod accounts;
+pub mod errors;
+pub mod instructions;
+pub mod types;
+solana_program::declare_id!("Vau1t11111111111111111111111111111111111111");
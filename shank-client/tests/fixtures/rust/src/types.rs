@@ -0,0 +1,25 @@
+//! Custom types used by accounts and instructions of the program.
+use borsh::{BorshDeserialize, BorshSerialize};
+#[allow(unused_imports)]
+use solana_program::pubkey::Pubkey;
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct DepositArgs {
+    pub amount: u64,
+    pub memo: Option<String>,
+}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Schedule {
+    pub frequency: Frequency,
+    pub limits: std::collections::HashMap<String, u64>,
+    pub window: (i64, i64),
+}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum VaultKind {
+    Personal,
+    Shared { members: u8 },
+}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
@@ -0,0 +1,140 @@
+import { Commitment, Connection, PublicKey } from "@solana/web3.js";
+import * as s from "./serializer";
+import {
+  DepositArgs,
+  depositArgsSerializer,
+  Schedule,
+  scheduleSerializer,
+  VaultKind,
+  vaultKindSerializer,
+  Frequency,
+  frequencySerializer,
+} from "./types";
+
+export type Allowance = {
+  vault: PublicKey;
+  delegate: PublicKey | null;
+  remaining: bigint | null;
+  schedule: Schedule | null;
+};
+
+export const allowanceSerializer: s.Serializer<Allowance> = s.struct<Allowance>([
+  ["vault", s.publicKey],
+  ["delegate", s.fixedSizeOption(s.publicKey, new Uint8Array([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]))],
+  ["remaining", s.fixedSizeOption(s.u64, new Uint8Array([255, 255, 255, 255, 255, 255, 255, 255]))],
+  ["schedule", s.option(s.lazy(() => scheduleSerializer))],
+]);
+
+/** Decodes the Allowance account from its data, ignoring any trailing bytes. */
+export function decodeAllowance(data: Uint8Array): Allowance {
+  return s.deserialize(allowanceSerializer, data);
+}
+
+/** Fetches and decodes the Allowance account, failing if it doesn't exist. */
+export async function fetchAllowance(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<Allowance> {
+  const account = await fetchMaybeAllowance(connection, address, commitment);
+  if (account == null) {
+    throw new Error(`Allowance account ${address.toBase58()} not found`);
+  }
+  return account;
+}
+
+/** Fetches and decodes the Allowance account, resolving `null` if it doesn't exist. */
+export async function fetchMaybeAllowance(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<Allowance | null> {
+  const info = await connection.getAccountInfo(address, commitment);
+  return info == null ? null : decodeAllowance(info.data);
+}
+
+export type Vault = {
+  owner: PublicKey;
+  kind: VaultKind;
+  balances: Array<bigint>;
+  label: Array<number>;
+};
+
+export const vaultSerializer: s.Serializer<Vault> = s.struct<Vault>([
+  ["owner", s.publicKey],
+  ["kind", s.lazy(() => vaultKindSerializer)],
+  ["balances", s.vec(s.u64)],
+  ["label", s.array(s.u8, 8)],
+]);
+
+/** Decodes the Vault account from its data, ignoring any trailing bytes. */
+export function decodeVault(data: Uint8Array): Vault {
+  return s.deserialize(vaultSerializer, data);
+}
+
+/** Fetches and decodes the Vault account, failing if it doesn't exist. */
+export async function fetchVault(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<Vault> {
+  const account = await fetchMaybeVault(connection, address, commitment);
+  if (account == null) {
+    throw new Error(`Vault account ${address.toBase58()} not found`);
+  }
+  return account;
+}
+
+/** Fetches and decodes the Vault account, resolving `null` if it doesn't exist. */
+export async function fetchMaybeVault(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<Vault | null> {
+  const info = await connection.getAccountInfo(address, commitment);
+  return info == null ? null : decodeVault(info.data);
+}
+
+export type VaultStats = {
+  paused: number;
+  implicitPadding1: Array<number>;
+  deposits: bigint;
+  lastSlot: number;
+  implicitPadding20: Array<number>;
+};
+
+export const vaultStatsSerializer: s.Serializer<VaultStats> = s.struct<VaultStats>([
+  ["paused", s.u8],
+  ["implicitPadding1", s.array(s.u8, 7)],
+  ["deposits", s.u64],
+  ["lastSlot", s.u32],
+  ["implicitPadding20", s.array(s.u8, 4)],
+]);
+
+/** Decodes the VaultStats account from its data, ignoring any trailing bytes. */
+export function decodeVaultStats(data: Uint8Array): VaultStats {
+  return s.deserialize(vaultStatsSerializer, data);
+}
+
+/** Fetches and decodes the VaultStats account, failing if it doesn't exist. */
+export async function fetchVaultStats(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<VaultStats> {
+  const account = await fetchMaybeVaultStats(connection, address, commitment);
+  if (account == null) {
+    throw new Error(`VaultStats account ${address.toBase58()} not found`);
+  }
+  return account;
+}
+
+/** Fetches and decodes the VaultStats account, resolving `null` if it doesn't exist. */
+export async function fetchMaybeVaultStats(
+  connection: Connection,
+  address: PublicKey,
+  commitment?: Commitment
+): Promise<VaultStats | null> {
+  const info = await connection.getAccountInfo(address, commitment);
+  return info == null ? null : decodeVaultStats(info.data);
+}
@@ -0,0 +1,24 @@
+/** Error returned by the program, identified by its custom error code. */
+export class VaultError extends Error {
+  constructor(
+    readonly code: number,
+    readonly errorName: string,
+    message: string
+  ) {
+    super(message);
+    this.name = "VaultError";
+  }
+}
+
+/** Name and message of each error keyed by its custom error code. */
+export const ERRORS_BY_CODE: ReadonlyMap<number, { name: string; msg: string }> =
+  new Map([
+    [0, { name: "InvalidOwner", msg: "Vault is not owned by the signer" }],
+    [1, { name: "Empty", msg: "Vault has no balance left" }],
+  ]);
+
+/** Resolves the error from its custom program error code. */
+export function errorFromCode(code: number): VaultError | null {
+  const error = ERRORS_BY_CODE.get(code);
+  return error == null ? null : new VaultError(code, error.name, error.msg);
+}
@@ -0,0 +1,6 @@
+export * from "./accounts";
+export * from "./errors";
+export * from "./instructions";
+export * from "./programId";
+export * from "./types";
+export * as serializer from "./serializer";
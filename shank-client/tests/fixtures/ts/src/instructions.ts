@@ -0,0 +1,188 @@
+import {
+  AccountMeta,
+  PublicKey,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { PROGRAM_ID } from "./programId";
+import * as s from "./serializer";
+import {
+  DepositArgs,
+  depositArgsSerializer,
+  Schedule,
+  scheduleSerializer,
+  VaultKind,
+  vaultKindSerializer,
+  Frequency,
+  frequencySerializer,
+} from "./types";
+
+// -----------------
+// Create
+// -----------------
+/** Accounts of the Create instruction. */
+export type CreateInstructionAccounts = {
+  /** The vault PDA */
+  vault: PublicKey;
+  /** The owner paying for the vault */
+  owner: PublicKey;
+  /**
+   * The system program
+   * Defaults to `11111111111111111111111111111111`
+   */
+  systemProgram?: PublicKey;
+};
+
+export type CreateInstructionArgs = {
+  vaultKind: VaultKind;
+};
+
+export const createInstructionArgsSerializer: s.Serializer<CreateInstructionArgs> = s.struct<CreateInstructionArgs>([
+  ["vaultKind", s.lazy(() => vaultKindSerializer)],
+]);
+
+export const createInstructionDiscriminator = 0;
+
+/** Creates the Create instruction. */
+export function createCreateInstruction(
+  accounts: CreateInstructionAccounts,
+  args: CreateInstructionArgs,
+  programId: PublicKey = PROGRAM_ID
+): TransactionInstruction {
+  const keys: AccountMeta[] = [];
+  keys.push({ pubkey: accounts.vault, isWritable: true, isSigner: false });
+  keys.push({ pubkey: accounts.owner, isWritable: true, isSigner: true });
+  keys.push({ pubkey: accounts.systemProgram ?? new PublicKey("11111111111111111111111111111111"), isWritable: false, isSigner: false });
+
+  const writer = new s.Writer();
+  s.u8.serialize(writer, createInstructionDiscriminator);
+  createInstructionArgsSerializer.serialize(writer, args);
+  return new TransactionInstruction({
+    programId,
+    keys,
+    data: Buffer.from(writer.toBytes()),
+  });
+}
+
+// -----------------
+// Deposit
+// -----------------
+/** Accounts of the Deposit instruction. */
+export type DepositInstructionAccounts = {
+  vault: PublicKey;
+  owner: PublicKey;
+  referrer?: PublicKey;
+};
+
+export type DepositInstructionArgs = {
+  depositArgs: DepositArgs;
+};
+
+export const depositInstructionArgsSerializer: s.Serializer<DepositInstructionArgs> = s.struct<DepositInstructionArgs>([
+  ["depositArgs", s.lazy(() => depositArgsSerializer)],
+]);
+
+export const depositInstructionDiscriminator = 1;
+
+/** Creates the Deposit instruction. */
+export function createDepositInstruction(
+  accounts: DepositInstructionAccounts,
+  args: DepositInstructionArgs,
+  programId: PublicKey = PROGRAM_ID
+): TransactionInstruction {
+  const keys: AccountMeta[] = [];
+  keys.push({ pubkey: accounts.vault, isWritable: true, isSigner: false });
+  keys.push({ pubkey: accounts.owner, isWritable: false, isSigner: true });
+  keys.push(
+    accounts.referrer == null
+      ? { pubkey: programId, isWritable: false, isSigner: false }
+      : { pubkey: accounts.referrer, isWritable: false, isSigner: false }
+  );
+
+  const writer = new s.Writer();
+  s.u8.serialize(writer, depositInstructionDiscriminator);
+  depositInstructionArgsSerializer.serialize(writer, args);
+  return new TransactionInstruction({
+    programId,
+    keys,
+    data: Buffer.from(writer.toBytes()),
+  });
+}
+
+// -----------------
+// Close
+// -----------------
+/** Accounts of the Close instruction. */
+export type CloseInstructionAccounts = {
+  vault: PublicKey;
+  owner: PublicKey;
+  /** Defaults to `MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr` */
+  memoProgram?: PublicKey;
+};
+
+export const closeInstructionDiscriminator = 2;
+
+/** Creates the Close instruction. */
+export function createCloseInstruction(
+  accounts: CloseInstructionAccounts,
+  programId: PublicKey = PROGRAM_ID
+): TransactionInstruction {
+  const keys: AccountMeta[] = [];
+  keys.push({ pubkey: accounts.vault, isWritable: true, isSigner: false });
+  keys.push({ pubkey: accounts.owner, isWritable: false, isSigner: true });
+  keys.push({ pubkey: accounts.memoProgram ?? new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"), isWritable: false, isSigner: false });
+
+  const writer = new s.Writer();
+  s.u8.serialize(writer, closeInstructionDiscriminator);
+  return new TransactionInstruction({
+    programId,
+    keys,
+    data: Buffer.from(writer.toBytes()),
+  });
+}
+
+// -----------------
+// SetAllowance
+// -----------------
+/** Accounts of the SetAllowance instruction. */
+export type SetAllowanceInstructionAccounts = {
+  allowance: PublicKey;
+  owner: PublicKey;
+  /** Whether owner signs the transaction. */
+  ownerIsSigner?: boolean;
+  delegate?: PublicKey;
+};
+
+export type SetAllowanceInstructionArgs = {
+  amount: bigint;
+  schedule: Schedule | null;
+};
+
+export const setAllowanceInstructionArgsSerializer: s.Serializer<SetAllowanceInstructionArgs> = s.struct<SetAllowanceInstructionArgs>([
+  ["amount", s.u64],
+  ["schedule", s.option(s.lazy(() => scheduleSerializer))],
+]);
+
+export const setAllowanceInstructionDiscriminator = 3;
+
+/** Creates the SetAllowance instruction. */
+export function createSetAllowanceInstruction(
+  accounts: SetAllowanceInstructionAccounts,
+  args: SetAllowanceInstructionArgs,
+  programId: PublicKey = PROGRAM_ID
+): TransactionInstruction {
+  const keys: AccountMeta[] = [];
+  keys.push({ pubkey: accounts.allowance, isWritable: true, isSigner: false });
+  keys.push({ pubkey: accounts.owner, isWritable: false, isSigner: accounts.ownerIsSigner ?? false });
+  if (accounts.delegate != null) {
+    keys.push({ pubkey: accounts.delegate, isWritable: false, isSigner: false });
+  }
+
+  const writer = new s.Writer();
+  s.u8.serialize(writer, setAllowanceInstructionDiscriminator);
+  setAllowanceInstructionArgsSerializer.serialize(writer, args);
+  return new TransactionInstruction({
+    programId,
+    keys,
+    data: Buffer.from(writer.toBytes()),
+  });
+}
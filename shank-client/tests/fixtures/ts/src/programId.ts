@@ -0,0 +1,3 @@
+import { PublicKey } from "@solana/web3.js";
+
+export const PROGRAM_ID = new PublicKey("Vau1t11111111111111111111111111111111111111");
@@ -0,0 +1,40 @@
+import { PublicKey } from "@solana/web3.js";
+import * as s from "./serializer";
+
+export type DepositArgs = {
+  amount: bigint;
+  memo: string | null;
+};
+
+export const depositArgsSerializer: s.Serializer<DepositArgs> = s.struct<DepositArgs>([
+  ["amount", s.u64],
+  ["memo", s.option(s.string)],
+]);
+
+export type Schedule = {
+  frequency: Frequency;
+  limits: Map<string, bigint>;
+  window: [bigint, bigint];
+};
+
+export const scheduleSerializer: s.Serializer<Schedule> = s.struct<Schedule>([
+  ["frequency", s.lazy(() => frequencySerializer)],
+  ["limits", s.map(s.string, s.u64)],
+  ["window", s.tuple<[bigint, bigint]>([s.i64, s.i64])],
+]);
+
+export type VaultKind =
+  | { __kind: "Personal" }
+  | { __kind: "Shared"; members: number };
+
+export const vaultKindSerializer: s.Serializer<VaultKind> = s.dataEnum<VaultKind>([
+  ["Personal", null],
+  ["Shared", s.struct<any>([["members", s.u8]])],
+]);
+
+export enum Frequency {
+  Daily,
+  Weekly,
+}
+
+export const frequencySerializer: s.Serializer<Frequency> = s.scalarEnum<Frequency>(2);
@@ -0,0 +1,93 @@
+use std::{
+    fs::{self, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use shank_client::rust::generate_rust_client;
+use shank_idl::{
+    idl::Idl, idl_seed::IdlSeedsProgram, parse_file, ParseIdlConfig,
+};
+
+fn fixtures_dir() -> PathBuf {
+    let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    root_dir.join("tests").join("fixtures")
+}
+
+fn program_idl() -> Idl {
+    let file = fixtures_dir().join("program.rs");
+    let config = ParseIdlConfig {
+        program_name: "vault".to_string(),
+        program_version: "0.1.0".to_string(),
+        ..ParseIdlConfig::optional_program_address()
+    };
+    parse_file(&file, &config)
+        .expect("Parsing should not fail")
+        .expect("File contains IDL")
+}
+
+/// The fixture crate lives below the `shank-client` workspace member, which
+/// `workspace.exclude` cannot override, so it gets its own empty workspace.
+fn fixture_contents(path: &Path, contents: &str) -> String {
+    if path.ends_with("Cargo.toml") {
+        format!("{}\n[workspace]\n", contents)
+    } else {
+        contents.to_string()
+    }
+}
+
+#[test]
+fn rust_client_matches_expected_files() {
+    let files = generate_rust_client(&program_idl())
+        .expect("Generating client should not fail");
+
+    let expected_dir = fixtures_dir().join("rust");
+    for file in files {
+        let expected_file = expected_dir.join(&file.path);
+        let contents = fixture_contents(&file.path, &file.contents);
+        if std::env::var("UPDATE_CLIENT").is_ok() {
+            fs::create_dir_all(expected_file.parent().unwrap())
+                .expect("Unable to create directory");
+            fs::write(&expected_file, &contents).expect("Unable to write file");
+        } else {
+            let expected = read_to_string(&expected_file)
+                .expect("Unable to read expected file");
+            assert_eq!(contents, expected, "{} differs", file.path.display());
+        }
+    }
+}
+
+#[test]
+fn rust_client_requires_program_address() {
+    let mut idl = program_idl();
+    idl.metadata.address = None;
+
+    let err = generate_rust_client(&idl)
+        .expect_err("Generating client without address should fail");
+    assert!(err.to_string().contains("no program address"));
+}
+
+#[test]
+fn rust_client_derives_pdas_for_seeds_program() {
+    let mut idl = program_idl();
+    let vault = idl
+        .accounts
+        .iter_mut()
+        .find(|account| account.name == "Vault")
+        .unwrap();
+    vault.seeds_program = Some(IdlSeedsProgram::Path {
+        path: "crate::other_program::ID".to_string(),
+    });
+
+    let files =
+        generate_rust_client(&idl).expect("Generating client should not fail");
+    let accounts = files
+        .iter()
+        .find(|file| file.path.ends_with("accounts.rs"))
+        .unwrap();
+    assert!(accounts.contents.contains(
+        "    pub fn shank_pda(
+        owner: &::solana_program::pubkey::Pubkey,
+    ) -> (::solana_program::pubkey::Pubkey, u8) {
+        let program_id = &crate::other_program::ID;"
+    ));
+}
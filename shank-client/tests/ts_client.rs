@@ -0,0 +1,69 @@
+use std::{
+    fs::{self, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use shank_client::ts::generate_ts_client;
+use shank_idl::{idl::Idl, parse_file, ParseIdlConfig};
+
+fn fixtures_dir() -> PathBuf {
+    let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    root_dir.join("tests").join("fixtures")
+}
+
+fn program_idl() -> Idl {
+    let file = fixtures_dir().join("program.rs");
+    let config = ParseIdlConfig {
+        program_name: "vault".to_string(),
+        program_version: "0.1.0".to_string(),
+        ..ParseIdlConfig::optional_program_address()
+    };
+    parse_file(&file, &config)
+        .expect("Parsing should not fail")
+        .expect("File contains IDL")
+}
+
+#[test]
+fn ts_client_matches_expected_files() {
+    let files = generate_ts_client(&program_idl())
+        .expect("Generating client should not fail");
+
+    let expected_dir = fixtures_dir().join("ts");
+    for file in files {
+        let expected_file = expected_dir.join(&file.path);
+        if std::env::var("UPDATE_CLIENT").is_ok() {
+            fs::create_dir_all(expected_file.parent().unwrap())
+                .expect("Unable to create directory");
+            fs::write(&expected_file, &file.contents)
+                .expect("Unable to write file");
+        } else {
+            let expected = read_to_string(&expected_file)
+                .expect("Unable to read expected file");
+            assert_eq!(
+                file.contents,
+                expected,
+                "{} differs",
+                file.path.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn ts_client_optional_accounts_strategies() {
+    let files = generate_ts_client(&program_idl())
+        .expect("Generating client should not fail");
+    let instructions = files
+        .iter()
+        .find(|file| file.path == Path::new("src/instructions.ts"))
+        .expect("Instructions should be generated");
+
+    // Omitted optional account is replaced with the program id by default
+    assert!(instructions.contents.contains(
+        "accounts.referrer == null\n      ? { pubkey: programId, isWritable: false, isSigner: false }"
+    ));
+    // but left out when using the legacy strategy
+    assert!(instructions
+        .contents
+        .contains("if (accounts.delegate != null) {"));
+}
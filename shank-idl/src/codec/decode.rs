@@ -0,0 +1,602 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, format_err, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use super::{is_padding, type_definitions};
+use crate::{
+    idl::Idl,
+    idl_field::IdlField,
+    idl_type::IdlType,
+    idl_type_definition::{
+        IdlSerialization, IdlTypeDefinition, IdlTypeDefinitionTy,
+    },
+    idl_variant::EnumFields,
+};
+
+// -----------------
+// Reader
+// -----------------
+
+/// Reads the bytes of Borsh serialized data while tracking the offset so that
+/// errors can point at the offending bytes.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// The offset of the next byte to be read.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The number of bytes that haven't been read yet.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    pub fn read(&mut self, len: usize, what: &str) -> Result<&'a [u8]> {
+        if self.remaining() < len {
+            bail!(
+                "Unexpected end of data reading {} at offset {}, needed {} bytes but only {} remain",
+                what,
+                self.offset,
+                len,
+                self.remaining()
+            );
+        }
+        let bytes = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self, what: &str) -> Result<[u8; N]> {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.read(N, what)?);
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self, what: &str) -> Result<u8> {
+        Ok(self.read(1, what)?[0])
+    }
+
+    /// Reads the `u32` length prefix of Borsh serialized strings and
+    /// collections.
+    fn read_len(&mut self, what: &str) -> Result<usize> {
+        let len = u32::from_le_bytes(self.read_array(what)?);
+        Ok(len as usize)
+    }
+}
+
+// -----------------
+// IdlDecoder
+// -----------------
+
+/// Decodes Borsh serialized data into JSON according to the types of an IDL.
+///
+/// Integers up to 64 bits are decoded into JSON numbers while 128 bit integers
+/// are decoded into strings since they exceed what JSON consumers can
+/// represent. Public keys are base58 encoded, unit enum variants are decoded
+/// into their name and variants with data into `{ "Variant": data }`.
+pub struct IdlDecoder<'a> {
+    definitions: HashMap<&'a str, &'a IdlTypeDefinition>,
+}
+
+impl<'a> IdlDecoder<'a> {
+    pub fn new(idl: &'a Idl) -> Self {
+        Self {
+            definitions: type_definitions(idl),
+        }
+    }
+
+    /// Finds the account or type of the IDL with the provided name.
+    pub fn definition(&self, name: &str) -> Result<&'a IdlTypeDefinition> {
+        self.definitions.get(name).copied().ok_or_else(|| {
+            format_err!("Type {} is not defined in the IDL", name)
+        })
+    }
+
+    pub fn decode(&self, ty: &IdlType, reader: &mut Reader) -> Result<Value> {
+        let value = match ty {
+            IdlType::Bool => match reader.read_u8("bool")? {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                value => bail!(
+                    "Invalid bool value {} at offset {}",
+                    value,
+                    reader.offset() - 1
+                ),
+            },
+            IdlType::U8 => reader.read_u8("u8")?.into(),
+            IdlType::I8 => i8::from_le_bytes(reader.read_array("i8")?).into(),
+            IdlType::U16 => {
+                u16::from_le_bytes(reader.read_array("u16")?).into()
+            }
+            IdlType::I16 => {
+                i16::from_le_bytes(reader.read_array("i16")?).into()
+            }
+            IdlType::U32 => {
+                u32::from_le_bytes(reader.read_array("u32")?).into()
+            }
+            IdlType::I32 => {
+                i32::from_le_bytes(reader.read_array("i32")?).into()
+            }
+            IdlType::U64 => {
+                u64::from_le_bytes(reader.read_array("u64")?).into()
+            }
+            IdlType::I64 => {
+                i64::from_le_bytes(reader.read_array("i64")?).into()
+            }
+            IdlType::U128 => u128::from_le_bytes(reader.read_array("u128")?)
+                .to_string()
+                .into(),
+            IdlType::I128 => i128::from_le_bytes(reader.read_array("i128")?)
+                .to_string()
+                .into(),
+            IdlType::String => {
+                let len = reader.read_len("string length")?;
+                let offset = reader.offset();
+                let bytes = reader.read(len, "string")?;
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|_| {
+                        format_err!("Invalid UTF-8 string at offset {}", offset)
+                    })?
+                    .into()
+            }
+            IdlType::Bytes => {
+                let len = reader.read_len("bytes length")?;
+                reader.read(len, "bytes")?.to_vec().into()
+            }
+            IdlType::PublicKey => bs58::encode(reader.read(32, "public key")?)
+                .into_string()
+                .into(),
+            IdlType::Defined(name) => {
+                self.decode_definition(self.definition(name)?, reader)?
+            }
+            IdlType::Option(inner) => {
+                let offset = reader.offset();
+                match reader.read_u8("option tag")? {
+                    0 => Value::Null,
+                    1 => self.decode(inner, reader)?,
+                    tag => {
+                        bail!("Invalid option tag {} at offset {}", tag, offset)
+                    }
+                }
+            }
+            // Stored as the raw inner value which is None if it holds the
+            // sentinel
+            IdlType::FixedSizeOption { inner, sentinel } => {
+                let start = reader.clone();
+                let value = self.decode(inner, reader)?;
+                let bytes = &start.data[start.offset..reader.offset()];
+                match sentinel {
+                    Some(sentinel) if sentinel.as_slice() == bytes => {
+                        Value::Null
+                    }
+                    _ => value,
+                }
+            }
+            IdlType::Array(inner, len) => Value::Array(
+                (0..*len)
+                    .map(|_| self.decode(inner, reader))
+                    .collect::<Result<Vec<Value>>>()?,
+            ),
+            IdlType::Vec(inner)
+            | IdlType::HashSet(inner)
+            | IdlType::BTreeSet(inner) => {
+                let len = reader.read_len("collection length")?;
+                // Not preallocated since the length may be bogus
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.decode(inner, reader)?);
+                }
+                Value::Array(items)
+            }
+            IdlType::Tuple(inners) => Value::Array(
+                inners
+                    .iter()
+                    .map(|inner| self.decode(inner, reader))
+                    .collect::<Result<Vec<Value>>>()?,
+            ),
+            IdlType::HashMap(key, val) | IdlType::BTreeMap(key, val) => {
+                let len = reader.read_len("map length")?;
+                let mut entries = Map::new();
+                for _ in 0..len {
+                    let key = match self.decode(key, reader)? {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    entries.insert(key, self.decode(val, reader)?);
+                }
+                Value::Object(entries)
+            }
+        };
+        Ok(value)
+    }
+
+    /// Decodes a value of the account or type, honoring the layout of
+    /// zero-copy structs.
+    pub fn decode_definition(
+        &self,
+        type_definition: &IdlTypeDefinition,
+        reader: &mut Reader,
+    ) -> Result<Value> {
+        let name = &type_definition.name;
+        match &type_definition.ty {
+            IdlTypeDefinitionTy::Struct { .. } => {
+                Ok(fields_object(self.decode_struct(type_definition, reader)?))
+            }
+            IdlTypeDefinitionTy::Enum { variants } => {
+                let offset = reader.offset();
+                let idx = reader.read_u8("enum variant")?;
+                let variant = variants.get(idx as usize).ok_or_else(|| {
+                    format_err!(
+                        "Invalid variant {} of enum {} at offset {}",
+                        idx,
+                        name,
+                        offset
+                    )
+                })?;
+                let fields = match &variant.fields {
+                    None => return Ok(Value::String(variant.name.clone())),
+                    Some(EnumFields::Named(fields)) => {
+                        fields_object(self.decode_fields(name, fields, reader)?)
+                    }
+                    Some(EnumFields::Tuple(tys)) => Value::Array(
+                        tys.iter()
+                            .map(|ty| self.decode(ty, reader))
+                            .collect::<Result<Vec<Value>>>()?,
+                    ),
+                };
+                let mut value = Map::new();
+                value.insert(variant.name.clone(), fields);
+                Ok(Value::Object(value))
+            }
+        }
+    }
+
+    /// Decodes the fields of the struct along with their location in the
+    /// data.
+    fn decode_struct<'t>(
+        &self,
+        type_definition: &'t IdlTypeDefinition,
+        reader: &mut Reader,
+    ) -> Result<Vec<DecodedField<'t>>> {
+        let name = &type_definition.name;
+        match &type_definition.ty {
+            IdlTypeDefinitionTy::Struct { fields }
+                if type_definition.serialization
+                    == Some(IdlSerialization::Bytemuck) =>
+            {
+                self.decode_zero_copy(type_definition, fields, reader)
+            }
+            IdlTypeDefinitionTy::Struct { fields } => {
+                self.decode_fields(name, fields, reader)
+            }
+            IdlTypeDefinitionTy::Enum { .. } => {
+                bail!("Enum {} has no fields", name)
+            }
+        }
+    }
+
+    fn decode_fields<'t>(
+        &self,
+        name: &str,
+        fields: &'t [IdlField],
+        reader: &mut Reader,
+    ) -> Result<Vec<DecodedField<'t>>> {
+        let mut decoded = Vec::with_capacity(fields.len());
+        for field in fields {
+            let offset = reader.offset();
+            let value = self.decode(&field.ty, reader).map_err(|err| {
+                format_err!("Field '{}' of {}: {}", field.name, name, err)
+            })?;
+            decoded.push(DecodedField {
+                field,
+                offset,
+                size: reader.offset() - offset,
+                value,
+            });
+        }
+        Ok(decoded)
+    }
+
+    /// Decodes the fields of a `#[repr(C)]` struct from their offsets, skipping
+    /// the padding in between.
+    fn decode_zero_copy<'t>(
+        &self,
+        type_definition: &IdlTypeDefinition,
+        fields: &'t [IdlField],
+        reader: &mut Reader,
+    ) -> Result<Vec<DecodedField<'t>>> {
+        let name = &type_definition.name;
+        let size = match type_definition.size {
+            Some(size) => size,
+            None => bail!("The size of zero-copy type {} is missing", name),
+        };
+        let start = reader.offset();
+        let data = reader.read(size, name)?;
+
+        let mut decoded = Vec::with_capacity(fields.len());
+        for field in fields {
+            let offset = match field.offset {
+                Some(offset) if offset <= size => offset,
+                _ => bail!(
+                    "Field '{}' of zero-copy type {} has no valid offset",
+                    field.name,
+                    name
+                ),
+            };
+            let mut field_reader = Reader::new(&data[offset..]);
+            let value =
+                self.decode(&field.ty, &mut field_reader).map_err(|err| {
+                    format_err!(
+                        "Field '{}' of {} at offset {}: {}",
+                        field.name,
+                        name,
+                        start + offset,
+                        err
+                    )
+                })?;
+            decoded.push(DecodedField {
+                field,
+                offset: start + offset,
+                size: field_reader.offset(),
+                value,
+            });
+        }
+        Ok(decoded)
+    }
+}
+
+/// A field of a struct decoded from the data located at `offset`.
+struct DecodedField<'t> {
+    field: &'t IdlField,
+    offset: usize,
+    size: usize,
+    value: Value,
+}
+
+/// Collects the decoded fields into an object, omitting `#[padding]` fields
+/// since they don't hold any data.
+fn fields_object(fields: Vec<DecodedField>) -> Value {
+    Value::Object(
+        fields
+            .into_iter()
+            .filter(|decoded| !is_padding(decoded.field))
+            .map(|decoded| (decoded.field.name.clone(), decoded.value))
+            .collect(),
+    )
+}
+
+// -----------------
+// Instructions
+// -----------------
+
+/// The instruction and its args decoded from instruction data.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DecodedInstruction {
+    pub name: String,
+    pub discriminant: u8,
+    pub args: Map<String, Value>,
+}
+
+/// Decodes the instruction data, identifying the instruction by its leading
+/// discriminant.
+///
+/// Fails if no instruction of the IDL has the discriminant or if the data
+/// holds more bytes than the args.
+pub fn decode_instruction(
+    idl: &Idl,
+    data: &[u8],
+) -> Result<DecodedInstruction> {
+    let decoder = IdlDecoder::new(idl);
+    let mut reader = Reader::new(data);
+    let discriminant = reader.read_u8("instruction discriminant")?;
+    let ix = idl
+        .instructions
+        .iter()
+        .find(|ix| ix.discriminant.value == discriminant)
+        .ok_or_else(|| {
+            format_err!(
+                "No instruction with discriminant {} found in the IDL",
+                discriminant
+            )
+        })?;
+
+    let mut args = Map::new();
+    for arg in &ix.args {
+        let value = decoder.decode(&arg.ty, &mut reader).map_err(|err| {
+            format_err!(
+                "Arg '{}' of instruction {}: {}",
+                arg.name,
+                ix.name,
+                err
+            )
+        })?;
+        args.insert(arg.name.clone(), value);
+    }
+    if reader.remaining() > 0 {
+        bail!(
+            "Instruction {} has {} trailing bytes after its args",
+            ix.name,
+            reader.remaining()
+        );
+    }
+
+    Ok(DecodedInstruction {
+        name: ix.name.clone(),
+        discriminant,
+        args,
+    })
+}
+
+// -----------------
+// Accounts
+// -----------------
+
+/// The account and its fields decoded from account data.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DecodedAccount {
+    pub name: String,
+    pub data: Value,
+}
+
+/// The location of a top level field of an account in its data.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FieldLayout {
+    pub name: String,
+    pub offset: usize,
+    pub size: usize,
+    pub padding: bool,
+}
+
+/// Finds the account of the IDL with the provided name or, if none is
+/// provided, the account whose `discriminator` the data starts with.
+///
+/// Detection requires accounts to declare their discriminator via
+/// `#[shank(loaders(discriminator = ..))]`. The longest matching
+/// discriminator wins if several match.
+pub fn find_account<'a>(
+    idl: &'a Idl,
+    name: Option<&str>,
+    data: &[u8],
+) -> Result<&'a IdlTypeDefinition> {
+    if let Some(name) = name {
+        return idl
+            .accounts
+            .iter()
+            .find(|account| account.name == name)
+            .ok_or_else(|| {
+                format_err!("Account {} is not defined in the IDL", name)
+            });
+    }
+
+    idl.accounts
+        .iter()
+        .filter_map(|account| {
+            account
+                .discriminator
+                .as_ref()
+                .filter(|discriminator| data.starts_with(discriminator))
+                .map(|discriminator| (discriminator.len(), account))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, account)| account)
+        .ok_or_else(|| {
+            format_err!(
+                "No account of the IDL has a discriminator matching the data, provide the account type instead"
+            )
+        })
+}
+
+/// Decodes the account data into the account with the provided name or the
+/// one detected via its discriminator.
+///
+/// Bytes following the account's fields are ignored since accounts are
+/// commonly allocated with extra space.
+pub fn decode_account(
+    idl: &Idl,
+    name: Option<&str>,
+    data: &[u8],
+) -> Result<DecodedAccount> {
+    let account = find_account(idl, name, data)?;
+    let decoder = IdlDecoder::new(idl);
+    let data = decoder.decode_definition(account, &mut Reader::new(data))?;
+    Ok(DecodedAccount {
+        name: account.name.clone(),
+        data,
+    })
+}
+
+/// Decodes the account data and returns the offset and size of each of its
+/// fields, including `#[padding]` fields.
+pub fn account_layout(
+    idl: &Idl,
+    name: Option<&str>,
+    data: &[u8],
+) -> Result<(String, Vec<FieldLayout>)> {
+    let account = find_account(idl, name, data)?;
+    let decoder = IdlDecoder::new(idl);
+    let fields = decoder
+        .decode_struct(account, &mut Reader::new(data))?
+        .into_iter()
+        .map(|decoded| FieldLayout {
+            name: decoded.field.name.clone(),
+            offset: decoded.offset,
+            size: decoded.size,
+            padding: is_padding(decoded.field),
+        })
+        .collect();
+    Ok((account.name.clone(), fields))
+}
+
+// -----------------
+// Events
+// -----------------
+
+/// The event and its fields decoded from `Program data:` logs.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DecodedEvent {
+    pub name: String,
+    pub data: Map<String, Value>,
+}
+
+/// The discriminator prefixing the data of an event, i.e. the first 8 bytes
+/// of `sha256("event:<name>")` as established by Anchor.
+pub fn event_discriminator(name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("event:{}", name).as_bytes());
+    let mut discriminator = [0; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Decodes data logged via `sol_log_data` into the event of the IDL whose
+/// discriminator it starts with.
+///
+/// Returns `None` if the data doesn't belong to any event of the IDL.
+pub fn decode_event(idl: &Idl, data: &[u8]) -> Result<Option<DecodedEvent>> {
+    let events = idl.events.as_deref().unwrap_or_default();
+    let event = match events
+        .iter()
+        .find(|event| data.starts_with(&event_discriminator(&event.name)))
+    {
+        Some(event) => event,
+        None => return Ok(None),
+    };
+
+    let decoder = IdlDecoder::new(idl);
+    let mut reader = Reader::new(&data[8..]);
+    let mut fields = Map::new();
+    for field in &event.fields {
+        let value = decoder.decode(&field.ty, &mut reader).map_err(|err| {
+            format_err!(
+                "Field '{}' of event {}: {}",
+                field.name,
+                event.name,
+                err
+            )
+        })?;
+        fields.insert(field.name.clone(), value);
+    }
+    if reader.remaining() > 0 {
+        bail!(
+            "Event {} has {} trailing bytes after its fields",
+            event.name,
+            reader.remaining()
+        );
+    }
+
+    Ok(Some(DecodedEvent {
+        name: event.name.clone(),
+        data: fields,
+    }))
+}
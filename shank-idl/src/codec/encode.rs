@@ -0,0 +1,782 @@
+use std::{collections::HashMap, convert::TryFrom, str::FromStr};
+
+use anyhow::{bail, format_err, Result};
+use serde_json::{Map, Value};
+
+use super::{is_padding, type_definitions};
+use crate::{
+    idl::Idl,
+    idl_field::IdlField,
+    idl_instruction::{IdlAccount, IdlAccountItem},
+    idl_type::IdlType,
+    idl_type_definition::{
+        IdlSerialization, IdlTypeDefinition, IdlTypeDefinitionTy,
+    },
+    idl_variant::{EnumFields, IdlEnumVariant},
+};
+
+/// Parses an integer provided either as JSON number or as string, the latter
+/// being required for integers exceeding what JSON numbers can represent.
+fn integer<T: FromStr>(value: &Value, what: &str) -> Result<T> {
+    let parsed = match value {
+        Value::Number(number) => number.to_string().parse().ok(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| format_err!("Expected a {}, found {}", what, value))
+}
+
+fn pubkey(value: &Value) -> Result<[u8; 32]> {
+    value
+        .as_str()
+        .and_then(|s| bs58::decode(s).into_vec().ok())
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| {
+            format_err!("Expected a base58 encoded public key, found {}", value)
+        })
+}
+
+fn array<'v>(value: &'v Value, what: &str) -> Result<&'v Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| format_err!("Expected {}, found {}", what, value))
+}
+
+fn object<'v>(value: &'v Value, what: &str) -> Result<&'v Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| format_err!("Expected {}, found {}", what, value))
+}
+
+fn write_len(len: usize, out: &mut Vec<u8>) -> Result<()> {
+    let len = u32::try_from(len)
+        .map_err(|_| format_err!("Length {} exceeds u32::MAX", len))?;
+    out.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+/// The value `#[padding]` fields that aren't provided are encoded as, i.e.
+/// zeros for byte arrays.
+fn padding_value(ty: &IdlType) -> Value {
+    match ty {
+        IdlType::Bool => Value::Bool(false),
+        IdlType::PublicKey => {
+            Value::String(bs58::encode([0; 32]).into_string())
+        }
+        IdlType::Array(inner, len) => {
+            Value::Array(vec![padding_value(inner); *len])
+        }
+        IdlType::U8
+        | IdlType::U16
+        | IdlType::U32
+        | IdlType::U64
+        | IdlType::U128
+        | IdlType::I8
+        | IdlType::I16
+        | IdlType::I32
+        | IdlType::I64
+        | IdlType::I128 => Value::from(0),
+        _ => Value::Null,
+    }
+}
+
+/// Resolves the variant of the enum the value is provided for, returning its
+/// index and the value of its fields.
+fn enum_variant<'d, 'v>(
+    name: &str,
+    variants: &'d [IdlEnumVariant],
+    value: &'v Value,
+) -> Result<(usize, &'d IdlEnumVariant, &'v Value)> {
+    let (variant_name, fields) = match value {
+        Value::String(variant_name) => (variant_name, &Value::Null),
+        Value::Object(variant) if variant.len() == 1 => {
+            variant.iter().next().unwrap()
+        }
+        _ => bail!("Expected a variant of enum {}, found {}", name, value),
+    };
+    let (idx, variant) = variants
+        .iter()
+        .enumerate()
+        .find(|(_, variant)| &variant.name == variant_name)
+        .ok_or_else(|| {
+            format_err!("Unknown variant '{}' of enum {}", variant_name, name)
+        })?;
+    Ok((idx, variant, fields))
+}
+
+/// A map key or set item ordered the way its Rust type derives `Ord`, which
+/// is the order Borsh serializes maps and sets in.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Signed(i128),
+    Unsigned(u128),
+    Bytes(Vec<u8>),
+    Seq(Vec<SortKey>),
+}
+
+/// Fails if the object has keys not part of the provided names.
+fn check_unknown_keys<'n>(
+    values: &Map<String, Value>,
+    names: impl Iterator<Item = &'n str> + Clone,
+    what: &str,
+    owner: &str,
+) -> Result<()> {
+    match values
+        .keys()
+        .find(|key| !names.clone().any(|name| name == key.as_str()))
+    {
+        Some(key) => bail!("Unknown {} '{}' of {}", what, key, owner),
+        None => Ok(()),
+    }
+}
+
+// -----------------
+// IdlEncoder
+// -----------------
+
+/// Encodes JSON into Borsh serialized data according to the types of an IDL.
+///
+/// Accepts the JSON [super::IdlDecoder] produces, i.e. 128 bit integers may
+/// be provided as strings and enums as the variant name or
+/// `{ "Variant": data }`.
+pub struct IdlEncoder<'a> {
+    definitions: HashMap<&'a str, &'a IdlTypeDefinition>,
+}
+
+impl<'a> IdlEncoder<'a> {
+    pub fn new(idl: &'a Idl) -> Self {
+        Self {
+            definitions: type_definitions(idl),
+        }
+    }
+
+    pub fn encode(
+        &self,
+        ty: &IdlType,
+        value: &Value,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        match ty {
+            IdlType::Bool => match value {
+                Value::Bool(value) => out.push(*value as u8),
+                _ => bail!("Expected a bool, found {}", value),
+            },
+            IdlType::U8 => out.push(integer::<u8>(value, "u8")?),
+            IdlType::I8 => out
+                .extend_from_slice(&integer::<i8>(value, "i8")?.to_le_bytes()),
+            IdlType::U16 => out.extend_from_slice(
+                &integer::<u16>(value, "u16")?.to_le_bytes(),
+            ),
+            IdlType::I16 => out.extend_from_slice(
+                &integer::<i16>(value, "i16")?.to_le_bytes(),
+            ),
+            IdlType::U32 => out.extend_from_slice(
+                &integer::<u32>(value, "u32")?.to_le_bytes(),
+            ),
+            IdlType::I32 => out.extend_from_slice(
+                &integer::<i32>(value, "i32")?.to_le_bytes(),
+            ),
+            IdlType::U64 => out.extend_from_slice(
+                &integer::<u64>(value, "u64")?.to_le_bytes(),
+            ),
+            IdlType::I64 => out.extend_from_slice(
+                &integer::<i64>(value, "i64")?.to_le_bytes(),
+            ),
+            IdlType::U128 => out.extend_from_slice(
+                &integer::<u128>(value, "u128")?.to_le_bytes(),
+            ),
+            IdlType::I128 => out.extend_from_slice(
+                &integer::<i128>(value, "i128")?.to_le_bytes(),
+            ),
+            IdlType::String => {
+                let s = value.as_str().ok_or_else(|| {
+                    format_err!("Expected a string, found {}", value)
+                })?;
+                write_len(s.len(), out)?;
+                out.extend_from_slice(s.as_bytes());
+            }
+            IdlType::Bytes => {
+                let bytes = array(value, "an array of bytes")?;
+                write_len(bytes.len(), out)?;
+                for byte in bytes {
+                    out.push(integer::<u8>(byte, "u8")?);
+                }
+            }
+            IdlType::PublicKey => out.extend_from_slice(&pubkey(value)?),
+            IdlType::Defined(name) => {
+                let type_definition =
+                    self.definitions.get(name.as_str()).ok_or_else(|| {
+                        format_err!("Type {} is not defined in the IDL", name)
+                    })?;
+                self.encode_definition(type_definition, value, out)?
+            }
+            IdlType::Option(inner) => match value {
+                Value::Null => out.push(0),
+                value => {
+                    out.push(1);
+                    self.encode(inner, value, out)?;
+                }
+            },
+            // Stored as the raw inner value which holds the sentinel if None
+            IdlType::FixedSizeOption { inner, sentinel } => {
+                match (value, sentinel) {
+                    (Value::Null, Some(sentinel)) => {
+                        out.extend_from_slice(sentinel)
+                    }
+                    (Value::Null, None) => {
+                        bail!("Cannot encode None without a sentinel")
+                    }
+                    (value, _) => self.encode(inner, value, out)?,
+                }
+            }
+            IdlType::Array(inner, len) => {
+                let items = array(value, "an array")?;
+                if items.len() != *len {
+                    bail!(
+                        "Expected an array of {} items, found {} items",
+                        len,
+                        items.len()
+                    );
+                }
+                for item in items {
+                    self.encode(inner, item, out)?;
+                }
+            }
+            IdlType::Vec(inner) => {
+                let items = array(value, "an array")?;
+                write_len(items.len(), out)?;
+                for item in items {
+                    self.encode(inner, item, out)?;
+                }
+            }
+            IdlType::HashSet(inner) | IdlType::BTreeSet(inner) => {
+                let items = array(value, "an array")?
+                    .iter()
+                    .map(|item| (item.clone(), ()))
+                    .collect();
+                let items = self.sorted_by_key(inner, items, "set item")?;
+                write_len(items.len(), out)?;
+                for (item, _) in items {
+                    self.encode(inner, &item, out)?;
+                }
+            }
+            IdlType::Tuple(inners) => {
+                let items = array(value, "an array")?;
+                if items.len() != inners.len() {
+                    bail!(
+                        "Expected a tuple of {} items, found {} items",
+                        inners.len(),
+                        items.len()
+                    );
+                }
+                for (inner, item) in inners.iter().zip(items) {
+                    self.encode(inner, item, out)?;
+                }
+            }
+            IdlType::HashMap(key_ty, val_ty)
+            | IdlType::BTreeMap(key_ty, val_ty) => {
+                let entries = object(value, "an object")?
+                    .iter()
+                    .map(|(key, val)| {
+                        // Keys which aren't strings are stringified JSON
+                        let key = match key_ty.as_ref() {
+                            IdlType::String | IdlType::PublicKey => {
+                                Value::String(key.clone())
+                            }
+                            _ => serde_json::from_str(key)
+                                .unwrap_or_else(|_| Value::String(key.clone())),
+                        };
+                        (key, val)
+                    })
+                    .collect();
+                let entries = self.sorted_by_key(key_ty, entries, "map key")?;
+                write_len(entries.len(), out)?;
+                for (key, val) in entries {
+                    self.encode(key_ty, &key, out)?;
+                    self.encode(val_ty, val, out)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes a value of the account or type, honoring the layout of
+    /// zero-copy structs.
+    pub fn encode_definition(
+        &self,
+        type_definition: &IdlTypeDefinition,
+        value: &Value,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        let name = &type_definition.name;
+        match &type_definition.ty {
+            IdlTypeDefinitionTy::Struct { fields }
+                if type_definition.serialization
+                    == Some(IdlSerialization::Bytemuck) =>
+            {
+                self.encode_zero_copy(type_definition, fields, value, out)
+            }
+            IdlTypeDefinitionTy::Struct { fields } => {
+                self.encode_fields(name, fields, value, out)
+            }
+            IdlTypeDefinitionTy::Enum { variants } => {
+                let (idx, variant, fields) =
+                    enum_variant(name, variants, value)?;
+                out.push(idx as u8);
+                match &variant.fields {
+                    None if fields.is_null() => Ok(()),
+                    None => bail!(
+                        "Variant {} of enum {} has no data, found {}",
+                        variant.name,
+                        name,
+                        fields
+                    ),
+                    Some(EnumFields::Named(variant_fields)) => {
+                        self.encode_fields(name, variant_fields, fields, out)
+                    }
+                    Some(EnumFields::Tuple(tys)) => {
+                        self.encode(&IdlType::Tuple(tys.clone()), fields, out)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sorts the map entries or set items by their key the way Borsh
+    /// serializes them, failing if a key is provided more than once.
+    fn sorted_by_key<T>(
+        &self,
+        key_ty: &IdlType,
+        items: Vec<(Value, T)>,
+        what: &str,
+    ) -> Result<Vec<(Value, T)>> {
+        let mut items = items
+            .into_iter()
+            .map(|(key, item)| Ok((self.sort_key(key_ty, &key)?, key, item)))
+            .collect::<Result<Vec<_>>>()?;
+        items.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+        if let Some(duplicate) = items.windows(2).find(|w| w[0].0 == w[1].0) {
+            bail!("Duplicate {} {}", what, duplicate[1].1);
+        }
+        Ok(items
+            .into_iter()
+            .map(|(_, key, item)| (key, item))
+            .collect())
+    }
+
+    /// Resolves the value of a map key or set item into the order its Rust
+    /// type derives.
+    fn sort_key(&self, ty: &IdlType, value: &Value) -> Result<SortKey> {
+        let sort_keys = |ty: &IdlType, items: &[Value]| {
+            items
+                .iter()
+                .map(|item| self.sort_key(ty, item))
+                .collect::<Result<Vec<_>>>()
+        };
+        let sort_key = match ty {
+            IdlType::Bool => match value {
+                Value::Bool(value) => SortKey::Unsigned(*value as u128),
+                _ => bail!("Expected a bool, found {}", value),
+            },
+            IdlType::U8
+            | IdlType::U16
+            | IdlType::U32
+            | IdlType::U64
+            | IdlType::U128 => {
+                SortKey::Unsigned(integer(value, "an unsigned integer")?)
+            }
+            IdlType::I8
+            | IdlType::I16
+            | IdlType::I32
+            | IdlType::I64
+            | IdlType::I128 => {
+                SortKey::Signed(integer(value, "a signed integer")?)
+            }
+            IdlType::String => SortKey::Bytes(
+                value
+                    .as_str()
+                    .ok_or_else(|| {
+                        format_err!("Expected a string, found {}", value)
+                    })?
+                    .as_bytes()
+                    .to_vec(),
+            ),
+            IdlType::Bytes => SortKey::Bytes(
+                array(value, "an array of bytes")?
+                    .iter()
+                    .map(|byte| integer::<u8>(byte, "u8"))
+                    .collect::<Result<_>>()?,
+            ),
+            IdlType::PublicKey => SortKey::Bytes(pubkey(value)?.to_vec()),
+            // None orders before Some
+            IdlType::Option(inner) | IdlType::FixedSizeOption { inner, .. } => {
+                match value {
+                    Value::Null => SortKey::Seq(vec![]),
+                    value => SortKey::Seq(vec![self.sort_key(inner, value)?]),
+                }
+            }
+            IdlType::Array(inner, _) | IdlType::Vec(inner) => {
+                SortKey::Seq(sort_keys(inner, array(value, "an array")?)?)
+            }
+            IdlType::BTreeSet(inner) => {
+                let mut items = sort_keys(inner, array(value, "an array")?)?;
+                items.sort();
+                SortKey::Seq(items)
+            }
+            IdlType::Tuple(inners) => SortKey::Seq(
+                inners
+                    .iter()
+                    .zip(array(value, "an array")?)
+                    .map(|(inner, item)| self.sort_key(inner, item))
+                    .collect::<Result<_>>()?,
+            ),
+            IdlType::Defined(name) => {
+                let type_definition =
+                    self.definitions.get(name.as_str()).ok_or_else(|| {
+                        format_err!("Type {} is not defined in the IDL", name)
+                    })?;
+                self.definition_sort_key(type_definition, value)?
+            }
+            IdlType::HashSet(_)
+            | IdlType::HashMap(..)
+            | IdlType::BTreeMap(..) => {
+                bail!("Maps and hash sets cannot be map keys or set items")
+            }
+        };
+        Ok(sort_key)
+    }
+
+    /// Resolves the order of a struct by its fields and of an enum by its
+    /// discriminant and then its fields.
+    fn definition_sort_key(
+        &self,
+        type_definition: &IdlTypeDefinition,
+        value: &Value,
+    ) -> Result<SortKey> {
+        let name = &type_definition.name;
+        let fields_sort_key = |fields: &[IdlField], value: &Value| {
+            Self::field_values(name, fields, value)?
+                .into_iter()
+                .zip(fields)
+                .map(|((value, padding), field)| {
+                    self.sort_key(&field.ty, padding.as_ref().unwrap_or(value))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        match &type_definition.ty {
+            IdlTypeDefinitionTy::Struct { fields } => {
+                Ok(SortKey::Seq(fields_sort_key(fields, value)?))
+            }
+            IdlTypeDefinitionTy::Enum { variants } => {
+                let (idx, variant, fields) =
+                    enum_variant(name, variants, value)?;
+                let mut sort_key = vec![SortKey::Unsigned(
+                    variant.discriminant_at(idx) as u128,
+                )];
+                match &variant.fields {
+                    None => {}
+                    Some(EnumFields::Named(variant_fields)) => sort_key
+                        .extend(fields_sort_key(variant_fields, fields)?),
+                    Some(EnumFields::Tuple(tys)) => sort_key.push(
+                        self.sort_key(&IdlType::Tuple(tys.clone()), fields)?,
+                    ),
+                }
+                Ok(SortKey::Seq(sort_key))
+            }
+        }
+    }
+
+    /// Resolves the values of the fields, defaulting `#[padding]` fields to
+    /// zeros.
+    fn field_values<'v>(
+        name: &str,
+        fields: &[IdlField],
+        value: &'v Value,
+    ) -> Result<Vec<(&'v Value, Option<Value>)>> {
+        let values = object(value, &format!("an object of {}", name))?;
+        check_unknown_keys(
+            values,
+            fields.iter().map(|field| field.name.as_str()),
+            "field",
+            name,
+        )?;
+        fields
+            .iter()
+            .map(|field| match values.get(&field.name) {
+                Some(value) => Ok((value, None)),
+                None if is_padding(field) => {
+                    Ok((&Value::Null, Some(padding_value(&field.ty))))
+                }
+                None => bail!("Missing field '{}' of {}", field.name, name),
+            })
+            .collect()
+    }
+
+    fn encode_fields(
+        &self,
+        name: &str,
+        fields: &[IdlField],
+        value: &Value,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        let values = Self::field_values(name, fields, value)?;
+        for (field, (value, padding)) in fields.iter().zip(values) {
+            self.encode(&field.ty, padding.as_ref().unwrap_or(value), out)
+                .map_err(|err| {
+                    format_err!("Field '{}' of {}: {}", field.name, name, err)
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Encodes the fields of a `#[repr(C)]` struct at their offsets, zeroing
+    /// the padding in between.
+    fn encode_zero_copy(
+        &self,
+        type_definition: &IdlTypeDefinition,
+        fields: &[IdlField],
+        value: &Value,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        let name = &type_definition.name;
+        let size = match type_definition.size {
+            Some(size) => size,
+            None => bail!("The size of zero-copy type {} is missing", name),
+        };
+        let values = Self::field_values(name, fields, value)?;
+
+        let mut data = vec![0; size];
+        for (field, (value, padding)) in fields.iter().zip(values) {
+            let mut field_data = Vec::new();
+            self.encode(
+                &field.ty,
+                padding.as_ref().unwrap_or(value),
+                &mut field_data,
+            )
+            .map_err(|err| {
+                format_err!("Field '{}' of {}: {}", field.name, name, err)
+            })?;
+            match field.offset {
+                Some(offset) if offset + field_data.len() <= size => data
+                    [offset..offset + field_data.len()]
+                    .copy_from_slice(&field_data),
+                _ => bail!(
+                    "Field '{}' of zero-copy type {} has no valid offset",
+                    field.name,
+                    name
+                ),
+            }
+        }
+        out.extend_from_slice(&data);
+        Ok(())
+    }
+}
+
+// -----------------
+// Instructions
+// -----------------
+
+/// An account of an encoded instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedAccountMeta {
+    pub pubkey: [u8; 32],
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// An instruction encoded from JSON args and accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedInstruction {
+    pub program_id: [u8; 32],
+    pub accounts: Vec<EncodedAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+impl EncodedInstruction {
+    /// Serializes the instruction via Borsh as program id, account metas and
+    /// data, which is the layout multisig tools like SPL Governance take raw
+    /// instructions in.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.program_id.to_vec();
+        bytes.extend_from_slice(&(self.accounts.len() as u32).to_le_bytes());
+        for account in &self.accounts {
+            bytes.extend_from_slice(&account.pubkey);
+            bytes.push(account.is_signer as u8);
+            bytes.push(account.is_writable as u8);
+        }
+        bytes.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
+
+fn flatten_accounts(items: &[IdlAccountItem]) -> Vec<&IdlAccount> {
+    items
+        .iter()
+        .flat_map(|item| match item {
+            IdlAccountItem::IdlAccount(account) => vec![account],
+            IdlAccountItem::IdlAccounts(accounts) => {
+                flatten_accounts(&accounts.accounts)
+            }
+        })
+        .collect()
+}
+
+/// Resolves the account meta of the instruction account.
+///
+/// Accounts are provided as public key or, for optional signers, as
+/// `{ "pubkey": .., "isSigner": .. }`. Accounts with an `address` default to
+/// it while optional accounts that aren't provided are omitted with the
+/// legacy strategy and replaced with the program id otherwise.
+fn account_meta(
+    account: &IdlAccount,
+    value: Option<&Value>,
+    owner: &str,
+    program_id: [u8; 32],
+    legacy_optional_accounts_strategy: bool,
+) -> Result<Option<EncodedAccountMeta>> {
+    let name = format!("account '{}' of {}", account.name, owner);
+    let (pubkey_value, is_signer) = match value {
+        Some(Value::Object(entry)) => {
+            check_unknown_keys(
+                entry,
+                ["pubkey", "isSigner"].iter().copied(),
+                "key",
+                &name,
+            )?;
+            let pubkey_value = entry
+                .get("pubkey")
+                .ok_or_else(|| format_err!("Missing pubkey of {}", name))?;
+            let is_signer = match entry.get("isSigner") {
+                None => None,
+                Some(Value::Bool(_)) if !account.is_optional_signer => bail!(
+                    "The {} is not an optional signer, isSigner cannot be provided",
+                    name
+                ),
+                Some(Value::Bool(is_signer)) => Some(*is_signer),
+                Some(value) => bail!(
+                    "Expected isSigner of {} to be a bool, found {}",
+                    name,
+                    value
+                ),
+            };
+            (pubkey_value, is_signer)
+        }
+        Some(value) => (value, None),
+        None => {
+            if let Some(address) = &account.address {
+                let address = Value::String(address.clone());
+                return Ok(Some(EncodedAccountMeta {
+                    pubkey: pubkey(&address).map_err(|err| {
+                        format_err!("Address of {}: {}", name, err)
+                    })?,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_mut,
+                }));
+            }
+            if account.is_optional {
+                return Ok(if legacy_optional_accounts_strategy {
+                    None
+                } else {
+                    Some(EncodedAccountMeta {
+                        pubkey: program_id,
+                        is_signer: false,
+                        is_writable: false,
+                    })
+                });
+            }
+            match &account.pda {
+                Some(pda) => bail!(
+                    "Missing {} which is a {} PDA that needs to be provided",
+                    name,
+                    pda.account
+                ),
+                None => bail!("Missing {}", name),
+            }
+        }
+    };
+
+    Ok(Some(EncodedAccountMeta {
+        pubkey: pubkey(pubkey_value)
+            .map_err(|err| format_err!("Pubkey of {}: {}", name, err))?,
+        is_signer: is_signer.unwrap_or(account.is_signer),
+        is_writable: account.is_mut,
+    }))
+}
+
+/// Encodes the instruction with the provided name from its args and accounts
+/// provided as JSON objects keyed by their names in the IDL.
+///
+/// The program id defaults to the address of the IDL.
+pub fn encode_instruction(
+    idl: &Idl,
+    name: &str,
+    args: &Value,
+    accounts: &Value,
+    program_id: Option<&str>,
+) -> Result<EncodedInstruction> {
+    let ix = idl
+        .instructions
+        .iter()
+        .find(|ix| ix.name == name)
+        .ok_or_else(|| {
+            format_err!("Instruction {} is not defined in the IDL", name)
+        })?;
+    let program_id = match program_id.or(idl.metadata.address.as_deref()) {
+        Some(program_id) => pubkey(&Value::String(program_id.to_string()))
+            .map_err(|err| format_err!("Invalid program id: {}", err))?,
+        None => bail!("The IDL has no address, provide the program id"),
+    };
+    let owner = format!("instruction {}", ix.name);
+
+    let empty = Map::new();
+    let args = match args {
+        Value::Null => &empty,
+        args => object(args, "an object of the instruction args")?,
+    };
+    check_unknown_keys(
+        args,
+        ix.args.iter().map(|arg| arg.name.as_str()),
+        "arg",
+        &owner,
+    )?;
+    let mut data = vec![ix.discriminant.value];
+    let encoder = IdlEncoder::new(idl);
+    for arg in &ix.args {
+        let value = args.get(&arg.name).ok_or_else(|| {
+            format_err!("Missing arg '{}' of {}", arg.name, owner)
+        })?;
+        encoder.encode(&arg.ty, value, &mut data).map_err(|err| {
+            format_err!("Arg '{}' of {}: {}", arg.name, owner, err)
+        })?;
+    }
+
+    let ix_accounts = flatten_accounts(&ix.accounts);
+    let accounts = match accounts {
+        Value::Null => &empty,
+        accounts => object(accounts, "an object of the instruction accounts")?,
+    };
+    check_unknown_keys(
+        accounts,
+        ix_accounts.iter().map(|account| account.name.as_str()),
+        "account",
+        &owner,
+    )?;
+    let legacy = ix.legacy_optional_accounts_strategy.unwrap_or(false);
+    let mut metas = Vec::with_capacity(ix_accounts.len());
+    for account in ix_accounts {
+        metas.extend(account_meta(
+            account,
+            accounts.get(&account.name),
+            &owner,
+            program_id,
+            legacy,
+        )?);
+    }
+
+    Ok(EncodedInstruction {
+        program_id,
+        accounts: metas,
+        data,
+    })
+}
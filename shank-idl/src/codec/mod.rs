@@ -0,0 +1,30 @@
+//! Borsh encoding and decoding of instruction and account data driven by the
+//! types of an IDL, i.e. to inspect data without the program crate.
+
+use std::collections::HashMap;
+
+use crate::{
+    idl::Idl, idl_field::IdlField, idl_type_definition::IdlTypeDefinition,
+};
+
+mod decode;
+mod encode;
+
+pub use decode::*;
+pub use encode::*;
+
+/// The accounts and types of the IDL by name.
+fn type_definitions(idl: &Idl) -> HashMap<&str, &IdlTypeDefinition> {
+    idl.accounts
+        .iter()
+        .chain(idl.types.iter())
+        .map(|type_definition| (type_definition.name.as_str(), type_definition))
+        .collect()
+}
+
+fn is_padding(field: &IdlField) -> bool {
+    field
+        .attrs
+        .as_ref()
+        .is_some_and(|attrs| attrs.iter().any(|attr| attr == "padding"))
+}
@@ -0,0 +1,179 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Error;
+use proc_macro2::Span;
+use shank_macro_impl::syn::Error as ParseError;
+
+// -----------------
+// SourceLocation
+// -----------------
+
+/// Location of a span inside one of the source files of the program crate.
+///
+/// Lines are 1-based and columns are 0-based char offsets, matching
+/// [proc_macro2::LineColumn].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl SourceLocation {
+    /// Resolves the location of the provided span inside the given file.
+    /// Returns `None` for spans that don't point at any source, i.e. `Span::call_site()`.
+    pub fn from_span(file: &Path, span: Span) -> Option<Self> {
+        let (start, end) = (span.start(), span.end());
+        if start == end {
+            return None;
+        }
+        Some(Self {
+            file: file.to_path_buf(),
+            line: start.line,
+            column: start.column,
+            end_line: end.line,
+            end_column: end.column,
+        })
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.file.display(),
+            self.line,
+            self.column + 1
+        )
+    }
+}
+
+// -----------------
+// Diagnostic
+// -----------------
+
+/// A problem encountered while extracting the IDL including where in the
+/// program source it was found if that is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub location: Option<SourceLocation>,
+}
+
+impl Diagnostic {
+    pub fn new(
+        message: impl Into<String>,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            location,
+        }
+    }
+
+    pub fn spanned(
+        message: impl Into<String>,
+        file: &Path,
+        span: Span,
+    ) -> Self {
+        Self::new(message, SourceLocation::from_span(file, span))
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+// -----------------
+// Diagnostics
+// -----------------
+
+/// Error returned when IDL extraction fails which holds a [Diagnostic] for each
+/// problem that was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl Diagnostics {
+    /// Converts each error contained in the [ParseError] into a [Diagnostic].
+    /// The `fallback` span is used for errors which don't point at any source.
+    pub fn from_parse_error(
+        err: &ParseError,
+        file: &Path,
+        fallback: Span,
+    ) -> Self {
+        let diagnostics = err
+            .clone()
+            .into_iter()
+            .map(|err| {
+                let location = SourceLocation::from_span(file, err.span())
+                    .or_else(|| SourceLocation::from_span(file, fallback));
+                Diagnostic::new(err.to_string(), location)
+            })
+            .collect();
+        Self(diagnostics)
+    }
+
+    /// Locates the error via the [ParseError] it wraps if any, otherwise at the
+    /// `fallback` span.
+    pub fn from_error(err: &Error, file: &Path, fallback: Span) -> Self {
+        match err.downcast_ref::<ParseError>() {
+            Some(parse_err) => {
+                Self::from_parse_error(parse_err, file, fallback)
+            }
+            None => Self(vec![Diagnostic::spanned(
+                format!("{:#}", err),
+                file,
+                fallback,
+            )]),
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Diagnostic> for Diagnostics {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Self(vec![diagnostic])
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let diagnostics = self
+            .0
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+        write!(f, "{}", diagnostics.join("\n"))
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+/// Finds the [Diagnostics] attached to an error returned from IDL extraction.
+pub fn find_diagnostics(err: &Error) -> Option<&Diagnostics> {
+    err.chain().find_map(|x| x.downcast_ref::<Diagnostics>())
+}
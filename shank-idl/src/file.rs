@@ -1,26 +1,28 @@
-use anyhow::Result;
+use anyhow::{Error, Result};
 
-use std::{
-    convert::{TryFrom, TryInto},
-    path::Path,
-};
+use std::{collections::HashMap, convert::TryFrom, path::Path};
 
 use crate::{
+    diagnostics::{find_diagnostics, Diagnostic, Diagnostics, SourceLocation},
     idl::{Idl, IdlConst, IdlEvent, IdlState},
     idl_error_code::IdlErrorCode,
-    idl_instruction::{IdlInstruction, IdlInstructions},
+    idl_instruction::IdlInstruction,
     idl_metadata::IdlMetadata,
     idl_type::IdlType,
     idl_type_definition::IdlTypeDefinition,
+    zero_copy::populate_zero_copy_layouts,
 };
+use proc_macro2::Span;
 use shank_macro_impl::{
     account::extract_account_structs,
-    converters::parse_error_into,
+    converters::PARSE_ERROR_HINT,
     custom_type::{CustomEnum, CustomStruct, DetectCustomTypeConfig},
     error::extract_this_errors,
     instruction::extract_instruction_enums,
     krate::CrateContext,
     macros::ProgramId,
+    parsed_macro::ParsedMacro,
+    syn::Error as ParseError,
 };
 
 // -----------------
@@ -33,6 +35,9 @@ pub struct ParseIdlConfig {
     pub detect_custom_struct: DetectCustomTypeConfig,
     pub require_program_address: bool,
     pub program_address_override: Option<String>,
+    /// When `true` extraction continues past items that fail to convert and
+    /// all errors are reported together.
+    pub collect_errors: bool,
 }
 
 impl Default for ParseIdlConfig {
@@ -43,6 +48,7 @@ impl Default for ParseIdlConfig {
             detect_custom_struct: Default::default(),
             require_program_address: true,
             program_address_override: None,
+            collect_errors: false,
         }
     }
 }
@@ -65,20 +71,66 @@ pub fn parse_file(
     filename: impl AsRef<Path>,
     config: &ParseIdlConfig,
 ) -> Result<Option<Idl>> {
+    if config.collect_errors {
+        let PartialIdl { idl, diagnostics } =
+            parse_file_partial(filename, config)?;
+        return if diagnostics.is_empty() {
+            Ok(idl)
+        } else {
+            Err(Error::new(diagnostics))
+        };
+    }
+
+    let ctx = CrateContext::parse(filename)?;
+    parse_crate(&ctx, config, &mut ErrorCollector::fail_fast())
+}
+
+/// IDL extracted while collecting errors, see [parse_file_partial].
+#[derive(Debug)]
+pub struct PartialIdl {
+    /// IDL containing all items that could be extracted.
+    pub idl: Option<Idl>,
+    /// Problems with the items that were left out of the IDL.
+    pub diagnostics: Diagnostics,
+}
+
+/// Parse an entire interface file, skipping items that fail to convert.
+/// Only fails if the crate itself cannot be parsed.
+pub fn parse_file_partial(
+    filename: impl AsRef<Path>,
+    config: &ParseIdlConfig,
+) -> Result<PartialIdl> {
     let ctx = CrateContext::parse(filename)?;
+    let mut collector = ErrorCollector::collecting();
+    let idl = parse_crate(&ctx, config, &mut collector)?;
+    Ok(PartialIdl {
+        idl,
+        diagnostics: Diagnostics(collector.diagnostics),
+    })
+}
 
-    let constants = constants(&ctx)?;
-    let instructions = instructions(&ctx)?;
-    let state = state(&ctx)?;
-    let accounts = accounts(&ctx)?;
-    let types = types(&ctx, &config.detect_custom_struct)?;
-    let events = events(&ctx)?;
-    let errors = errors(&ctx)?;
-    let metadata = metadata(
-        &ctx,
-        config.require_program_address,
-        config.program_address_override.as_ref(),
-    )?;
+fn parse_crate(
+    ctx: &CrateContext,
+    config: &ParseIdlConfig,
+    collector: &mut ErrorCollector,
+) -> Result<Option<Idl>> {
+    let constants = constants(ctx)?;
+    let instructions = instructions(ctx, collector)?;
+    let state = state(ctx)?;
+    let accounts = accounts(ctx, collector)?;
+    let types = types(ctx, &config.detect_custom_struct, collector)?;
+    let events = events(ctx)?;
+    let errors = errors(ctx, collector)?;
+    let metadata = collector
+        .check(metadata(
+            ctx,
+            config.require_program_address,
+            config.program_address_override.as_ref(),
+        ))?
+        .unwrap_or_else(|| IdlMetadata {
+            origin: "shank".to_string(),
+            address: None,
+        });
 
     let mut idl = Idl {
         version: config.program_version.to_string(),
@@ -97,35 +149,158 @@ pub fn parse_file(
     populate_pod_option_sentinels(&mut idl)?;
 
     // Validate that custom types used in PodOption have pod_sentinel defined
-    validate_pod_option_sentinels(&idl)?;
+    let type_locations = type_locations(ctx);
+    collector.check(validate_pod_option_sentinels(&idl, &type_locations))?;
+
+    // Compute field offsets of zero-copy types
+    collector.check(populate_zero_copy_layouts(&mut idl, &type_locations))?;
 
     Ok(Some(idl))
 }
 
-fn accounts(ctx: &CrateContext) -> Result<Vec<IdlTypeDefinition>> {
-    let account_structs = extract_account_structs(ctx.structs())?;
+// -----------------
+// Error Collection
+// -----------------
+
+/// Decides if extraction fails on the first error or keeps going past items
+/// that fail to convert, recording a [Diagnostic] for each problem.
+struct ErrorCollector {
+    collect: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ErrorCollector {
+    fn fail_fast() -> Self {
+        Self {
+            collect: false,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn collecting() -> Self {
+        Self {
+            collect: true,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns `None` when the error was collected so the item can be skipped.
+    fn check<T>(&mut self, res: Result<T>) -> Result<Option<T>> {
+        match res {
+            Ok(val) => Ok(Some(val)),
+            Err(err) if self.collect => {
+                match find_diagnostics(&err) {
+                    Some(diagnostics) => {
+                        self.diagnostics.extend(diagnostics.iter().cloned())
+                    }
+                    None => self
+                        .diagnostics
+                        .push(Diagnostic::new(format!("{:#}", err), None)),
+                }
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// -----------------
+// Source Locations
+// -----------------
+
+/// Wraps the error into [Diagnostics] pointing at the location of the [ParseError] it contains
+/// or the `fallback` span, i.e. the ident of the item that failed to convert.
+fn located_error(err: Error, file: &Path, fallback: Span) -> Error {
+    Error::new(Diagnostics::from_error(&err, file, fallback))
+}
+
+fn located_parse_error(err: ParseError, file: &Path, fallback: Span) -> Error {
+    Error::new(Diagnostics::from_parse_error(&err, file, fallback))
+        .context(PARSE_ERROR_HINT)
+}
+
+/// Locations of all structs and enums defined in the crate keyed by their name.
+fn type_locations(ctx: &CrateContext) -> HashMap<String, SourceLocation> {
+    let mut locations = HashMap::new();
+    for module in ctx.modules() {
+        let idents = module
+            .structs()
+            .map(|x| &x.ident)
+            .chain(module.enums().map(|x| &x.ident));
+        for ident in idents {
+            if let Some(location) =
+                SourceLocation::from_span(module.file(), ident.span())
+            {
+                locations.entry(ident.to_string()).or_insert(location);
+            }
+        }
+    }
+    locations
+}
 
+// -----------------
+// Extractors
+// -----------------
+fn accounts(
+    ctx: &CrateContext,
+    collector: &mut ErrorCollector,
+) -> Result<Vec<IdlTypeDefinition>> {
     let mut accounts: Vec<IdlTypeDefinition> = Vec::new();
-    for strct in account_structs {
-        let idl_def: IdlTypeDefinition = strct.try_into()?;
-        accounts.push(idl_def);
+    for module in ctx.modules() {
+        for item in module.structs() {
+            let account_structs = collector
+                .check(extract_account_structs(std::iter::once(item)).map_err(
+                    |err| located_error(err, module.file(), item.ident.span()),
+                ))?
+                .unwrap_or_default();
+            for strct in account_structs {
+                let idl_def = collector.check(
+                    IdlTypeDefinition::try_from(strct).map_err(|err| {
+                        located_error(err, module.file(), item.ident.span())
+                    }),
+                )?;
+                accounts.extend(idl_def);
+            }
+        }
     }
     Ok(accounts)
 }
 
-fn instructions(ctx: &CrateContext) -> Result<Vec<IdlInstruction>> {
-    let instruction_enums =
-        extract_instruction_enums(ctx.enums()).map_err(parse_error_into)?;
-
+fn instructions(
+    ctx: &CrateContext,
+    collector: &mut ErrorCollector,
+) -> Result<Vec<IdlInstruction>> {
     let mut instructions: Vec<IdlInstruction> = Vec::new();
     // TODO(thlorenz): Should we enforce only one Instruction Enum Arg?
     // TODO(thlorenz): Should unfold that only arg?
     // TODO(thlorenz): Better way to combine those if we don't do the above.
 
-    for ix in instruction_enums {
-        let idl_instructions: IdlInstructions = ix.try_into()?;
-        for ix in idl_instructions.0 {
-            instructions.push(ix);
+    for module in ctx.modules() {
+        for item in module.enums() {
+            let instruction_enums = collector
+                .check(
+                    extract_instruction_enums(std::iter::once(item)).map_err(
+                        |err| {
+                            located_parse_error(
+                                err,
+                                module.file(),
+                                item.ident.span(),
+                            )
+                        },
+                    ),
+                )?
+                .unwrap_or_default();
+            for ix in instruction_enums {
+                for variant in ix.variants {
+                    let span = variant.ident.span();
+                    let ix = collector.check(
+                        IdlInstruction::try_from(variant).map_err(|err| {
+                            located_error(err, module.file(), span)
+                        }),
+                    )?;
+                    instructions.extend(ix);
+                }
+            }
         }
     }
     Ok(instructions)
@@ -145,24 +320,57 @@ fn state(_ctx: &CrateContext) -> Result<Option<IdlState>> {
 fn types(
     ctx: &CrateContext,
     detect_custom_type: &DetectCustomTypeConfig,
+    collector: &mut ErrorCollector,
 ) -> Result<Vec<IdlTypeDefinition>> {
-    let custom_structs = ctx
-        .structs()
-        .filter(|x| detect_custom_type.are_custom_type_attrs(&x.attrs))
-        .map(|x| CustomStruct::try_from(x).map_err(parse_error_into))
-        .collect::<Result<Vec<CustomStruct>>>()?;
-
-    let custom_enums = ctx
-        .enums()
-        .filter(|x| detect_custom_type.are_custom_type_attrs(&x.attrs))
-        .map(|x| CustomEnum::try_from(x).map_err(parse_error_into))
-        .collect::<Result<Vec<CustomEnum>>>()?;
-
-    let types = custom_structs
-        .into_iter()
-        .map(IdlTypeDefinition::try_from)
-        .chain(custom_enums.into_iter().map(IdlTypeDefinition::try_from))
-        .collect::<Result<Vec<IdlTypeDefinition>>>()?;
+    let mut custom_structs = Vec::new();
+    let mut custom_enums = Vec::new();
+    for module in ctx.modules() {
+        let file = module.file();
+        for x in module
+            .structs()
+            .filter(|x| detect_custom_type.are_custom_type_attrs(&x.attrs))
+        {
+            let span = x.ident.span();
+            let strct = collector.check(
+                CustomStruct::try_from(x)
+                    .map_err(|err| located_parse_error(err, file, span)),
+            )?;
+            if let Some(strct) = strct {
+                custom_structs.push((strct, file, span));
+            }
+        }
+        for x in module
+            .enums()
+            .filter(|x| detect_custom_type.are_custom_type_attrs(&x.attrs))
+        {
+            let span = x.ident.span();
+            let enm = collector.check(
+                CustomEnum::try_from(x)
+                    .map_err(|err| located_parse_error(err, file, span)),
+            )?;
+            if let Some(enm) = enm {
+                custom_enums.push((enm, file, span));
+            }
+        }
+    }
+
+    let mut types = Vec::new();
+    for (strct, file, span) in custom_structs {
+        types.extend(
+            collector.check(
+                IdlTypeDefinition::try_from(strct)
+                    .map_err(|err| located_error(err, file, span)),
+            )?,
+        );
+    }
+    for (enm, file, span) in custom_enums {
+        types.extend(
+            collector.check(
+                IdlTypeDefinition::try_from(enm)
+                    .map_err(|err| located_error(err, file, span)),
+            )?,
+        );
+    }
 
     Ok(types)
 }
@@ -178,7 +386,26 @@ fn metadata(
     } else {
         match ProgramId::try_from(&macros[..]) {
             Ok(ProgramId { id }) => Ok(Some(id)),
-            Err(err) if require_program_address => Err(err),
+            Err(err) if require_program_address => {
+                // When multiple program ids are declared the error points at the first one
+                let declaring_file = ctx.modules().find_map(|module| {
+                    module
+                        .macros()
+                        .map(ParsedMacro::from)
+                        .any(|x| x.path.ends_with("declare_id"))
+                        .then(|| module.file())
+                });
+                match declaring_file {
+                    Some(file) => {
+                        Err(Error::new(Diagnostics::from_parse_error(
+                            &err,
+                            file,
+                            Span::call_site(),
+                        )))
+                    }
+                    None => Err(err.into()),
+                }
+            }
             Err(_) => Ok(None),
         }
     }?;
@@ -193,8 +420,21 @@ fn events(_ctx: &CrateContext) -> Result<Option<Vec<IdlEvent>>> {
     Ok(None)
 }
 
-fn errors(ctx: &CrateContext) -> Result<Option<Vec<IdlErrorCode>>> {
-    let program_errors = extract_this_errors(ctx.enums())?;
+fn errors(
+    ctx: &CrateContext,
+    collector: &mut ErrorCollector,
+) -> Result<Option<Vec<IdlErrorCode>>> {
+    let mut program_errors = Vec::new();
+    for module in ctx.modules() {
+        for item in module.enums() {
+            let errors = collector.check(
+                extract_this_errors(std::iter::once(item)).map_err(|err| {
+                    located_error(err, module.file(), item.ident.span())
+                }),
+            )?;
+            program_errors.extend(errors.into_iter().flatten());
+        }
+    }
     if program_errors.is_empty() {
         Ok(None)
     } else {
@@ -249,21 +489,29 @@ where
     // Walk all account fields
     for account in &mut idl.accounts {
         match &mut account.ty {
-            crate::idl_type_definition::IdlTypeDefinitionTy::Struct { fields } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Struct {
+                fields,
+            } => {
                 for field in fields {
                     walk_type(&mut field.ty, &mut f);
                 }
             }
-            crate::idl_type_definition::IdlTypeDefinitionTy::Enum { variants } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Enum {
+                variants,
+            } => {
                 for variant in variants {
                     if let Some(fields) = &mut variant.fields {
                         match fields {
-                            crate::idl_variant::EnumFields::Named(named_fields) => {
+                            crate::idl_variant::EnumFields::Named(
+                                named_fields,
+                            ) => {
                                 for field in named_fields {
                                     walk_type(&mut field.ty, &mut f);
                                 }
                             }
-                            crate::idl_variant::EnumFields::Tuple(tuple_types) => {
+                            crate::idl_variant::EnumFields::Tuple(
+                                tuple_types,
+                            ) => {
                                 for ty in tuple_types {
                                     walk_type(ty, &mut f);
                                 }
@@ -278,21 +526,29 @@ where
     // Walk all custom type fields
     for type_def in &mut idl.types {
         match &mut type_def.ty {
-            crate::idl_type_definition::IdlTypeDefinitionTy::Struct { fields } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Struct {
+                fields,
+            } => {
                 for field in fields {
                     walk_type(&mut field.ty, &mut f);
                 }
             }
-            crate::idl_type_definition::IdlTypeDefinitionTy::Enum { variants } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Enum {
+                variants,
+            } => {
                 for variant in variants {
                     if let Some(fields) = &mut variant.fields {
                         match fields {
-                            crate::idl_variant::EnumFields::Named(named_fields) => {
+                            crate::idl_variant::EnumFields::Named(
+                                named_fields,
+                            ) => {
                                 for field in named_fields {
                                     walk_type(&mut field.ty, &mut f);
                                 }
                             }
-                            crate::idl_variant::EnumFields::Tuple(tuple_types) => {
+                            crate::idl_variant::EnumFields::Tuple(
+                                tuple_types,
+                            ) => {
                                 for ty in tuple_types {
                                     walk_type(ty, &mut f);
                                 }
@@ -354,21 +610,29 @@ where
     // Walk all account fields
     for account in &idl.accounts {
         match &account.ty {
-            crate::idl_type_definition::IdlTypeDefinitionTy::Struct { fields } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Struct {
+                fields,
+            } => {
                 for field in fields {
                     walk_type(&field.ty, &mut f);
                 }
             }
-            crate::idl_type_definition::IdlTypeDefinitionTy::Enum { variants } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Enum {
+                variants,
+            } => {
                 for variant in variants {
                     if let Some(fields) = &variant.fields {
                         match fields {
-                            crate::idl_variant::EnumFields::Named(named_fields) => {
+                            crate::idl_variant::EnumFields::Named(
+                                named_fields,
+                            ) => {
                                 for field in named_fields {
                                     walk_type(&field.ty, &mut f);
                                 }
                             }
-                            crate::idl_variant::EnumFields::Tuple(tuple_types) => {
+                            crate::idl_variant::EnumFields::Tuple(
+                                tuple_types,
+                            ) => {
                                 for ty in tuple_types {
                                     walk_type(ty, &mut f);
                                 }
@@ -383,21 +647,29 @@ where
     // Walk all custom type fields
     for type_def in &idl.types {
         match &type_def.ty {
-            crate::idl_type_definition::IdlTypeDefinitionTy::Struct { fields } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Struct {
+                fields,
+            } => {
                 for field in fields {
                     walk_type(&field.ty, &mut f);
                 }
             }
-            crate::idl_type_definition::IdlTypeDefinitionTy::Enum { variants } => {
+            crate::idl_type_definition::IdlTypeDefinitionTy::Enum {
+                variants,
+            } => {
                 for variant in variants {
                     if let Some(fields) = &variant.fields {
                         match fields {
-                            crate::idl_variant::EnumFields::Named(named_fields) => {
+                            crate::idl_variant::EnumFields::Named(
+                                named_fields,
+                            ) => {
                                 for field in named_fields {
                                     walk_type(&field.ty, &mut f);
                                 }
                             }
-                            crate::idl_variant::EnumFields::Tuple(tuple_types) => {
+                            crate::idl_variant::EnumFields::Tuple(
+                                tuple_types,
+                            ) => {
                                 for ty in tuple_types {
                                     walk_type(ty, &mut f);
                                 }
@@ -425,9 +697,10 @@ fn populate_pod_option_sentinels(idl: &mut Idl) -> Result<()> {
         .types
         .iter()
         .filter_map(|type_def| {
-            type_def.pod_sentinel.as_ref().map(|sentinel| {
-                (type_def.name.clone(), sentinel.clone())
-            })
+            type_def
+                .pod_sentinel
+                .as_ref()
+                .map(|sentinel| (type_def.name.clone(), sentinel.clone()))
         })
         .collect();
 
@@ -448,8 +721,12 @@ fn populate_pod_option_sentinels(idl: &mut Idl) -> Result<()> {
     Ok(())
 }
 
-fn validate_pod_option_sentinels(idl: &Idl) -> Result<()> {
+fn validate_pod_option_sentinels(
+    idl: &Idl,
+    type_locations: &HashMap<String, SourceLocation>,
+) -> Result<()> {
     let mut errors = Vec::new();
+    let mut diagnostics = Vec::new();
 
     // Walk all IdlType instances and check for missing sentinels
     walk_idl_types_ref(idl, |ty| {
@@ -458,18 +735,27 @@ fn validate_pod_option_sentinels(idl: &Idl) -> Result<()> {
                 // This is PodOption<CustomType>
                 // After population, sentinel should be present
                 if sentinel.is_none() {
-                    errors.push(format!(
+                    let error = format!(
                         "Type '{}' is used in PodOption but does not define #[pod_sentinel(...)]. \
                          Custom types used with PodOption must specify a sentinel value.",
                         type_name
+                    );
+                    // Point at the type definition since that is where the sentinel is missing
+                    diagnostics.push(Diagnostic::new(
+                        error.clone(),
+                        type_locations.get(type_name).cloned(),
                     ));
+                    errors.push(error);
                 }
             }
         }
     });
 
     if !errors.is_empty() {
-        anyhow::bail!("PodOption validation errors:\n  - {}", errors.join("\n  - "));
+        return Err(Error::new(Diagnostics(diagnostics)).context(format!(
+            "PodOption validation errors:\n  - {}",
+            errors.join("\n  - ")
+        )));
     }
 
     Ok(())
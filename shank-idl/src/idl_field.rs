@@ -14,6 +14,9 @@ pub struct IdlField {
     pub ty: IdlType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attrs: Option<Vec<String>>,
+    /// The byte offset of the field inside a zero-copy type.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub offset: Option<usize>,
 }
 
 impl TryFrom<StructField> for IdlField {
@@ -39,6 +42,11 @@ impl TryFrom<StructField> for IdlField {
             .collect::<Vec<String>>();
 
         let attrs = if attrs.is_empty() { None } else { Some(attrs) };
-        Ok(Self { name, ty, attrs })
+        Ok(Self {
+            name,
+            ty,
+            attrs,
+            offset: None,
+        })
     }
 }
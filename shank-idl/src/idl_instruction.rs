@@ -3,9 +3,12 @@ use std::convert::TryFrom;
 use anyhow::{anyhow, ensure, Error, Result};
 use heck::MixedCase;
 use serde::{Deserialize, Serialize};
-use shank_macro_impl::instruction::{
-    Instruction, InstructionAccount, InstructionStrategy, InstructionVariant,
-    InstructionVariantFields,
+use shank_macro_impl::{
+    instruction::{
+        AccountDefault, Instruction, InstructionAccount, InstructionStrategy,
+        InstructionVariant, InstructionVariantFields,
+    },
+    well_known::well_known_address,
 };
 
 use crate::{idl_field::IdlField, idl_type::IdlType};
@@ -69,6 +72,7 @@ impl TryFrom<InstructionVariant> for IdlInstruction {
                         name: field_name.to_mixed_case(),
                         ty,
                         attrs: None,
+                        offset: None,
                     })
                 }
                 Ok(parsed)
@@ -90,6 +94,7 @@ impl TryFrom<InstructionVariant> for IdlInstruction {
                         name,
                         ty,
                         attrs: None,
+                        offset: None,
                     })
                 }
                 Ok(parsed)
@@ -184,6 +189,23 @@ pub struct IdlAccount {
     pub is_optional: bool,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub docs: Option<Vec<String>>,
+    /// The fixed address of the account, see `#[account(address = "..")]`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub address: Option<String>,
+    /// The PDA the account is derived as, see `#[account(pda = .., seeds(..))]`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pda: Option<IdlAccountPda>,
+}
+
+/// Links an instruction account to the `#[seeds]` of an account type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlAccountPda {
+    /// The account type whose seeds derive the PDA.
+    pub account: String,
+    /// The names of the accounts and args of the instruction providing the
+    /// seeds, in order.
+    pub seeds: Vec<String>,
 }
 
 impl From<InstructionAccount> for IdlAccount {
@@ -195,8 +217,33 @@ impl From<InstructionAccount> for IdlAccount {
             desc,
             optional,
             optional_signer,
+            default,
             ..
         } = acc;
+        // Paths are only known to Rust code and thus only part of the IDL if
+        // they refer to a well known program or sysvar
+        let (address, pda) = match default {
+            Some(AccountDefault::Address(address)) => (Some(address), None),
+            Some(AccountDefault::Path(path)) => {
+                (well_known_address(&path).map(String::from), None)
+            }
+            Some(AccountDefault::Pda { account, seeds }) => {
+                // The account type is referred to by its name only
+                let pda = IdlAccountPda {
+                    account: account
+                        .rsplit("::")
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    seeds: seeds
+                        .iter()
+                        .map(|seed| seed.to_mixed_case())
+                        .collect(),
+                };
+                (None, Some(pda))
+            }
+            None => (None, None),
+        };
         Self {
             name: name.to_mixed_case(),
             is_mut: writable,
@@ -204,6 +251,8 @@ impl From<InstructionAccount> for IdlAccount {
             docs: desc.map(|desc| vec![desc]),
             is_optional: optional,
             is_optional_signer: optional_signer,
+            address,
+            pda,
         }
     }
 }
@@ -0,0 +1,90 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::{Error, Result};
+use heck::MixedCase;
+use serde::{Deserialize, Serialize};
+use shank_macro_impl::{
+    parsed_struct::{
+        type_name, Seed, SeedsProgram, ACCOUNT_INFO_TY, PUBKEY_TY,
+    },
+    types::RustType,
+    well_known::well_known_address,
+};
+
+use crate::idl_type::IdlType;
+
+/// A seed of an account PDA as declared via `#[seeds(..)]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum IdlSeed {
+    /// A string literal which is included as bytes.
+    Constant { value: String },
+    /// The id of the program deriving the PDA.
+    ProgramId,
+    /// A value which is provided when deriving the PDA.
+    Variable {
+        name: String,
+        description: String,
+        #[serde(rename = "type")]
+        ty: IdlType,
+    },
+}
+
+impl TryFrom<&Seed> for IdlSeed {
+    type Error = Error;
+
+    fn try_from(seed: &Seed) -> Result<Self> {
+        let idl_seed = match seed {
+            Seed::Literal(value) => IdlSeed::Constant {
+                value: value.to_string(),
+            },
+            Seed::ProgramId => IdlSeed::ProgramId,
+            Seed::Param(name, desc, ty) => {
+                let ty = match ty.as_deref() {
+                    // Account infos contribute their key to the seeds
+                    None | Some(PUBKEY_TY) | Some(ACCOUNT_INFO_TY) => {
+                        IdlType::PublicKey
+                    }
+                    Some(ty) => {
+                        RustType::try_from(type_name(ty))?.try_into()?
+                    }
+                };
+                IdlSeed::Variable {
+                    name: name.to_mixed_case(),
+                    description: desc.to_string(),
+                    ty,
+                }
+            }
+        };
+        Ok(idl_seed)
+    }
+}
+
+/// The program the PDA of an account is derived for when it isn't the program
+/// itself as declared via `#[seeds(.., program = ..)]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum IdlSeedsProgram {
+    /// The address of the program.
+    Address { address: String },
+    /// The path of the program id in the program whose address isn't known.
+    Path { path: String },
+}
+
+impl From<&SeedsProgram> for IdlSeedsProgram {
+    fn from(program: &SeedsProgram) -> Self {
+        match program {
+            SeedsProgram::Address(address) => IdlSeedsProgram::Address {
+                address: address.to_string(),
+            },
+            SeedsProgram::Path(path) => match well_known_address(path) {
+                Some(address) => IdlSeedsProgram::Address {
+                    address: address.to_string(),
+                },
+                None => IdlSeedsProgram::Path {
+                    path: path.to_string(),
+                },
+            },
+        }
+    }
+}
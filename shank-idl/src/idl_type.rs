@@ -173,8 +173,8 @@ impl TryFrom<RustType> for IdlType {
                         if sentinel.is_none() && !matches!(inner_idl, IdlType::Defined(_)) {
                             anyhow::bail!(
                                 "PodOption<T> is only supported for integer/Pubkey primitives or \
-                                 custom types with #[pod_sentinel]. Type '{}' is not supported.",
-                                format!("{:?}", inner_idl)
+                                 custom types with #[pod_sentinel]. Type '{:?}' is not supported.",
+                                inner_idl
                             );
                         }
 
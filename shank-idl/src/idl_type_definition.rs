@@ -1,6 +1,6 @@
 use std::convert::{TryFrom, TryInto};
 
-use anyhow::{Error, Result};
+use anyhow::{bail, Error, Result};
 use serde::{Deserialize, Serialize};
 use shank_macro_impl::{
     custom_type::{CustomEnum, CustomStruct},
@@ -8,7 +8,11 @@ use shank_macro_impl::{
     parsed_struct::{ParsedStruct, StructAttr, StructAttrs},
 };
 
-use crate::{idl_field::IdlField, idl_variant::IdlEnumVariant};
+use crate::{
+    idl_field::IdlField,
+    idl_seed::{IdlSeed, IdlSeedsProgram},
+    idl_variant::IdlEnumVariant,
+};
 
 fn extract_pod_sentinel(struct_attrs: &StructAttrs) -> Option<Vec<u8>> {
     struct_attrs.items_ref().iter().find_map(|attr| match attr {
@@ -17,6 +21,27 @@ fn extract_pod_sentinel(struct_attrs: &StructAttrs) -> Option<Vec<u8>> {
     })
 }
 
+/// The discriminator of accounts deriving loaders which identifies them when
+/// decoding their data.
+fn extract_discriminator(struct_attrs: &StructAttrs) -> Option<Vec<u8>> {
+    struct_attrs
+        .loaders()
+        .map(|loaders| loaders.discriminator.clone())
+        .filter(|discriminator| !discriminator.is_empty())
+}
+
+fn extract_seeds(struct_attrs: &StructAttrs) -> Result<Option<Vec<IdlSeed>>> {
+    struct_attrs
+        .items_ref()
+        .iter()
+        .find_map(|attr| match attr {
+            StructAttr::Seeds(seeds) => Some(seeds),
+            _ => None,
+        })
+        .map(|seeds| seeds.iter().map(IdlSeed::try_from).collect())
+        .transpose()
+}
+
 // -----------------
 // IdlTypeDefinitionTy
 // -----------------
@@ -55,6 +80,29 @@ impl TryFrom<ParsedEnum> for IdlTypeDefinitionTy {
     }
 }
 
+// -----------------
+// IdlSerialization
+// -----------------
+
+/// How the data of a type is laid out when it isn't Borsh serialized.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IdlSerialization {
+    /// A `#[repr(C)]` struct read via bytemuck whose fields are located at the
+    /// provided offsets.
+    Bytemuck,
+}
+
+fn extract_serialization(
+    struct_attrs: &StructAttrs,
+) -> Option<IdlSerialization> {
+    if struct_attrs.is_zero_copy() {
+        Some(IdlSerialization::Bytemuck)
+    } else {
+        None
+    }
+}
+
 // -----------------
 // IdlTypeDefinition
 // -----------------
@@ -69,6 +117,23 @@ pub struct IdlTypeDefinition {
         rename = "podSentinel"
     )]
     pub pod_sentinel: Option<Vec<u8>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub seeds: Option<Vec<IdlSeed>>,
+    /// The program the PDA is derived for if it isn't the program itself.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        rename = "seedsProgram"
+    )]
+    pub seeds_program: Option<IdlSeedsProgram>,
+    /// The bytes the data of the account starts with.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub serialization: Option<IdlSerialization>,
+    /// The size in bytes of a zero-copy type including trailing padding.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub size: Option<usize>,
 }
 
 impl TryFrom<ParsedStruct> for IdlTypeDefinition {
@@ -77,12 +142,24 @@ impl TryFrom<ParsedStruct> for IdlTypeDefinition {
     fn try_from(strct: ParsedStruct) -> Result<Self> {
         let name = strct.ident.to_string();
         let pod_sentinel = extract_pod_sentinel(&strct.struct_attrs);
+        let seeds = extract_seeds(&strct.struct_attrs)?;
+        let seeds_program = strct
+            .struct_attrs
+            .seeds_program()
+            .map(IdlSeedsProgram::from);
+        let discriminator = extract_discriminator(&strct.struct_attrs);
+        let serialization = extract_serialization(&strct.struct_attrs);
 
         let ty: IdlTypeDefinitionTy = strct.try_into()?;
         Ok(Self {
             ty,
             name,
             pod_sentinel,
+            seeds,
+            seeds_program,
+            discriminator,
+            serialization,
+            size: None,
         })
     }
 }
@@ -93,12 +170,18 @@ impl TryFrom<CustomStruct> for IdlTypeDefinition {
     fn try_from(strct: CustomStruct) -> Result<Self> {
         let name = strct.ident.to_string();
         let pod_sentinel = extract_pod_sentinel(&strct.0.struct_attrs);
+        let serialization = extract_serialization(&strct.0.struct_attrs);
 
         let ty: IdlTypeDefinitionTy = strct.0.try_into()?;
         Ok(Self {
             ty,
             name,
             pod_sentinel,
+            seeds: None,
+            seeds_program: None,
+            discriminator: None,
+            serialization,
+            size: None,
         })
     }
 }
@@ -109,12 +192,23 @@ impl TryFrom<CustomEnum> for IdlTypeDefinition {
     fn try_from(enm: CustomEnum) -> Result<Self> {
         let name = enm.ident.to_string();
         let pod_sentinel = extract_pod_sentinel(&enm.0.struct_attrs);
+        if enm.0.struct_attrs.is_zero_copy() {
+            bail!(
+                "Enum {} cannot be zero_copy, only structs have a fixed layout",
+                name
+            );
+        }
 
         let ty: IdlTypeDefinitionTy = enm.0.try_into()?;
         Ok(Self {
             ty,
             name,
             pod_sentinel,
+            seeds: None,
+            seeds_program: None,
+            discriminator: None,
+            serialization: None,
+            size: None,
         })
     }
 }
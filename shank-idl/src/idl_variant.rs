@@ -20,6 +20,18 @@ pub struct IdlEnumVariant {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub fields: Option<EnumFields>,
+    /// The discriminant assigned to the variant, i.e. `Member = 7`, which is
+    /// only included if it differs from the index of the variant.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub discriminant: Option<usize>,
+}
+
+impl IdlEnumVariant {
+    /// The discriminant of the variant declared at the provided index, which
+    /// is what `as u8` converts it to.
+    pub fn discriminant_at(&self, index: usize) -> usize {
+        self.discriminant.unwrap_or(index)
+    }
 }
 
 impl TryFrom<ParsedEnumVariantField> for IdlType {
@@ -43,6 +55,7 @@ impl TryFrom<ParsedEnumVariant> for IdlEnumVariant {
                     name: name.to_string(),
                     ty,
                     attrs: None,
+                    offset: None,
                 }),
                 None => tuple_fields.push(ty),
             }
@@ -57,9 +70,16 @@ impl TryFrom<ParsedEnumVariant> for IdlEnumVariant {
             None
         };
 
+        let discriminant = if variant.discriminant != variant.slot {
+            Some(variant.discriminant)
+        } else {
+            None
+        };
+
         Ok(Self {
             name: variant.ident.to_string(),
             fields,
+            discriminant,
         })
     }
 }
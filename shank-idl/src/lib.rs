@@ -5,16 +5,23 @@ use shank_macro_impl::custom_type::DetectCustomTypeConfig;
 
 use std::path::PathBuf;
 
+pub mod codec;
+pub mod diagnostics;
 mod file;
 pub mod idl;
-mod idl_error_code;
+pub mod idl_error_code;
 pub mod idl_field;
 pub mod idl_instruction;
 pub mod idl_metadata;
+pub mod idl_seed;
 pub mod idl_type;
 pub mod idl_type_definition;
 pub mod idl_variant;
+pub mod lint;
+pub mod logs;
 pub mod manifest;
+pub mod pda;
+mod zero_copy;
 
 pub use file::*;
 
@@ -41,6 +48,24 @@ impl Default for ParseIdlOpts {
 // extract_idl
 // -----------------
 pub fn extract_idl(file: &str, opts: ParseIdlOpts) -> Result<Option<Idl>> {
+    let (file, config) = resolve_parse_config(file, opts)?;
+    file::parse_file(file, &config)
+}
+
+/// Extracts the IDL of all items that could be converted together with
+/// diagnostics for the ones that failed, see [parse_file_partial].
+pub fn extract_idl_partial(
+    file: &str,
+    opts: ParseIdlOpts,
+) -> Result<PartialIdl> {
+    let (file, config) = resolve_parse_config(file, opts)?;
+    file::parse_file_partial(file, &config)
+}
+
+fn resolve_parse_config(
+    file: &str,
+    opts: ParseIdlOpts,
+) -> Result<(String, ParseIdlConfig)> {
     let file = shellexpand::tilde(file);
     let manifest_from_path =
         std::env::current_dir()?.join(PathBuf::from(&*file).parent().unwrap());
@@ -49,14 +74,13 @@ pub fn extract_idl(file: &str, opts: ParseIdlOpts) -> Result<Option<Idl>> {
     let program_name = cargo
         .lib_name()
         .map_err(|err| anyhow!("Cargo.toml is missing lib name. {}", err))?;
-    file::parse_file(
-        &*file,
-        &ParseIdlConfig {
-            program_name,
-            program_version: cargo.version(),
-            detect_custom_struct: opts.detect_custom_struct,
-            require_program_address: opts.require_program_address,
-            program_address_override: opts.program_address_override,
-        },
-    )
+    let config = ParseIdlConfig {
+        program_name,
+        program_version: cargo.version(),
+        detect_custom_struct: opts.detect_custom_struct,
+        require_program_address: opts.require_program_address,
+        program_address_override: opts.program_address_override,
+        ..ParseIdlConfig::default()
+    };
+    Ok((file.into_owned(), config))
 }
@@ -0,0 +1,390 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    convert::TryFrom,
+    fmt,
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Error, Result};
+use proc_macro2::Span;
+use shank_macro_impl::{
+    instruction::{
+        IdlInstruction, InstructionAccount, InstructionAccounts,
+        InstructionStrategies, InstructionStrategy,
+    },
+    krate::{CrateContext, ModuleContext},
+    parsed_enum::{ParsedEnum, ParsedEnumVariant},
+    parsed_macro::ParsedMacro,
+    parsers::get_derive_attr,
+    syn::Result as ParseResult,
+    DERIVE_INSTRUCTION_ATTR,
+};
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+
+// -----------------
+// LintRule
+// -----------------
+
+/// The problems `shank lint` checks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintRule {
+    /// Explicit account indices of an instruction skip one or more indices.
+    AccountIndexGap,
+    /// Two accounts of an instruction have the same explicit index.
+    DuplicateAccountIndex,
+    /// Two accounts of an instruction have the same name.
+    DuplicateAccountName,
+    /// An account is marked both `signer` and `optional_signer`.
+    SignerAndOptionalSigner,
+    /// An optional account is followed by a required one while the instruction
+    /// uses the `legacy_optional_accounts_strategy`.
+    NonTrailingOptionalAccount,
+    /// A `declare_id!` program id is not a base58 encoded 32 byte key.
+    InvalidProgramId,
+    /// An instruction account has no `desc`.
+    MissingAccountDesc,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 7] = [
+        LintRule::AccountIndexGap,
+        LintRule::DuplicateAccountIndex,
+        LintRule::DuplicateAccountName,
+        LintRule::SignerAndOptionalSigner,
+        LintRule::NonTrailingOptionalAccount,
+        LintRule::InvalidProgramId,
+        LintRule::MissingAccountDesc,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::AccountIndexGap => "account-index-gap",
+            LintRule::DuplicateAccountIndex => "duplicate-account-index",
+            LintRule::DuplicateAccountName => "duplicate-account-name",
+            LintRule::SignerAndOptionalSigner => "signer-and-optional-signer",
+            LintRule::NonTrailingOptionalAccount => {
+                "non-trailing-optional-account"
+            }
+            LintRule::InvalidProgramId => "invalid-program-id",
+            LintRule::MissingAccountDesc => "missing-account-desc",
+        }
+    }
+
+    /// Level used unless configured otherwise.
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            LintRule::AccountIndexGap | LintRule::MissingAccountDesc => {
+                LintLevel::Warn
+            }
+            LintRule::DuplicateAccountIndex
+            | LintRule::DuplicateAccountName
+            | LintRule::SignerAndOptionalSigner
+            | LintRule::NonTrailingOptionalAccount
+            | LintRule::InvalidProgramId => LintLevel::Deny,
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for LintRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        LintRule::ALL
+            .iter()
+            .find(|rule| rule.name() == s)
+            .copied()
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unknown lint '{}', valid lints are: {}",
+                    s,
+                    LintRule::ALL
+                        .iter()
+                        .map(LintRule::name)
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+// -----------------
+// LintLevel
+// -----------------
+
+/// Severity of a lint, `Deny` findings fail the lint run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+// -----------------
+// LintConfig
+// -----------------
+#[derive(Debug, Default)]
+pub struct LintConfig {
+    levels: HashMap<LintRule, LintLevel>,
+}
+
+impl LintConfig {
+    /// Overrides the level of the given rule.
+    pub fn set_level(&mut self, rule: LintRule, level: LintLevel) {
+        self.levels.insert(rule, level);
+    }
+
+    pub fn level(&self, rule: LintRule) -> LintLevel {
+        self.levels
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_level())
+    }
+}
+
+// -----------------
+// LintDiagnostic
+// -----------------
+
+/// A problem found by a lint rule which isn't allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    pub rule: LintRule,
+    pub level: LintLevel,
+    pub diagnostic: Diagnostic,
+}
+
+// -----------------
+// Lint File
+// -----------------
+
+/// Lints the instruction and program id annotations of the crate whose root
+/// is the given file.
+pub fn lint_file(
+    filename: impl AsRef<Path>,
+    config: &LintConfig,
+) -> Result<Vec<LintDiagnostic>> {
+    let ctx = CrateContext::parse(filename)?;
+
+    let mut linter = Linter {
+        config,
+        diagnostics: Vec::new(),
+    };
+    for module in ctx.modules() {
+        linter.lint_module(&module)?;
+    }
+    Ok(linter.diagnostics)
+}
+
+struct Linter<'a> {
+    config: &'a LintConfig,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a> Linter<'a> {
+    fn report(
+        &mut self,
+        rule: LintRule,
+        message: String,
+        file: &Path,
+        span: Span,
+    ) {
+        let level = self.config.level(rule);
+        if level != LintLevel::Allow {
+            self.diagnostics.push(LintDiagnostic {
+                rule,
+                level,
+                diagnostic: Diagnostic::spanned(message, file, span),
+            });
+        }
+    }
+
+    fn lint_module(&mut self, module: &ModuleContext) -> Result<()> {
+        let file = module.file();
+        for item_enum in module.enums() {
+            if get_derive_attr(&item_enum.attrs, DERIVE_INSTRUCTION_ATTR)
+                .is_none()
+            {
+                continue;
+            }
+            let parsed_enum =
+                ParsedEnum::try_from(item_enum).map_err(|err| {
+                    Error::new(Diagnostics::from_parse_error(
+                        &err,
+                        file,
+                        item_enum.ident.span(),
+                    ))
+                })?;
+            for variant in &parsed_enum.variants {
+                let accounts = variant_accounts(variant).map_err(|err| {
+                    Error::new(Diagnostics::from_parse_error(
+                        &err,
+                        file,
+                        variant.ident.span(),
+                    ))
+                })?;
+                self.lint_variant(file, variant, &accounts);
+            }
+        }
+
+        for item_macro in module.macros() {
+            let ParsedMacro { path, literal, .. } =
+                ParsedMacro::from(item_macro);
+            let program_id = match literal {
+                Some(program_id) if path.ends_with("declare_id") => program_id,
+                _ => continue,
+            };
+            if !is_valid_pubkey(&program_id) {
+                let span = item_macro
+                    .mac
+                    .tokens
+                    .clone()
+                    .into_iter()
+                    .next()
+                    .map_or_else(Span::call_site, |x| x.span());
+                self.report(
+                    LintRule::InvalidProgramId,
+                    format!(
+                        "Program id '{}' is not a base58 encoded 32 byte public key",
+                        program_id
+                    ),
+                    file,
+                    span,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn lint_variant(
+        &mut self,
+        file: &Path,
+        variant: &ParsedEnumVariant,
+        accounts: &[InstructionAccount],
+    ) {
+        let variant_name = variant.ident.to_string();
+
+        // Account indices
+        let mut indices = BTreeSet::new();
+        for account in accounts {
+            if let Some(index) = account.index {
+                if !indices.insert(index) {
+                    self.report(
+                        LintRule::DuplicateAccountIndex,
+                        format!(
+                            "Duplicate account index {} in instruction variant '{}'",
+                            index, variant_name
+                        ),
+                        file,
+                        account.ident.span(),
+                    );
+                }
+            }
+        }
+        if let Some(max) = indices.iter().next_back() {
+            let missing = (0..*max)
+                .filter(|x| !indices.contains(x))
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>();
+            if !missing.is_empty() {
+                self.report(
+                    LintRule::AccountIndexGap,
+                    format!(
+                        "Account indices of instruction variant '{}' skip {}",
+                        variant_name,
+                        missing.join(", ")
+                    ),
+                    file,
+                    variant.ident.span(),
+                );
+            }
+        }
+
+        // Account names
+        let mut names = HashSet::new();
+        for account in accounts {
+            if !names.insert(account.name.as_str()) {
+                self.report(
+                    LintRule::DuplicateAccountName,
+                    format!(
+                        "Duplicate account name '{}' in instruction variant '{}'",
+                        account.name, variant_name
+                    ),
+                    file,
+                    account.ident.span(),
+                );
+            }
+        }
+
+        // Account flags
+        for account in accounts {
+            if account.signer && account.optional_signer {
+                self.report(
+                    LintRule::SignerAndOptionalSigner,
+                    format!(
+                        "Account '{}' is marked both signer and optional_signer",
+                        account.name
+                    ),
+                    file,
+                    account.ident.span(),
+                );
+            }
+            if account.desc.is_none() {
+                self.report(
+                    LintRule::MissingAccountDesc,
+                    format!(
+                        "Account '{}' of instruction variant '{}' has no desc",
+                        account.name, variant_name
+                    ),
+                    file,
+                    account.ident.span(),
+                );
+            }
+        }
+
+        // Optional accounts are omitted by the legacy strategy and thus need to come last
+        let strategies = InstructionStrategies::from(&variant.attrs[..]);
+        if strategies
+            .0
+            .contains(&InstructionStrategy::LegacyOptionalAccounts)
+        {
+            for (idx, account) in accounts.iter().enumerate() {
+                let required = accounts[idx + 1..].iter().find(|x| !x.optional);
+                if let (true, Some(required)) = (account.optional, required) {
+                    self.report(
+                        LintRule::NonTrailingOptionalAccount,
+                        format!(
+                            "Optional account '{}' is followed by required account '{}' which the legacy optional accounts strategy does not support",
+                            account.name, required.name
+                        ),
+                        file,
+                        account.ident.span(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Accounts as declared on the variant, including those which would be
+/// rejected when extracting the IDL.
+fn variant_accounts(
+    variant: &ParsedEnumVariant,
+) -> ParseResult<Vec<InstructionAccount>> {
+    match IdlInstruction::try_from(&variant.attrs[..]) {
+        Ok(idl_ix) => Ok(idl_ix.to_accounts(variant.ident.clone()).0),
+        Err(_) => InstructionAccounts::from_attrs_unvalidated(&variant.attrs)
+            .map(|x| x.0),
+    }
+}
+
+fn is_valid_pubkey(key: &str) -> bool {
+    matches!(bs58::decode(key).into_vec(), Ok(bytes) if bytes.len() == 32)
+}
@@ -0,0 +1,165 @@
+use anyhow::{format_err, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{codec::decode_event, idl::Idl, idl_error_code::IdlErrorCode};
+
+const CUSTOM_ERROR: &str = "custom program error: 0x";
+const PROGRAM_DATA: &str = "Program data: ";
+const PROGRAM: &str = "Program ";
+
+// -----------------
+// Errors
+// -----------------
+
+/// Parses an error code provided either as hex prefixed with `0x`, as Solana
+/// logs them, or as decimal number.
+pub fn parse_error_code(code: &str) -> Result<u32> {
+    let code = code.trim();
+    let parsed =
+        match code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => code.parse(),
+        };
+    parsed.map_err(|_| format_err!("Invalid error code '{}'", code))
+}
+
+/// Finds the error of the IDL with the provided code.
+pub fn find_error(idl: &Idl, code: u32) -> Option<&IdlErrorCode> {
+    idl.errors
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .find(|error| error.code == code)
+}
+
+/// Describes the error via its name and message, i.e. `InvalidOwner: Owner
+/// does not match`.
+pub fn describe_error(error: &IdlErrorCode) -> String {
+    match &error.msg {
+        Some(msg) if !msg.is_empty() => format!("{}: {}", error.name, msg),
+        _ => error.name.clone(),
+    }
+}
+
+// -----------------
+// LogAnnotator
+// -----------------
+
+/// Annotates transaction logs with the errors and events of the program.
+///
+/// The invoked programs are tracked so that only errors and events of the
+/// program are annotated. If neither the IDL nor the caller provide the
+/// program id then those of all programs are annotated.
+pub struct LogAnnotator<'a> {
+    idl: &'a Idl,
+    program_id: Option<String>,
+    invocations: Vec<String>,
+}
+
+impl<'a> LogAnnotator<'a> {
+    pub fn new(idl: &'a Idl, program_id: Option<&str>) -> Self {
+        Self {
+            idl,
+            program_id: program_id
+                .or(idl.metadata.address.as_deref())
+                .map(str::to_string),
+            invocations: Vec::new(),
+        }
+    }
+
+    fn is_program(&self, program: Option<&str>) -> bool {
+        match (&self.program_id, program) {
+            (None, _) => true,
+            (Some(program_id), Some(program)) => program_id == program,
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Appends the error or event a log line refers to, i.e.
+    /// `Program Vau1t.. failed: custom program error: 0x1771 (InvalidOwner:
+    /// Owner does not match)`. Other lines are returned unchanged.
+    pub fn annotate_line(&mut self, line: &str) -> String {
+        if let Some(idx) = line.find(PROGRAM_DATA) {
+            let program = self.invocations.last().map(String::as_str);
+            if !self.is_program(program) {
+                return line.to_string();
+            }
+            let events = line[idx + PROGRAM_DATA.len()..]
+                .split_whitespace()
+                .filter_map(|data| STANDARD.decode(data).ok())
+                .filter_map(|data| decode_event(self.idl, &data).ok()?)
+                .map(|event| {
+                    format!(
+                        "{} {}",
+                        event.name,
+                        serde_json::Value::Object(event.data)
+                    )
+                })
+                .collect::<Vec<String>>();
+            return annotate(line, &events.join(", "));
+        }
+
+        let (program, status) = match line.find(PROGRAM) {
+            Some(idx) => {
+                let rest = &line[idx + PROGRAM.len()..];
+                match rest.split_once(' ') {
+                    Some((program, status)) => (Some(program), status),
+                    None => (None, rest),
+                }
+            }
+            None => (None, line),
+        };
+        let ended = status == "success" || status.starts_with("failed");
+        let invoked = status.starts_with("invoke [");
+        if let (true, Some(program)) = (invoked, program) {
+            self.invocations.push(program.to_string());
+        }
+
+        let annotated = match line.find(CUSTOM_ERROR) {
+            Some(idx) => {
+                // Errors without the program refer to the current invocation
+                let program = program
+                    .filter(|_| ended)
+                    .or_else(|| self.invocations.last().map(String::as_str));
+                let hex = line[idx + CUSTOM_ERROR.len()..]
+                    .split(|c: char| !c.is_ascii_hexdigit())
+                    .next()
+                    .unwrap_or_default();
+                let error = u32::from_str_radix(hex, 16)
+                    .ok()
+                    .filter(|_| self.is_program(program))
+                    .and_then(|code| find_error(self.idl, code))
+                    .map(describe_error)
+                    .unwrap_or_default();
+                annotate(line, &error)
+            }
+            None => line.to_string(),
+        };
+
+        if ended {
+            self.invocations.pop();
+        }
+        annotated
+    }
+}
+
+fn annotate(line: &str, annotation: &str) -> String {
+    if annotation.is_empty() {
+        line.to_string()
+    } else {
+        format!("{} ({})", line, annotation)
+    }
+}
+
+/// Annotates each line of the logs, see [LogAnnotator].
+pub fn annotate_logs(
+    idl: &Idl,
+    program_id: Option<&str>,
+    logs: &str,
+) -> String {
+    let mut annotator = LogAnnotator::new(idl, program_id);
+    logs.lines()
+        .map(|line| annotator.annotate_line(line))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -69,16 +69,8 @@ impl Manifest {
     }
 
     pub fn lib_name(&self) -> Result<String> {
-        if self.lib.is_some() && self.lib.as_ref().unwrap().name.is_some() {
-            Ok(self
-                .lib
-                .as_ref()
-                .unwrap()
-                .name
-                .as_ref()
-                .unwrap()
-                .to_string()
-                .to_snake_case())
+        if let Some(name) = self.lib.as_ref().and_then(|x| x.name.as_ref()) {
+            Ok(name.to_string().to_snake_case())
         } else {
             Ok(self
                 .package
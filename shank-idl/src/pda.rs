@@ -0,0 +1,368 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use anyhow::{bail, format_err, Result};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use heck::MixedCase;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    idl::Idl,
+    idl_seed::{IdlSeed, IdlSeedsProgram},
+    idl_type::IdlType,
+    idl_type_definition::IdlTypeDefinitionTy,
+};
+
+/// The maximum number of seeds of a PDA, including the bump.
+pub const MAX_SEEDS: usize = 16;
+/// The maximum length of a single seed in bytes.
+pub const MAX_SEED_LEN: usize = 32;
+
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+pub type Pubkey = [u8; 32];
+
+/// Parses a base58 encoded public key.
+pub fn parse_pubkey(pubkey: &str) -> Result<Pubkey> {
+    bs58::decode(pubkey)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .ok_or_else(|| {
+            format_err!("'{}' is not a base58 encoded public key", pubkey)
+        })
+}
+
+// -----------------
+// Derivation
+// -----------------
+
+fn check_seeds(seeds: &[&[u8]]) -> Result<()> {
+    if seeds.len() > MAX_SEEDS {
+        bail!(
+            "A PDA can have at most {} seeds including the bump, but it has {}",
+            MAX_SEEDS,
+            seeds.len()
+        );
+    }
+    match seeds.iter().find(|seed| seed.len() > MAX_SEED_LEN) {
+        Some(seed) => bail!(
+            "Seeds can be at most {} bytes long, but a seed has {} bytes",
+            MAX_SEED_LEN,
+            seed.len()
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Hashes the seeds, returning the address if it lies off the ed25519 curve
+/// which ensures that no private key exists for it.
+fn pda_off_curve(seeds: &[&[u8]], program_id: &Pubkey) -> Option<Pubkey> {
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    hasher.update(program_id);
+    hasher.update(PDA_MARKER);
+
+    let mut address = [0; 32];
+    address.copy_from_slice(&hasher.finalize());
+    match CompressedEdwardsY(address).decompress() {
+        Some(_) => None,
+        None => Some(address),
+    }
+}
+
+/// Derives the address for the seeds the same way as
+/// `Pubkey::create_program_address`, failing if it isn't a valid PDA.
+pub fn create_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<Pubkey> {
+    check_seeds(seeds)?;
+    pda_off_curve(seeds, program_id).ok_or_else(|| {
+        format_err!("The address derived from the seeds is not a valid PDA")
+    })
+}
+
+/// Finds the PDA and its bump the same way as `Pubkey::find_program_address`,
+/// trying bumps from 255 down until the address lies off the curve.
+pub fn find_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<(Pubkey, u8)> {
+    let mut checked_seeds = seeds.to_vec();
+    checked_seeds.push(&[0]);
+    check_seeds(&checked_seeds)?;
+
+    for bump in (0..=u8::MAX).rev() {
+        let bump_seed = [bump];
+        let mut seeds_with_bump = seeds.to_vec();
+        seeds_with_bump.push(&bump_seed);
+        if let Some(address) = pda_off_curve(&seeds_with_bump, program_id) {
+            return Ok((address, bump));
+        }
+    }
+    bail!("Unable to find a valid bump for the seeds")
+}
+
+// -----------------
+// Account PDAs
+// -----------------
+
+fn parse_int<T: FromStr>(value: &str, ty: &str) -> Result<T> {
+    value
+        .parse::<T>()
+        .map_err(|_| format_err!("'{}' is not a valid {}", value, ty))
+}
+
+/// Resolves the discriminant of the enum variant provided by name or
+/// discriminant, honouring discriminants assigned to variants the same way
+/// `as u8` does.
+fn enum_discriminant(idl: &Idl, name: &str, value: &str) -> Result<u8> {
+    let variants = idl
+        .types
+        .iter()
+        .find(|type_definition| type_definition.name == name)
+        .and_then(|type_definition| match &type_definition.ty {
+            IdlTypeDefinitionTy::Enum { variants } => Some(variants),
+            IdlTypeDefinitionTy::Struct { .. } => None,
+        })
+        .ok_or_else(|| {
+            format_err!("Enum {} is not defined in the IDL", name)
+        })?;
+
+    let discriminants = variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| (variant, variant.discriminant_at(index)));
+    let discriminant = match value.parse::<usize>() {
+        Ok(value) => discriminants
+            .map(|(_, discriminant)| discriminant)
+            .find(|discriminant| *discriminant == value),
+        Err(_) => discriminants
+            .filter(|(variant, _)| variant.name == value)
+            .map(|(_, discriminant)| discriminant)
+            .next(),
+    };
+    let discriminant = discriminant.ok_or_else(|| {
+        format_err!(
+            "'{}' is not a variant of {}, its variants are: {}",
+            value,
+            name,
+            variants
+                .iter()
+                .map(|variant| variant.name.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )
+    })?;
+    u8::try_from(discriminant).map_err(|_| {
+        format_err!(
+            "The discriminant {} of {}::{} does not fit into a single byte",
+            discriminant,
+            name,
+            value
+        )
+    })
+}
+
+/// Encodes the value of a seed param according to its type the same way the
+/// `shank_pda` fn derived for the account does.
+pub fn seed_bytes(idl: &Idl, ty: &IdlType, value: &str) -> Result<Vec<u8>> {
+    let bytes = match ty {
+        IdlType::PublicKey => parse_pubkey(value)?.to_vec(),
+        IdlType::U8 => vec![parse_int::<u8>(value, "u8")?],
+        IdlType::U16 => parse_int::<u16>(value, "u16")?.to_le_bytes().to_vec(),
+        IdlType::U32 => parse_int::<u32>(value, "u32")?.to_le_bytes().to_vec(),
+        IdlType::U64 => parse_int::<u64>(value, "u64")?.to_le_bytes().to_vec(),
+        IdlType::I64 => parse_int::<i64>(value, "i64")?.to_le_bytes().to_vec(),
+        IdlType::Bool => match value {
+            "true" => vec![1],
+            "false" => vec![0],
+            _ => bail!("'{}' is not a valid bool", value),
+        },
+        IdlType::String => value.as_bytes().to_vec(),
+        IdlType::Defined(name) => vec![enum_discriminant(idl, name, value)?],
+        ty => bail!("Seeds of type {:?} are not supported", ty),
+    };
+    Ok(bytes)
+}
+
+/// A PDA along with its bump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedPda {
+    pub address: Pubkey,
+    pub bump: u8,
+}
+
+/// Derives the PDA of the account from the `#[seeds]` it declares, given the
+/// values of its seed params by name.
+///
+/// The program id defaults to the program declared via `#[seeds(.., program = ..)]`
+/// and otherwise to the address of the IDL.
+pub fn derive_account_pda(
+    idl: &Idl,
+    account: &str,
+    values: &[(String, String)],
+    program_id: Option<&str>,
+) -> Result<DerivedPda> {
+    let type_definition = idl
+        .accounts
+        .iter()
+        .find(|type_definition| type_definition.name == account)
+        .ok_or_else(|| {
+            format_err!("Account {} is not defined in the IDL", account)
+        })?;
+    let seeds = type_definition.seeds.as_deref().ok_or_else(|| {
+        format_err!("Account {} does not declare #[seeds]", account)
+    })?;
+    let program_id = match (program_id, &type_definition.seeds_program) {
+        (Some(program_id), _) => parse_pubkey(program_id)?,
+        (None, Some(IdlSeedsProgram::Address { address })) => {
+            parse_pubkey(address)?
+        }
+        (None, Some(IdlSeedsProgram::Path { path })) => bail!(
+            "The PDA of account {} is derived for the program {} whose address isn't known, provide the program id",
+            account,
+            path
+        ),
+        (None, None) => match idl.metadata.address.as_deref() {
+            Some(address) => parse_pubkey(address)?,
+            None => bail!("The IDL has no address, provide the program id"),
+        },
+    };
+
+    // Params may be provided by their name in the IDL or in the program
+    let params = seeds
+        .iter()
+        .filter_map(|seed| match seed {
+            IdlSeed::Variable { name, .. } => Some(name.as_str()),
+            IdlSeed::Constant { .. } | IdlSeed::ProgramId => None,
+        })
+        .collect::<Vec<&str>>();
+    if let Some((name, _)) = values
+        .iter()
+        .find(|(name, _)| !params.contains(&name.to_mixed_case().as_str()))
+    {
+        bail!(
+            "Unknown seed '{}' of account {}, its seeds are: {}",
+            name,
+            account,
+            params.join(", ")
+        );
+    }
+
+    let mut seed_bytes_list = Vec::with_capacity(seeds.len());
+    for seed in seeds {
+        let bytes = match seed {
+            IdlSeed::Constant { value } => value.as_bytes().to_vec(),
+            IdlSeed::ProgramId => program_id.to_vec(),
+            IdlSeed::Variable {
+                name,
+                description,
+                ty,
+            } => {
+                let value = values
+                    .iter()
+                    .find(|(provided, _)| &provided.to_mixed_case() == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| {
+                        format_err!(
+                            "Missing seed '{}' of account {} ({})",
+                            name,
+                            account,
+                            description
+                        )
+                    })?;
+                seed_bytes(idl, ty, value).map_err(|err| {
+                    format_err!(
+                        "Seed '{}' of account {}: {}",
+                        name,
+                        account,
+                        err
+                    )
+                })?
+            }
+        };
+        seed_bytes_list.push(bytes);
+    }
+
+    let seeds = seed_bytes_list
+        .iter()
+        .map(Vec::as_slice)
+        .collect::<Vec<&[u8]>>();
+    let (address, bump) = find_program_address(&seeds, &program_id)?;
+    Ok(DerivedPda { address, bump })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pubkey(pubkey: &str) -> Pubkey {
+        parse_pubkey(pubkey).unwrap()
+    }
+
+    #[test]
+    fn create_program_address_matches_solana() {
+        let program_id = pubkey("BPFLoaderUpgradeab1e11111111111111111111111");
+        let public_key = pubkey("SeedPubey1111111111111111111111111111111111");
+
+        assert_eq!(
+            create_program_address(&[b"", &[1]], &program_id).unwrap(),
+            pubkey("BwqrghZA2htAcqq8dzP1WDAhTXYTYWj7CHxF5j7TDBAe")
+        );
+        assert_eq!(
+            create_program_address(&["\u{2609}".as_bytes(), &[0]], &program_id)
+                .unwrap(),
+            pubkey("13yWmRpaTR4r5nAktwLqMpRNr28tnVUZw26rTvPSSB19")
+        );
+        assert_eq!(
+            create_program_address(&[b"Talking", b"Squirrels"], &program_id)
+                .unwrap(),
+            pubkey("2fnQrngrQT4SeLcdToJAD96phoEjNL2man2kfRLCASVk")
+        );
+        assert_eq!(
+            create_program_address(&[&public_key, &[1]], &program_id).unwrap(),
+            pubkey("976ymqVnfE32QFe6NfGDctSvVa36LWnvYxhU6G2232YL")
+        );
+    }
+
+    #[test]
+    fn find_program_address_returns_first_valid_bump() {
+        let program_id = pubkey("BPFLoaderUpgradeab1e11111111111111111111111");
+        let (address, bump) =
+            find_program_address(&[b"Lil'", b"Bits"], &program_id).unwrap();
+        assert_eq!(
+            create_program_address(&[b"Lil'", b"Bits", &[bump]], &program_id)
+                .unwrap(),
+            address
+        );
+        for higher in (u16::from(bump) + 1)..=u16::from(u8::MAX) {
+            assert!(create_program_address(
+                &[b"Lil'", b"Bits", &[higher as u8]],
+                &program_id
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn seed_limits() {
+        let program_id = [0; 32];
+        let long_seed = [0; MAX_SEED_LEN + 1];
+        assert_eq!(
+            create_program_address(&[&long_seed], &program_id)
+                .unwrap_err()
+                .to_string(),
+            "Seeds can be at most 32 bytes long, but a seed has 33 bytes"
+        );
+        let seeds = [&[0u8][..]; MAX_SEEDS];
+        assert_eq!(
+            find_program_address(&seeds, &program_id)
+                .unwrap_err()
+                .to_string(),
+            "A PDA can have at most 16 seeds including the bump, but it has 17"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, format_err, Error, Result};
+
+use crate::{
+    diagnostics::{Diagnostic, Diagnostics, SourceLocation},
+    idl::Idl,
+    idl_field::IdlField,
+    idl_type::IdlType,
+    idl_type_definition::{
+        IdlSerialization, IdlTypeDefinition, IdlTypeDefinitionTy,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Layout {
+    size: usize,
+    align: usize,
+}
+
+impl Layout {
+    fn new(size: usize, align: usize) -> Self {
+        Self { size, align }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StructLayout {
+    layout: Layout,
+    offsets: Vec<usize>,
+}
+
+fn align_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+fn is_zero_copy(type_definition: &IdlTypeDefinition) -> bool {
+    type_definition.serialization == Some(IdlSerialization::Bytemuck)
+}
+
+/// Describes why the type can't be part of a zero-copy type.
+fn unsized_reason(ty: &IdlType) -> &'static str {
+    match ty {
+        IdlType::String => "String has no fixed size",
+        IdlType::Bytes => "bytes have no fixed size",
+        IdlType::Option(_) => {
+            "Option has no fixed layout, use a PodOption instead"
+        }
+        IdlType::Vec(_) => "Vec has no fixed size",
+        IdlType::Tuple(_) => "tuples have no defined layout",
+        IdlType::HashMap(_, _) | IdlType::BTreeMap(_, _) => {
+            "maps have no fixed size"
+        }
+        IdlType::HashSet(_) | IdlType::BTreeSet(_) => "sets have no fixed size",
+        _ => "the type has no fixed layout",
+    }
+}
+
+// -----------------
+// LayoutResolver
+// -----------------
+
+/// Computes the `#[repr(C)]` layout of zero-copy types, resolving the layouts
+/// of the types they reference along the way.
+struct LayoutResolver<'a> {
+    definitions: HashMap<&'a str, &'a IdlTypeDefinition>,
+    resolved: HashMap<String, StructLayout>,
+    resolving: Vec<String>,
+}
+
+impl<'a> LayoutResolver<'a> {
+    fn new(idl: &'a Idl) -> Self {
+        let definitions = idl
+            .accounts
+            .iter()
+            .chain(idl.types.iter())
+            .map(|type_definition| {
+                (type_definition.name.as_str(), type_definition)
+            })
+            .collect();
+        Self {
+            definitions,
+            resolved: HashMap::new(),
+            resolving: Vec::new(),
+        }
+    }
+
+    fn struct_layout(
+        &mut self,
+        type_definition: &IdlTypeDefinition,
+    ) -> Result<StructLayout> {
+        let name = &type_definition.name;
+        if let Some(layout) = self.resolved.get(name) {
+            return Ok(layout.clone());
+        }
+        if self.resolving.contains(name) {
+            bail!("Type {} cannot contain itself", name);
+        }
+
+        let fields = match &type_definition.ty {
+            IdlTypeDefinitionTy::Struct { fields } => fields,
+            IdlTypeDefinitionTy::Enum { .. } => {
+                bail!("Enum {} has no fixed layout", name)
+            }
+        };
+
+        self.resolving.push(name.to_string());
+        let layout = self.fields_layout(name, fields);
+        self.resolving.pop();
+
+        let layout = layout?;
+        self.resolved.insert(name.to_string(), layout.clone());
+        Ok(layout)
+    }
+
+    fn fields_layout(
+        &mut self,
+        name: &str,
+        fields: &[IdlField],
+    ) -> Result<StructLayout> {
+        let mut offset = 0;
+        let mut align = 1;
+        let mut offsets = Vec::with_capacity(fields.len());
+        for field in fields {
+            let field_layout = self.type_layout(&field.ty).map_err(|err| {
+                format_err!("Field '{}' of {}: {}", field.name, name, err)
+            })?;
+            offset = align_up(offset, field_layout.align);
+            offsets.push(offset);
+            offset += field_layout.size;
+            align = align.max(field_layout.align);
+        }
+
+        // Trailing padding ensures that arrays of the type stay aligned
+        Ok(StructLayout {
+            layout: Layout::new(align_up(offset, align), align),
+            offsets,
+        })
+    }
+
+    fn type_layout(&mut self, ty: &IdlType) -> Result<Layout> {
+        let layout = match ty {
+            IdlType::U8 | IdlType::I8 => Layout::new(1, 1),
+            IdlType::Bool => {
+                bail!("bool is not Pod since only 0 and 1 are valid, use a u8 instead")
+            }
+            IdlType::U16 | IdlType::I16 => Layout::new(2, 2),
+            IdlType::U32 | IdlType::I32 => Layout::new(4, 4),
+            IdlType::U64 | IdlType::I64 => Layout::new(8, 8),
+            // 128-bit integers are 16 byte aligned since Rust 1.77
+            IdlType::U128 | IdlType::I128 => Layout::new(16, 16),
+            IdlType::PublicKey => Layout::new(32, 1),
+            IdlType::Array(inner, len) => {
+                let inner = self.type_layout(inner)?;
+                Layout::new(inner.size * len, inner.align)
+            }
+            IdlType::FixedSizeOption { inner, .. } => {
+                self.type_layout(inner)?
+            }
+            IdlType::Defined(name) => {
+                let type_definition = match self.definitions.get(name.as_str())
+                {
+                    Some(type_definition) => *type_definition,
+                    None => bail!(
+                        "the layout of type {} is unknown since it isn't defined in this program",
+                        name
+                    ),
+                };
+                if !is_zero_copy(type_definition) {
+                    bail!(
+                        "type {} needs to be annotated with #[shank(zero_copy)] as well",
+                        name
+                    );
+                }
+                self.struct_layout(type_definition)?.layout
+            }
+            IdlType::String
+            | IdlType::Bytes
+            | IdlType::Option(_)
+            | IdlType::Vec(_)
+            | IdlType::Tuple(_)
+            | IdlType::HashMap(_, _)
+            | IdlType::BTreeMap(_, _)
+            | IdlType::HashSet(_)
+            | IdlType::BTreeSet(_) => bail!("{}", unsized_reason(ty)),
+        };
+        Ok(layout)
+    }
+}
+
+// -----------------
+// Populate Layouts
+// -----------------
+
+/// Computes the `#[repr(C)]` layout of all `#[shank(zero_copy)]` accounts and
+/// types, setting the offset of each field and the size of the type.
+///
+/// Fails for zero-copy types containing fields without a fixed layout.
+pub(crate) fn populate_zero_copy_layouts(
+    idl: &mut Idl,
+    type_locations: &HashMap<String, SourceLocation>,
+) -> Result<()> {
+    let mut layouts = HashMap::new();
+    let mut errors = Vec::new();
+    let mut diagnostics = Vec::new();
+    {
+        let mut resolver = LayoutResolver::new(idl);
+        let zero_copy_definitions = idl
+            .accounts
+            .iter()
+            .chain(idl.types.iter())
+            .filter(|type_definition| is_zero_copy(type_definition));
+        for type_definition in zero_copy_definitions {
+            match resolver.struct_layout(type_definition) {
+                Ok(layout) => {
+                    layouts.insert(type_definition.name.clone(), layout);
+                }
+                Err(err) => {
+                    let error = err.to_string();
+                    diagnostics.push(Diagnostic::new(
+                        error.clone(),
+                        type_locations.get(&type_definition.name).cloned(),
+                    ));
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new(Diagnostics(diagnostics)).context(format!(
+            "zero_copy layout errors:\n  - {}",
+            errors.join("\n  - ")
+        )));
+    }
+
+    for type_definition in idl.accounts.iter_mut().chain(idl.types.iter_mut()) {
+        let layout = match layouts.get(&type_definition.name) {
+            Some(layout) => layout,
+            None => continue,
+        };
+        type_definition.size = Some(layout.layout.size);
+        if let IdlTypeDefinitionTy::Struct { fields } = &mut type_definition.ty
+        {
+            for (field, offset) in fields.iter_mut().zip(&layout.offsets) {
+                field.offset = Some(*offset);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align_up_offsets() {
+        assert_eq!(align_up(0, 8), 0);
+        assert_eq!(align_up(1, 8), 8);
+        assert_eq!(align_up(9, 4), 12);
+        assert_eq!(align_up(33, 1), 33);
+    }
+}
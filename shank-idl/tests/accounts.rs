@@ -5,7 +5,8 @@ use std::{
 };
 
 use shank_idl::{
-    extract_idl, idl::Idl, parse_file, ParseIdlConfig, ParseIdlOpts,
+    diagnostics::find_diagnostics, extract_idl, idl::Idl, parse_file,
+    ParseIdlConfig, ParseIdlOpts,
 };
 
 fn fixtures_dir() -> PathBuf {
@@ -45,6 +46,36 @@ fn account_from_single_file() {
     check_or_update_idl(&idl, "single_file/account.json");
 }
 
+#[test]
+fn account_from_single_file_seeds() {
+    let file = fixtures_dir().join("single_file").join("seeds.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/seeds.json");
+}
+
+#[test]
+fn account_from_single_file_typed_seeds() {
+    let file = fixtures_dir().join("single_file").join("seeds_typed.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/seeds_typed.json");
+}
+
+#[test]
+fn account_from_single_file_seeds_program() {
+    let file = fixtures_dir().join("single_file").join("seeds_program.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/seeds_program.json");
+}
+
 #[test]
 fn account_from_single_file_complex_types() {
     let file = fixtures_dir().join("single_file").join("complex_types.rs");
@@ -190,6 +221,81 @@ fn account_from_single_file_pod_option_missing_sentinel() {
     );
 }
 
+#[test]
+fn account_from_single_file_pod_option_missing_sentinel_location() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("pod_option_missing_sentinel.rs");
+    let err =
+        parse_file(file.clone(), &ParseIdlConfig::optional_program_address())
+            .unwrap_err();
+
+    let diagnostics = find_diagnostics(&err).expect("should have diagnostics");
+    assert_eq!(diagnostics.len(), 1);
+
+    // Points at the definition of the type missing the sentinel
+    let location = diagnostics.0[0]
+        .location
+        .as_ref()
+        .expect("should have location");
+    assert_eq!(location.file, file);
+    assert_eq!(location.line, 8);
+}
+
+#[test]
+fn account_from_single_file_zero_copy() {
+    let file = fixtures_dir().join("single_file").join("zero_copy.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/zero_copy.json");
+}
+
+#[test]
+fn account_from_single_file_zero_copy_unsized() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("zero_copy_unsized.rs");
+    let err =
+        parse_file(file.clone(), &ParseIdlConfig::optional_program_address())
+            .unwrap_err();
+    let err_msg = err.to_string();
+    assert!(
+        err_msg.contains("zero_copy layout errors"),
+        "Error message should mention zero_copy layout: {}",
+        err_msg
+    );
+
+    // Reports the first field without a fixed layout at the type definition
+    let diagnostics = find_diagnostics(&err).expect("should have diagnostics");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics.0[0].message,
+        "Field 'name' of Market: String has no fixed size"
+    );
+    let location = diagnostics.0[0]
+        .location
+        .as_ref()
+        .expect("should have location");
+    assert_eq!(location.file, file);
+    assert_eq!(location.line, 11);
+}
+
+#[test]
+fn account_from_single_file_zero_copy_bool() {
+    let file = fixtures_dir().join("single_file").join("zero_copy_bool.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err();
+
+    let diagnostics = find_diagnostics(&err).expect("should have diagnostics");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics.0[0].message,
+        "Field 'paused' of Market: bool is not Pod since only 0 and 1 are valid, use a u8 instead"
+    );
+}
+
 #[test]
 fn account_from_crate() {
     let file = fixtures_dir()
@@ -0,0 +1,471 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use serde_json::json;
+use shank_idl::{
+    codec::{
+        account_layout, decode_account, decode_instruction, encode_instruction,
+        EncodedAccountMeta,
+    },
+    idl::Idl,
+    parse_file, ParseIdlConfig,
+};
+
+fn fixtures_dir() -> PathBuf {
+    let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    root_dir.join("tests").join("fixtures").join("codec")
+}
+
+fn program_idl() -> Idl {
+    let file = fixtures_dir().join("program.rs");
+    parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL")
+}
+
+#[test]
+fn decode_instruction_with_defined_args() {
+    let idl = program_idl();
+
+    let mut data = vec![0];
+    // config.kind: VaultKind::Escrow { release: -5 }
+    data.push(1);
+    data.extend_from_slice(&(-5i64).to_le_bytes());
+    // config.label: "main"
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"main");
+    // config.limits: { 7: 1000 }
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.push(7);
+    data.extend_from_slice(&1000u64.to_le_bytes());
+    // delegate: Some(11111111111111111111111111111111)
+    data.push(1);
+    data.extend_from_slice(&[0; 32]);
+    // expiry: None via the u64::MAX sentinel
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    // tags: { 1, 2 }
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&2u16.to_le_bytes());
+
+    let decoded = decode_instruction(&idl, &data).unwrap();
+    assert_eq!(decoded.name, "CreateVault");
+    assert_eq!(decoded.discriminant, 0);
+    assert_eq!(
+        serde_json::to_value(&decoded.args).unwrap(),
+        json!({
+            "config": {
+                "kind": { "Escrow": { "release": -5 } },
+                "label": "main",
+                "limits": { "7": 1000 },
+            },
+            "delegate": "11111111111111111111111111111111",
+            "expiry": null,
+            "tags": [1, 2],
+        })
+    );
+}
+
+#[test]
+fn decode_instruction_with_large_integers() {
+    let idl = program_idl();
+
+    let mut data = vec![1];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.extend_from_slice(&u128::MAX.to_le_bytes());
+
+    let decoded = decode_instruction(&idl, &data).unwrap();
+    assert_eq!(decoded.name, "Deposit");
+    assert_eq!(
+        serde_json::to_value(&decoded.args).unwrap(),
+        json!({
+            "amount": 42,
+            "total": u128::MAX.to_string(),
+        })
+    );
+}
+
+#[test]
+fn decode_instruction_without_args() {
+    let idl = program_idl();
+
+    let decoded = decode_instruction(&idl, &[2]).unwrap();
+    assert_eq!(decoded.name, "Close");
+    assert!(decoded.args.is_empty());
+}
+
+#[test]
+fn decode_instruction_unknown_discriminant() {
+    let idl = program_idl();
+
+    let err = decode_instruction(&idl, &[9]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No instruction with discriminant 9 found in the IDL"
+    );
+}
+
+#[test]
+fn decode_instruction_truncated_and_trailing_data() {
+    let idl = program_idl();
+
+    let err = decode_instruction(&idl, &[1, 42, 0]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arg 'amount' of instruction Deposit: Unexpected end of data reading u64 at offset 1, needed 8 bytes but only 2 remain"
+    );
+
+    let err = decode_instruction(&idl, &[2, 0]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Instruction Close has 1 trailing bytes after its args"
+    );
+}
+
+#[test]
+fn decode_instruction_invalid_enum_variant() {
+    let idl = program_idl();
+
+    let err = decode_instruction(&idl, &[0, 3]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arg 'config' of instruction CreateVault: Field 'kind' of VaultConfig: Invalid variant 3 of enum VaultKind at offset 1"
+    );
+}
+
+fn vault_data() -> Vec<u8> {
+    let mut data = vec![1];
+    data.extend_from_slice(&[0; 32]);
+    // config: { kind: Locked(3, true), label: "a", limits: {} }
+    data.extend_from_slice(&[2, 3, 0, 1]);
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(b"a");
+    data.extend_from_slice(&0u32.to_le_bytes());
+    // reserved padding followed by unused space
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&[0; 8]);
+    data
+}
+
+#[test]
+fn decode_account_detected_by_discriminator() {
+    let idl = program_idl();
+    let discriminators = idl
+        .accounts
+        .iter()
+        .map(|account| account.discriminator.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(discriminators, [Some(vec![1]), Some(b"mkt".to_vec())]);
+
+    let decoded = decode_account(&idl, None, &vault_data()).unwrap();
+    assert_eq!(decoded.name, "Vault");
+    assert_eq!(
+        decoded.data,
+        json!({
+            "key": 1,
+            "owner": "11111111111111111111111111111111",
+            "config": {
+                "kind": { "Locked": [3, true] },
+                "label": "a",
+                "limits": {},
+            },
+        })
+    );
+}
+
+#[test]
+fn decode_zero_copy_account() {
+    let idl = program_idl();
+
+    let mut data = b"mkt".to_vec();
+    // implicit padding before fee_bps
+    data.push(0xaa);
+    data.extend_from_slice(&30u16.to_le_bytes());
+    data.extend_from_slice(&[0xaa; 2]);
+    data.extend_from_slice(&i64::MAX.to_le_bytes());
+
+    let decoded = decode_account(&idl, Some("Market"), &data).unwrap();
+    assert_eq!(decoded.name, "Market");
+    assert_eq!(
+        decoded.data,
+        json!({
+            "tag": [109, 107, 116],
+            "feeBps": 30,
+            "expiry": null,
+        })
+    );
+
+    let (_, layout) = account_layout(&idl, None, &data).unwrap();
+    let offsets = layout
+        .iter()
+        .map(|field| (field.name.as_str(), field.offset, field.size))
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, [("tag", 0, 3), ("feeBps", 4, 2), ("expiry", 8, 8)]);
+}
+
+#[test]
+fn account_layout_includes_padding() {
+    let idl = program_idl();
+
+    let (name, layout) = account_layout(&idl, None, &vault_data()).unwrap();
+    assert_eq!(name, "Vault");
+    let offsets = layout
+        .iter()
+        .map(|field| {
+            (field.name.as_str(), field.offset, field.size, field.padding)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        offsets,
+        [
+            ("key", 0, 1, false),
+            ("owner", 1, 32, false),
+            ("config", 33, 13, false),
+            ("reserved", 46, 4, true),
+        ]
+    );
+}
+
+#[test]
+fn decode_account_undetected() {
+    let idl = program_idl();
+
+    let err = decode_account(&idl, None, &[7, 0]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No account of the IDL has a discriminator matching the data, provide the account type instead"
+    );
+
+    let err = decode_account(&idl, Some("Missing"), &[7, 0]).unwrap_err();
+    assert_eq!(err.to_string(), "Account Missing is not defined in the IDL");
+}
+
+fn pubkey(byte: u8) -> String {
+    bs58::encode([byte; 32]).into_string()
+}
+
+fn key(pubkey: &str) -> [u8; 32] {
+    let mut key = [0; 32];
+    key.copy_from_slice(&bs58::decode(pubkey).into_vec().unwrap());
+    key
+}
+
+fn meta(
+    pubkey: &str,
+    is_signer: bool,
+    is_writable: bool,
+) -> EncodedAccountMeta {
+    EncodedAccountMeta {
+        pubkey: key(pubkey),
+        is_signer,
+        is_writable,
+    }
+}
+
+#[test]
+fn encode_instruction_roundtrips_decoded_args() {
+    let idl = program_idl();
+    let args = json!({
+        "config": {
+            "kind": { "Locked": [3, true] },
+            "label": "main",
+            "limits": { "7": 1000 },
+        },
+        "delegate": null,
+        "expiry": "42",
+        "tags": [1, 2],
+    });
+    let program_id = &pubkey(9);
+    let owner = &pubkey(1);
+    let vault = &pubkey(2);
+    let accounts = json!({ "owner": owner, "vault": vault });
+
+    let ix = encode_instruction(
+        &idl,
+        "CreateVault",
+        &args,
+        &accounts,
+        Some(program_id),
+    )
+    .unwrap();
+    assert_eq!(ix.program_id, key(program_id));
+    assert_eq!(
+        ix.accounts,
+        [meta(owner, true, false), meta(vault, false, true)]
+    );
+
+    let decoded = decode_instruction(&idl, &ix.data).unwrap();
+    assert_eq!(decoded.name, "CreateVault");
+    assert_eq!(serde_json::to_value(&decoded.args).unwrap(), {
+        let mut expected = args;
+        expected["expiry"] = json!(42);
+        expected
+    });
+}
+
+#[test]
+fn encode_instruction_sorts_maps_and_sets_like_borsh() {
+    let idl = program_idl();
+    let limits: HashMap<u8, u64> =
+        vec![(200, 1), (7, 2), (42, 3)].into_iter().collect();
+    let tags: BTreeSet<u16> = vec![300, 2, 17].into_iter().collect();
+    let args = json!({
+        "config": {
+            "kind": "Savings",
+            "label": "main",
+            "limits": { "200": 1, "7": 2, "42": 3 },
+        },
+        "delegate": null,
+        "expiry": 42,
+        "tags": [300, 2, 17],
+    });
+
+    let ix = encode_instruction(
+        &idl,
+        "CreateVault",
+        &args,
+        &json!({ "owner": pubkey(1), "vault": pubkey(2) }),
+        Some(&pubkey(9)),
+    )
+    .unwrap();
+
+    let mut expected = vec![0, 0];
+    expected.extend(borsh::to_vec(&String::from("main")).unwrap());
+    expected.extend(borsh::to_vec(&limits).unwrap());
+    expected.push(0);
+    expected.extend_from_slice(&42u64.to_le_bytes());
+    expected.extend(borsh::to_vec(&tags).unwrap());
+    assert_eq!(ix.data, expected);
+}
+
+#[test]
+fn encode_instruction_optional_accounts() {
+    let idl = program_idl();
+    let program_id = &pubkey(9);
+    let owner = &pubkey(1);
+    let args = json!({ "amount": 5 });
+
+    // Omitted optional accounts are replaced with the program id and the
+    // address is used by default
+    let ix = encode_instruction(
+        &idl,
+        "Withdraw",
+        &args,
+        &json!({ "owner": { "pubkey": owner, "isSigner": true } }),
+        Some(program_id),
+    )
+    .unwrap();
+    assert_eq!(ix.data, [3, 5, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        ix.accounts,
+        [
+            meta(owner, true, false),
+            meta(program_id, false, false),
+            meta("11111111111111111111111111111111", false, false),
+        ]
+    );
+
+    // Omitted with the legacy strategy
+    let ix = encode_instruction(
+        &idl,
+        "LegacyWithdraw",
+        &args,
+        &json!({ "owner": owner }),
+        Some(program_id),
+    )
+    .unwrap();
+    assert_eq!(ix.accounts, [meta(owner, true, false)]);
+
+    let mut bytes = key(program_id).to_vec();
+    bytes.extend_from_slice(&[1, 0, 0, 0]);
+    bytes.extend_from_slice(&key(owner));
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&[9, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(ix.to_bytes(), bytes);
+}
+
+#[test]
+fn encode_instruction_invalid_input() {
+    let idl = program_idl();
+    let program_id = &pubkey(9);
+    let owner = &pubkey(1);
+    let encode = |name: &str, args, accounts, program_id| {
+        encode_instruction(&idl, name, &args, &accounts, program_id)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(
+        encode("Close", json!(null), json!({ "owner": owner }), None),
+        "The IDL has no address, provide the program id"
+    );
+    assert_eq!(
+        encode(
+            "Deposit",
+            json!({ "amount": 1 }),
+            json!({}),
+            Some(program_id)
+        ),
+        "Missing arg 'total' of instruction Deposit"
+    );
+    assert_eq!(
+        encode(
+            "Deposit",
+            json!({ "amount": -1, "total": 0 }),
+            json!({}),
+            Some(program_id)
+        ),
+        "Arg 'amount' of instruction Deposit: Expected a u64, found -1"
+    );
+    assert_eq!(
+        encode(
+            "Close",
+            json!(null),
+            json!({ "owners": owner }),
+            Some(program_id)
+        ),
+        "Unknown account 'owners' of instruction Close"
+    );
+    assert_eq!(
+        encode(
+            "Close",
+            json!(null),
+            json!({ "owner": { "pubkey": owner, "isSigner": false } }),
+            Some(program_id)
+        ),
+        "The account 'owner' of instruction Close is not an optional signer, isSigner cannot be provided"
+    );
+    assert_eq!(
+        encode("Close", json!(null), json!({}), Some(program_id)),
+        "Missing account 'owner' of instruction Close"
+    );
+    let create_vault = |limits, tags| {
+        json!({
+            "config": { "kind": "Savings", "label": "", "limits": limits },
+            "delegate": null,
+            "expiry": null,
+            "tags": tags,
+        })
+    };
+    let accounts = json!({ "owner": owner, "vault": owner });
+    assert_eq!(
+        encode(
+            "CreateVault",
+            create_vault(json!({}), json!([2, 1, 2])),
+            accounts.clone(),
+            Some(program_id)
+        ),
+        "Arg 'tags' of instruction CreateVault: Duplicate set item 2"
+    );
+    assert_eq!(
+        encode(
+            "CreateVault",
+            create_vault(json!({ "7": 1, "07": 2 }), json!([])),
+            accounts,
+            Some(program_id)
+        ),
+        "Arg 'config' of instruction CreateVault: Field 'limits' of VaultConfig: Duplicate map key \"07\""
+    );
+}
@@ -0,0 +1,17 @@
+#[derive(ShankAccount)]
+#[seeds(
+    "metadata",
+    program_id,
+    mint("The mint of the metadata account"),
+    edition_bump("The edition bump", u8),
+    name("The name of the metadata", String),
+)]
+pub struct Metadata {
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[derive(ShankAccount)]
+pub struct AccountWithoutSeeds {
+    pub count: u8,
+}
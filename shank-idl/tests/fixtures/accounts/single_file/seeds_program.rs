@@ -0,0 +1,28 @@
+#[derive(ShankAccount)]
+#[seeds(
+    wallet("The owner of the token account"),
+    token_program("The token program"),
+    mint("The mint of the token account"),
+    program = spl_associated_token_account::ID,
+)]
+pub struct AssociatedToken {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(ShankAccount)]
+#[seeds(
+    "metadata",
+    program_id,
+    mint("The mint of the metadata account"),
+    program = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+)]
+pub struct Metadata {
+    pub mint: Pubkey,
+}
+
+#[derive(ShankAccount)]
+#[seeds("config", program = crate::other_program::ID)]
+pub struct OtherConfig {
+    pub count: u8,
+}
@@ -0,0 +1,21 @@
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum Role {
+    Admin,
+    Member = 7,
+}
+
+#[derive(ShankAccount)]
+#[seeds(
+    "escrow",
+    owner("The owner of the escrow"),
+    index("The index of the escrow", u16),
+    epoch("The epoch the escrow was opened", u32),
+    amount("The amount held in escrow", u64),
+    offset("The offset of the unlock time", i64),
+    label("The label of the escrow", String),
+    role("The role of the owner", Role),
+)]
+pub struct Escrow {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
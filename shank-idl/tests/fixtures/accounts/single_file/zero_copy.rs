@@ -0,0 +1,32 @@
+use shank::{ShankAccount, ShankType};
+
+pub struct Pubkey(pub [u8; 32]);
+pub struct PodOption<T>(pub T);
+
+#[derive(Clone, Copy, ShankType)]
+#[shank(zero_copy)]
+#[repr(C)]
+pub struct Position {
+    pub active: u8,
+    pub amount: u64,
+    pub index: u16,
+}
+
+#[derive(Clone, Copy, ShankAccount)]
+#[shank(zero_copy)]
+#[repr(C)]
+pub struct Market {
+    pub key: u8,
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub total: u128,
+    pub positions: [Position; 2],
+    pub expiry: PodOption<i64>,
+    pub bump: u8,
+}
+
+/// Borsh accounts don't have offsets
+#[derive(ShankAccount)]
+pub struct Registry {
+    pub markets: Vec<Pubkey>,
+}
@@ -0,0 +1,9 @@
+use shank::ShankAccount;
+
+#[derive(Clone, Copy, ShankAccount)]
+#[shank(zero_copy)]
+#[repr(C)]
+pub struct Market {
+    pub paused: bool,
+    pub amount: u64,
+}
@@ -0,0 +1,14 @@
+use shank::{ShankAccount, ShankType};
+
+#[derive(ShankType)]
+pub struct Position {
+    pub amount: u64,
+}
+
+#[derive(ShankAccount)]
+#[shank(zero_copy)]
+#[repr(C)]
+pub struct Market {
+    pub name: String,
+    pub position: Position,
+}
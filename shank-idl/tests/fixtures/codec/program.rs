@@ -0,0 +1,64 @@
+use std::collections::{BTreeSet, HashMap};
+
+use shank::{ShankAccount, ShankInstruction, ShankType};
+
+pub struct Pubkey(pub [u8; 32]);
+pub struct PodOption<T>(pub T);
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub enum VaultKind {
+    Savings,
+    Escrow { release: i64 },
+    Locked(u16, bool),
+}
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct VaultConfig {
+    pub kind: VaultKind,
+    pub label: String,
+    pub limits: HashMap<u8, u64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, ShankAccount)]
+#[shank(loaders(discriminator = 1))]
+pub struct Vault {
+    pub key: u8,
+    pub owner: Pubkey,
+    pub config: VaultConfig,
+    #[padding]
+    pub reserved: [u8; 4],
+}
+
+#[derive(Clone, Copy, ShankAccount)]
+#[shank(zero_copy, loaders(discriminator = b"mkt"))]
+#[repr(C)]
+pub struct Market {
+    pub tag: [u8; 3],
+    pub fee_bps: u16,
+    pub expiry: PodOption<i64>,
+}
+
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, name = "owner", sig)]
+    #[account(1, name = "vault", mut)]
+    CreateVault {
+        config: VaultConfig,
+        delegate: Option<Pubkey>,
+        expiry: PodOption<u64>,
+        tags: BTreeSet<u16>,
+    },
+    #[account(0, name = "owner", sig)]
+    #[account(1, name = "vault", mut)]
+    Deposit { amount: u64, total: u128 },
+    #[account(0, name = "owner", sig)]
+    Close,
+    #[account(0, name = "owner", optional_signer)]
+    #[account(1, name = "vault", mut, optional)]
+    #[account(2, name = "system_program", address = "11111111111111111111111111111111")]
+    Withdraw { amount: u64 },
+    #[legacy_optional_accounts_strategy]
+    #[account(0, name = "owner", sig)]
+    #[account(1, name = "vault", mut, optional)]
+    LegacyWithdraw { amount: u64 },
+}
@@ -0,0 +1,20 @@
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    // Misspelled sig
+    #[account(0, name = "creator", sg)]
+    CreateThing,
+    #[account(0, name = "closer", sig)]
+    #[account(0, name = "thing", mut)]
+    CloseThing,
+}
+
+#[derive(ShankAccount)]
+pub struct ValidAccount {
+    pub count: u8,
+}
+
+#[derive(ShankAccount)]
+pub struct InvalidAccount {
+    #[padding]
+    pub _padding: u8,
+}
@@ -0,0 +1,9 @@
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, name = "creator", signer)]
+    #[account(1, name = "thing", writable)]
+    #[account(2, name = "metadata_program", address = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")]
+    #[account(3, name = "rewards_program", default = "rewards::ID")]
+    #[account(4, name = "token_program", default = "spl_token::ID")]
+    CreateThing,
+}
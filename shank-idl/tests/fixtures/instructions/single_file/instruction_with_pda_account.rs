@@ -0,0 +1,7 @@
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, name = "metadata", writable, pda = Metadata, seeds(mint, edition_number))]
+    #[account(1, name = "mint")]
+    #[account(2, name = "payer", signer, writable)]
+    CreateMetadata { edition_number: u64 },
+}
@@ -0,0 +1,15 @@
+use solana_program::declare_id;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, name = "creator", sig, desc = "The creator")]
+    #[account(1, name = "thing", mut, desc = "The thing")]
+    CreateThing,
+
+    #[legacy_optional_accounts_strategy]
+    #[account(0, name = "owner", desc = "The owner")]
+    #[account(1, name = "delegate", optional, desc = "The delegate")]
+    Delegate,
+}
@@ -0,0 +1,24 @@
+use solana_program::declare_id;
+
+declare_id!("NotAValidProgramId");
+
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, name = "creator", sig, desc = "The creator")]
+    #[account(2, name = "thing", mut, desc = "The thing")]
+    CreateThing,
+
+    #[account(0, name = "authority", sig, desc = "The authority")]
+    #[account(0, name = "authority", desc = "Also the authority")]
+    CloseThing,
+
+    #[account(0, name = "payer", signer, optional_signer, desc = "The payer")]
+    #[account(1, name = "owner")]
+    SetOwner,
+
+    #[legacy_optional_accounts_strategy]
+    #[account(0, name = "delegate", optional, desc = "The delegate")]
+    #[account(1, name = "owner", desc = "The owner")]
+    #[account(2, name = "record", optional, desc = "The record")]
+    Delegate,
+}
@@ -1,6 +1,9 @@
 use std::path::{Path, PathBuf};
 
-use shank_idl::{idl::Idl, parse_file, ParseIdlConfig};
+use shank_idl::{
+    diagnostics::find_diagnostics, idl::Idl, parse_file, parse_file_partial,
+    ParseIdlConfig,
+};
 
 fn fixtures_dir() -> PathBuf {
     let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
@@ -145,6 +148,73 @@ fn instruction_from_single_file_invalid_attr() {
     assert!(source_string.contains("account meta configuration"));
 }
 
+#[test]
+fn instruction_from_single_file_invalid_attr_location() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_invalid_attr.rs");
+    let res =
+        parse_file(file.clone(), &ParseIdlConfig::optional_program_address());
+
+    let err = res.unwrap_err();
+    let diagnostics = find_diagnostics(&err).expect("should have diagnostics");
+    assert_eq!(diagnostics.len(), 1);
+
+    let diagnostic = &diagnostics.0[0];
+    assert!(diagnostic.message.contains("account meta configuration"));
+    let location = diagnostic.location.as_ref().expect("should have location");
+    assert_eq!(location.file, file);
+    assert_eq!(location.line, 4);
+}
+
+#[test]
+fn instruction_from_single_file_multiple_errors_fail_fast() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_multiple_errors.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err();
+
+    // Both invalid variants are part of the same enum and reported together
+    let diagnostics = find_diagnostics(&err).expect("should have diagnostics");
+    assert_eq!(diagnostics.len(), 2);
+}
+
+#[test]
+fn instruction_from_single_file_multiple_errors_collected() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_multiple_errors.rs");
+    let config = ParseIdlConfig {
+        collect_errors: true,
+        ..ParseIdlConfig::optional_program_address()
+    };
+    let err = parse_file(file, &config).unwrap_err();
+
+    let diagnostics = find_diagnostics(&err).expect("should have diagnostics");
+    let lines = diagnostics
+        .iter()
+        .map(|x| x.location.as_ref().expect("should have location").line)
+        .collect::<Vec<usize>>();
+    assert_eq!(lines, vec![4, 8, 19]);
+}
+
+#[test]
+fn instruction_from_single_file_multiple_errors_partial() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_multiple_errors.rs");
+    let partial =
+        parse_file_partial(file, &ParseIdlConfig::optional_program_address())
+            .expect("Parsing partially should not fail");
+
+    assert_eq!(partial.diagnostics.len(), 3);
+    let idl = partial.idl.expect("File contains IDL");
+    assert!(idl.instructions.is_empty());
+    let accounts = idl.accounts.iter().map(|x| x.name.as_str());
+    assert_eq!(accounts.collect::<Vec<&str>>(), vec!["ValidAccount"]);
+}
+
 #[test]
 fn instruction_from_single_file_invalid_discriminant() {
     let file = fixtures_dir()
@@ -190,3 +260,37 @@ fn instruction_from_single_file_with_docs() {
 
     assert_eq!(idl, expected_idl);
 }
+
+#[test]
+fn instruction_from_single_file_with_account_address() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_with_account_address.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    let expected_idl: Idl = serde_json::from_str(include_str!(
+        "./fixtures/instructions/single_file/instruction_with_account_address.json"
+    ))
+    .unwrap();
+
+    assert_eq!(idl, expected_idl);
+}
+
+#[test]
+fn instruction_from_single_file_with_pda_account() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_with_pda_account.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    let expected_idl: Idl = serde_json::from_str(include_str!(
+        "./fixtures/instructions/single_file/instruction_with_pda_account.json"
+    ))
+    .unwrap();
+
+    assert_eq!(idl, expected_idl);
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use shank_idl::lint::{lint_file, LintConfig, LintLevel, LintRule};
+
+fn fixtures_dir() -> PathBuf {
+    let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    root_dir.join("tests").join("fixtures").join("lint")
+}
+
+fn rules_and_lines(
+    file: &Path,
+    config: &LintConfig,
+) -> Vec<(LintRule, LintLevel, usize)> {
+    lint_file(file, config)
+        .expect("Linting should not fail")
+        .into_iter()
+        .map(|x| {
+            let line =
+                x.diagnostic.location.expect("should have location").line;
+            (x.rule, x.level, line)
+        })
+        .collect()
+}
+
+#[test]
+fn lint_clean() {
+    let file = fixtures_dir().join("clean.rs");
+    assert_eq!(rules_and_lines(&file, &LintConfig::default()), vec![]);
+}
+
+#[test]
+fn lint_problems() {
+    let file = fixtures_dir().join("problems.rs");
+    let mut found = rules_and_lines(&file, &LintConfig::default());
+    found.sort_by_key(|x| (x.2, x.0));
+
+    use LintLevel::*;
+    use LintRule::*;
+    assert_eq!(
+        found,
+        vec![
+            (InvalidProgramId, Deny, 3),
+            (AccountIndexGap, Warn, 9),
+            (DuplicateAccountIndex, Deny, 12),
+            (DuplicateAccountName, Deny, 12),
+            (SignerAndOptionalSigner, Deny, 15),
+            (MissingAccountDesc, Warn, 16),
+            (NonTrailingOptionalAccount, Deny, 20),
+        ]
+    );
+}
+
+#[test]
+fn lint_problems_configured_levels() {
+    let file = fixtures_dir().join("problems.rs");
+    let mut config = LintConfig::default();
+    config.set_level(LintRule::AccountIndexGap, LintLevel::Deny);
+    config.set_level(LintRule::MissingAccountDesc, LintLevel::Allow);
+    config.set_level(LintRule::InvalidProgramId, LintLevel::Warn);
+
+    let found = rules_and_lines(&file, &config);
+    assert!(found.contains(&(LintRule::AccountIndexGap, LintLevel::Deny, 9)));
+    assert!(found.contains(&(LintRule::InvalidProgramId, LintLevel::Warn, 3)));
+    assert!(!found.iter().any(|x| x.0 == LintRule::MissingAccountDesc));
+}
+
+#[test]
+fn lint_rule_names() {
+    for rule in LintRule::ALL.iter() {
+        assert_eq!(rule.name().parse::<LintRule>().unwrap(), *rule);
+    }
+    assert!("not-a-lint".parse::<LintRule>().is_err());
+}
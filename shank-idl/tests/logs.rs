@@ -0,0 +1,117 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use shank_idl::{
+    codec::event_discriminator,
+    idl::Idl,
+    logs::{annotate_logs, describe_error, find_error, parse_error_code},
+};
+
+const PROGRAM_ID: &str = "Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8";
+const OTHER_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+fn program_idl() -> Idl {
+    serde_json::from_str(include_str!("./fixtures/logs/program.json")).unwrap()
+}
+
+#[test]
+fn error_lookup() {
+    let idl = program_idl();
+
+    assert_eq!(parse_error_code("0x1770").unwrap(), 6000);
+    assert_eq!(parse_error_code("6001").unwrap(), 6001);
+    assert_eq!(
+        parse_error_code("0xzz").unwrap_err().to_string(),
+        "Invalid error code '0xzz'"
+    );
+
+    let error = find_error(&idl, 6000).unwrap();
+    assert_eq!(describe_error(error), "InvalidOwner: Owner does not match");
+    let error = find_error(&idl, 6001).unwrap();
+    assert_eq!(describe_error(error), "VaultLocked");
+    assert!(find_error(&idl, 6002).is_none());
+}
+
+#[test]
+fn annotate_errors_of_program() {
+    let idl = program_idl();
+    let logs = format!(
+        "Program {program} invoke [1]
+Program {other} invoke [2]
+Program {other} failed: custom program error: 0x1770
+Program {program} consumed 5000 of 200000 compute units
+Program {program} failed: custom program error: 0x1770
+Program {program} failed: custom program error: 0x1772",
+        program = PROGRAM_ID,
+        other = OTHER_PROGRAM_ID
+    );
+
+    assert_eq!(
+        annotate_logs(&idl, None, &logs),
+        format!(
+            "Program {program} invoke [1]
+Program {other} invoke [2]
+Program {other} failed: custom program error: 0x1770
+Program {program} consumed 5000 of 200000 compute units
+Program {program} failed: custom program error: 0x1770 (InvalidOwner: Owner does not match)
+Program {program} failed: custom program error: 0x1772",
+            program = PROGRAM_ID,
+            other = OTHER_PROGRAM_ID
+        )
+    );
+}
+
+#[test]
+fn annotate_events_of_program() {
+    let idl = program_idl();
+    let mut data = event_discriminator("Deposited").to_vec();
+    data.extend_from_slice(&[0; 32]);
+    data.extend_from_slice(&42u64.to_le_bytes());
+    let data = STANDARD.encode(data);
+    let logs = format!(
+        "Program {program} invoke [1]
+Program data: {data}
+Program {other} invoke [2]
+Program data: {data}
+Program {other} success
+Program data: AAAA
+Program {program} success",
+        program = PROGRAM_ID,
+        other = OTHER_PROGRAM_ID,
+        data = data
+    );
+
+    assert_eq!(
+        annotate_logs(&idl, None, &logs),
+        format!(
+            "Program {program} invoke [1]
+Program data: {data} (Deposited {{\"owner\":\"11111111111111111111111111111111\",\"amount\":42}})
+Program {other} invoke [2]
+Program data: {data}
+Program {other} success
+Program data: AAAA
+Program {program} success",
+            program = PROGRAM_ID,
+            other = OTHER_PROGRAM_ID,
+            data = data
+        )
+    );
+}
+
+#[test]
+fn annotate_without_program_id() {
+    let mut idl = program_idl();
+    idl.metadata.address = None;
+    let logs = format!(
+        "Program {other} failed: custom program error: 0x1771",
+        other = OTHER_PROGRAM_ID
+    );
+
+    assert_eq!(
+        annotate_logs(&idl, None, &logs),
+        format!(
+            "Program {other} failed: custom program error: 0x1771 (VaultLocked)",
+            other = OTHER_PROGRAM_ID
+        )
+    );
+    // Explicitly provided program ids take precedence
+    assert_eq!(annotate_logs(&idl, Some(PROGRAM_ID), &logs), logs);
+}
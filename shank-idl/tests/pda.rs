@@ -0,0 +1,231 @@
+use shank_idl::{
+    idl::Idl,
+    pda::{derive_account_pda, find_program_address, parse_pubkey, DerivedPda},
+};
+
+const PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+const MINT: &str = "So11111111111111111111111111111111111111112";
+
+fn seeds_idl() -> Idl {
+    serde_json::from_str(include_str!(
+        "./fixtures/accounts/single_file/seeds.json"
+    ))
+    .unwrap()
+}
+
+fn values(values: &[(&str, &str)]) -> Vec<(String, String)> {
+    values
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn derive_pda_from_account_seeds() {
+    let idl = seeds_idl();
+    let program_id = parse_pubkey(PROGRAM_ID).unwrap();
+    let mint = parse_pubkey(MINT).unwrap();
+    let (address, bump) = find_program_address(
+        &[b"metadata", &program_id, &mint, &[3], b"Punk"],
+        &program_id,
+    )
+    .unwrap();
+    let expected = DerivedPda { address, bump };
+
+    // Seed params can be provided by their IDL or program names
+    let pda = derive_account_pda(
+        &idl,
+        "Metadata",
+        &values(&[("mint", MINT), ("editionBump", "3"), ("name", "Punk")]),
+        Some(PROGRAM_ID),
+    )
+    .unwrap();
+    assert_eq!(pda, expected);
+
+    let pda = derive_account_pda(
+        &idl,
+        "Metadata",
+        &values(&[("name", "Punk"), ("edition_bump", "3"), ("mint", MINT)]),
+        Some(PROGRAM_ID),
+    )
+    .unwrap();
+    assert_eq!(pda, expected);
+}
+
+#[test]
+fn derive_pda_errors() {
+    let idl = seeds_idl();
+    let derive = |account: &str, provided: &[(&str, &str)]| {
+        derive_account_pda(&idl, account, &values(provided), Some(PROGRAM_ID))
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(
+        derive("Unknown", &[]),
+        "Account Unknown is not defined in the IDL"
+    );
+    assert_eq!(
+        derive("AccountWithoutSeeds", &[]),
+        "Account AccountWithoutSeeds does not declare #[seeds]"
+    );
+    assert_eq!(
+        derive("Metadata", &[("mint", MINT), ("owner", MINT)]),
+        "Unknown seed 'owner' of account Metadata, its seeds are: mint, editionBump, name"
+    );
+    assert_eq!(
+        derive("Metadata", &[("mint", MINT), ("name", "Punk")]),
+        "Missing seed 'editionBump' of account Metadata (The edition bump)"
+    );
+    assert_eq!(
+        derive(
+            "Metadata",
+            &[("mint", MINT), ("editionBump", "256"), ("name", "Punk")]
+        ),
+        "Seed 'editionBump' of account Metadata: '256' is not a valid u8"
+    );
+    assert_eq!(
+        derive_account_pda(&idl, "Metadata", &[], None)
+            .unwrap_err()
+            .to_string(),
+        "The IDL has no address, provide the program id"
+    );
+}
+
+#[test]
+fn derive_pda_from_typed_account_seeds() {
+    let idl: Idl = serde_json::from_str(include_str!(
+        "./fixtures/accounts/single_file/seeds_typed.json"
+    ))
+    .unwrap();
+    let program_id = parse_pubkey(PROGRAM_ID).unwrap();
+    let owner = parse_pubkey(MINT).unwrap();
+    let (address, bump) = find_program_address(
+        &[
+            b"escrow",
+            &owner,
+            &7u16.to_le_bytes(),
+            &500u32.to_le_bytes(),
+            &1_000_000u64.to_le_bytes(),
+            &(-60i64).to_le_bytes(),
+            b"savings",
+            &[7],
+        ],
+        &program_id,
+    )
+    .unwrap();
+    let expected = DerivedPda { address, bump };
+
+    let mut provided = vec![
+        ("owner", MINT),
+        ("index", "7"),
+        ("epoch", "500"),
+        ("amount", "1000000"),
+        ("offset", "-60"),
+        ("label", "savings"),
+        ("role", "Member"),
+    ];
+    let pda = derive_account_pda(
+        &idl,
+        "Escrow",
+        &values(&provided),
+        Some(PROGRAM_ID),
+    )
+    .unwrap();
+    assert_eq!(pda, expected);
+
+    // Enum variants can also be provided by their discriminant
+    provided[6] = ("role", "7");
+    let pda = derive_account_pda(
+        &idl,
+        "Escrow",
+        &values(&provided),
+        Some(PROGRAM_ID),
+    )
+    .unwrap();
+    assert_eq!(pda, expected);
+
+    provided[6] = ("role", "1");
+    assert_eq!(
+        derive_account_pda(&idl, "Escrow", &values(&provided), Some(PROGRAM_ID))
+            .unwrap_err()
+            .to_string(),
+        "Seed 'role' of account Escrow: '1' is not a variant of Role, its variants are: Admin, Member"
+    );
+    provided[6] = ("role", "Guest");
+    assert_eq!(
+        derive_account_pda(&idl, "Escrow", &values(&provided), Some(PROGRAM_ID))
+            .unwrap_err()
+            .to_string(),
+        "Seed 'role' of account Escrow: 'Guest' is not a variant of Role, its variants are: Admin, Member"
+    );
+    provided[6] = ("role", "Admin");
+    provided[1] = ("index", "70000");
+    assert_eq!(
+        derive_account_pda(
+            &idl,
+            "Escrow",
+            &values(&provided),
+            Some(PROGRAM_ID)
+        )
+        .unwrap_err()
+        .to_string(),
+        "Seed 'index' of account Escrow: '70000' is not a valid u16"
+    );
+}
+
+#[test]
+fn derive_pda_for_seeds_program() {
+    let idl: Idl = serde_json::from_str(include_str!(
+        "./fixtures/accounts/single_file/seeds_program.json"
+    ))
+    .unwrap();
+    let token_program = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+    let ata_program =
+        parse_pubkey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
+    let (address, bump) = find_program_address(
+        &[
+            &parse_pubkey(PROGRAM_ID).unwrap(),
+            &parse_pubkey(token_program).unwrap(),
+            &parse_pubkey(MINT).unwrap(),
+        ],
+        &ata_program,
+    )
+    .unwrap();
+
+    // Derived for the program of the seeds rather than the one of the IDL
+    let pda = derive_account_pda(
+        &idl,
+        "AssociatedToken",
+        &values(&[
+            ("wallet", PROGRAM_ID),
+            ("tokenProgram", token_program),
+            ("mint", MINT),
+        ]),
+        None,
+    )
+    .unwrap();
+    assert_eq!(pda, DerivedPda { address, bump });
+
+    // The program_id seed is the program the PDA is derived for
+    let metadata_program = parse_pubkey(PROGRAM_ID).unwrap();
+    let (address, bump) = find_program_address(
+        &[b"metadata", &metadata_program, &parse_pubkey(MINT).unwrap()],
+        &metadata_program,
+    )
+    .unwrap();
+    let pda =
+        derive_account_pda(&idl, "Metadata", &values(&[("mint", MINT)]), None)
+            .unwrap();
+    assert_eq!(pda, DerivedPda { address, bump });
+
+    assert_eq!(
+        derive_account_pda(&idl, "OtherConfig", &[], None)
+            .unwrap_err()
+            .to_string(),
+        "The PDA of account OtherConfig is derived for the program crate::other_program::ID whose address isn't known, provide the program id"
+    );
+    assert!(
+        derive_account_pda(&idl, "OtherConfig", &[], Some(PROGRAM_ID)).is_ok()
+    );
+}
@@ -6,7 +6,8 @@ use crate::{
     DERIVE_ACCOUNT_ATTR,
 };
 
-use anyhow::{format_err, Result};
+use anyhow::Result;
+use syn::Error as ParseError;
 
 fn filter_account_structs<'a>(
     structs: impl Iterator<Item = &'a syn::ItemStruct>,
@@ -26,10 +27,12 @@ pub fn extract_account_structs<'a>(
 
     for x in filter_account_structs(structs) {
         let strct = ParsedStruct::try_from(x).map_err(|err| {
-            format_err!(
-                "Encountered an error parsing {} Account.\n{}",
-                x.ident,
-                err
+            ParseError::new(
+                err.span(),
+                format!(
+                    "Encountered an error parsing {} Account.\n{}",
+                    x.ident, err
+                ),
             )
         })?;
         verify_account_struct(&strct)?;
@@ -40,20 +43,25 @@ pub fn extract_account_structs<'a>(
 
 fn verify_account_struct(strct: &ParsedStruct) -> Result<()> {
     if strct.fields.is_empty() {
-        return Err(format_err!(
-            "Account struct {} has no fields",
-            strct.ident
-        ));
+        return Err(ParseError::new_spanned(
+            &strct.ident,
+            format!("Account struct {} has no fields", strct.ident),
+        )
+        .into());
     }
     for f in &strct.fields {
-        if f.attrs.contains(&StructFieldAttr::Padding) {
-            if f.rust_type.ident != "Array" {
-                return Err(format_err!(
+        if f.attrs.contains(&StructFieldAttr::Padding)
+            && f.rust_type.ident != "Array"
+        {
+            return Err(ParseError::new_spanned(
+                &f.ident,
+                format!(
                     "Account struct {} field {} has padding attribute, but is not an Array, i.e. [u8; 36]",
                     strct.ident,
                     f.ident
-                ));
-            }
+                ),
+            )
+            .into());
         }
     }
 
@@ -63,7 +71,10 @@ fn verify_account_struct(strct: &ParsedStruct) -> Result<()> {
 #[cfg(test)]
 mod tests {
 
-    use crate::parsed_struct::{Seed, StructAttr, StructFieldAttr};
+    use crate::parsed_struct::{
+        CratePaths, CratePathsPreset, Loaders, Seed, SeedsProgram, StructAttr,
+        StructFieldAttr,
+    };
 
     use super::*;
     use assert_matches::assert_matches;
@@ -193,7 +204,7 @@ mod tests {
             assert_eq!(fields.len(), 2);
             assert_eq!(fields[0].attrs.len(), 0, "first field not padded");
             assert_eq!(fields[1].attrs.len(), 1, "second field has one attribute");
-            assert_eq!(fields[1].attrs.contains(&StructFieldAttr::Padding), true, "second field has padding attribute");
+            assert!(fields[1].attrs.contains(&StructFieldAttr::Padding), "second field has padding attribute");
         });
     }
 
@@ -221,8 +232,8 @@ mod tests {
             assert_eq!(ident, "AccountStructWithTwoPaddedFields");
             assert_eq!(fields.len(), 3);
             assert_eq!(fields[0].attrs.len(), 0, "first field not padded");
-            assert_eq!(fields[1].attrs.contains(&StructFieldAttr::Padding), true, "second field has padding attribute");
-            assert_eq!(fields[2].attrs.contains(&StructFieldAttr::Padding), true, "third field has padding attribute");
+            assert!(fields[1].attrs.contains(&StructFieldAttr::Padding), "second field has padding attribute");
+            assert!(fields[2].attrs.contains(&StructFieldAttr::Padding), "third field has padding attribute");
         });
     }
 
@@ -596,4 +607,530 @@ mod tests {
             );
         });
     }
+
+    fn extract_seeds_program(
+        account_struct: &ItemStruct,
+    ) -> Result<Option<SeedsProgram>> {
+        let all_structs = vec![account_struct].into_iter();
+        let res = extract_account_structs(all_structs)?;
+        let struct_attrs = res.into_iter().next().unwrap().struct_attrs;
+        Ok(struct_attrs.seeds_program().cloned())
+    }
+
+    #[test]
+    fn account_with_seeds_program() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds(wallet("The wallet"), program = spl_associated_token_account::ID)]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        assert_eq!(
+            extract_seeds_program(&account_struct).unwrap(),
+            Some(SeedsProgram::Path(
+                "spl_associated_token_account::ID".to_string()
+            ))
+        );
+        let all_structs = vec![&account_struct].into_iter();
+        let struct_attrs = extract_account_structs(all_structs).unwrap()[0]
+            .struct_attrs
+            .clone();
+        let seeds = struct_attrs
+            .items()
+            .into_iter()
+            .find_map(StructAttr::into_seeds)
+            .unwrap();
+        assert_eq!(
+            seeds,
+            vec![Seed::Param(
+                "wallet".to_string(),
+                "The wallet".to_string(),
+                None
+            )]
+        );
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("metadata", program = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        assert_eq!(
+            extract_seeds_program(&account_struct).unwrap(),
+            Some(SeedsProgram::Address(
+                "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s".to_string()
+            ))
+        );
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("metadata")]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        assert_eq!(extract_seeds_program(&account_struct).unwrap(), None);
+    }
+
+    #[test]
+    fn account_with_invalid_seeds_program() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("metadata", program = "not-a-pubkey")]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_seeds_program(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("The seeds program needs to be a base58 encoded pubkey"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("metadata", program = spl_token::ID, program = spl_token_2022::ID)]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_seeds_program(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("Only one program allowed per #[seeds(..)]"));
+        });
+    }
+
+    #[test]
+    fn account_with_max_seeds() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds(
+                "01234567890123456789012345678901",
+                a("a"), b("b"), c("c"), d("d"), e("e"), f("f"), g("g"),
+                h("h"), i("i"), j("j"), k("k"), l("l"), m("m"), n("n"),
+            )]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        let seeds = extract_account_structs(all_structs).unwrap()[0]
+            .struct_attrs
+            .clone()
+            .items()
+            .into_iter()
+            .find_map(StructAttr::into_seeds)
+            .unwrap();
+        assert_eq!(seeds.len(), 15);
+    }
+
+    #[test]
+    fn account_with_too_many_seeds() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds(
+                "prefix",
+                a("a"), b("b"), c("c"), d("d"), e("e"), f("f"), g("g"),
+                h("h"), i("i"), j("j"), k("k"), l("l"), m("m"), n("n"),
+                o("o"),
+            )]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("An account can have at most 15 seeds since a PDA is derived from at most 16 seeds including the bump"));
+        });
+    }
+
+    #[test]
+    fn account_with_non_enum_custom_seed() {
+        for ty in [quote! { Vec }, quote! { std::boxed::Box }] {
+            let account_struct = parse_struct(quote! {
+                #[derive(ShankAccount)]
+                #[seeds("vault", items("The items", #ty))]
+                struct AccountStructWithSeed {
+                    count: u8,
+                }
+            });
+            let all_structs = vec![&account_struct].into_iter();
+            assert_matches!(extract_account_structs(all_structs), Err(err) => {
+                assert!(err.to_string().contains("Custom seed types need to be fieldless enums which are Copy"));
+            });
+        }
+    }
+
+    #[test]
+    fn account_with_too_long_seed_literal() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("012345678901234567890123456789012", owner("The owner"))]
+            struct AccountStructWithSeed {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("The seed literal is 33 bytes long, but seeds can be at most 32 bytes"));
+        });
+    }
+
+    fn extract_seeds_bump(
+        account_struct: &ItemStruct,
+    ) -> Result<Option<String>> {
+        let all_structs = vec![account_struct].into_iter();
+        let res = extract_account_structs(all_structs)?;
+        let struct_attrs = res.into_iter().next().unwrap().struct_attrs;
+        Ok(struct_attrs.seeds_bump().map(|bump| bump.to_string()))
+    }
+
+    #[test]
+    fn account_with_seeds_bump() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("vault", owner("The owner"), bump = vault_bump)]
+            struct AccountStructWithSeed {
+                owner: Pubkey,
+                vault_bump: u8,
+            }
+        });
+        assert_eq!(
+            extract_seeds_bump(&account_struct).unwrap(),
+            Some("vault_bump".to_string())
+        );
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("vault", owner("The owner"))]
+            struct AccountStructWithSeed {
+                owner: Pubkey,
+                bump: u8,
+            }
+        });
+        assert_eq!(extract_seeds_bump(&account_struct).unwrap(), None);
+    }
+
+    #[test]
+    fn account_with_invalid_seeds_bump() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("vault", bump = bump)]
+            struct AccountStructWithSeed {
+                owner: Pubkey,
+            }
+        });
+        assert_matches!(extract_seeds_bump(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("The seeds bump 'bump' is not a field of the struct"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("vault", bump = bump)]
+            struct AccountStructWithSeed {
+                bump: u64,
+            }
+        });
+        assert_matches!(extract_seeds_bump(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("The seeds bump field 'bump' needs to be a u8"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[seeds("vault", bump = bump, bump = other_bump)]
+            struct AccountStructWithSeed {
+                bump: u8,
+                other_bump: u8,
+            }
+        });
+        assert_matches!(extract_seeds_bump(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("Only one bump allowed per #[seeds(..)]"));
+        });
+    }
+
+    // -----------------
+    // Loaders
+    // -----------------
+    fn extract_loaders(account_struct: &ItemStruct) -> Result<Loaders> {
+        let all_structs = vec![account_struct].into_iter();
+        let res = extract_account_structs(all_structs)?;
+        let struct_attrs = res.into_iter().next().unwrap().struct_attrs;
+        Ok(struct_attrs
+            .loaders()
+            .cloned()
+            .expect("Should extract loaders"))
+    }
+
+    #[test]
+    fn account_with_default_loaders() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let loaders = extract_loaders(&account_struct).unwrap();
+        assert_eq!(loaders, Loaders::default());
+        assert_eq!(loaders.min_len(), 0);
+    }
+
+    #[test]
+    fn account_with_configured_loaders() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders(discriminator = 4, min_len = 33))]
+            #[seeds("lit:prefix")]
+            struct AccountStruct {
+                key: Key,
+                owner: Pubkey,
+            }
+        });
+        let loaders = extract_loaders(&account_struct).unwrap();
+        assert_eq!(loaders.discriminator, vec![4]);
+        assert_eq!(loaders.min_len(), 33);
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders(discriminator = b"vault"))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let loaders = extract_loaders(&account_struct).unwrap();
+        assert_eq!(loaders.discriminator, b"vault".to_vec());
+        assert_eq!(loaders.min_len(), 5);
+    }
+
+    #[test]
+    fn account_with_zero_copy_and_loaders() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(zero_copy, loaders)]
+            #[repr(C)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        let res = extract_account_structs(all_structs).unwrap();
+        let struct_attrs = &res[0].struct_attrs;
+        assert!(struct_attrs.is_zero_copy());
+        assert_eq!(struct_attrs.loaders(), Some(&Loaders::default()));
+    }
+
+    #[test]
+    fn account_with_zero_copy_without_repr_c() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(zero_copy)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("needs to be #[repr(C)]"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(zero_copy)]
+            #[repr(C, packed)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("without packed or align modifiers"));
+        });
+    }
+
+    #[test]
+    fn account_with_zero_copy_and_split_repr_attrs() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(zero_copy)]
+            #[repr(C)]
+            #[repr(packed)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("without packed or align modifiers"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(zero_copy)]
+            #[repr(C)]
+            #[repr(align(16))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("without packed or align modifiers"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(zero_copy)]
+            #[repr(packed)]
+            #[repr(C)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let all_structs = vec![&account_struct].into_iter();
+        assert_matches!(extract_account_structs(all_structs), Err(err) => {
+            assert!(err.to_string().contains("without packed or align modifiers"));
+        });
+    }
+
+    #[test]
+    fn account_with_invalid_loaders() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders(discriminator = 256))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_loaders(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("discriminator integer needs to be a u8"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders(owner = "other"))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_loaders(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("Invalid loaders option"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders, loaders(discriminator = 1))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_loaders(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("Only one #[shank(loaders)] allowed"));
+        });
+    }
+
+    // -----------------
+    // Crate Paths
+    // -----------------
+    fn extract_crate_paths(account_struct: &ItemStruct) -> Result<CratePaths> {
+        let all_structs = vec![account_struct].into_iter();
+        let res = extract_account_structs(all_structs)?;
+        Ok(res.into_iter().next().unwrap().struct_attrs.crate_paths())
+    }
+
+    fn path_string(path: &syn::Path) -> String {
+        quote! { #path }.to_string().replace(' ', "")
+    }
+
+    #[test]
+    fn account_without_crate_paths() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let crate_paths = extract_crate_paths(&account_struct).unwrap();
+        assert_eq!(crate_paths, CratePaths::default());
+        assert_eq!(crate_paths.preset, CratePathsPreset::SolanaProgram);
+        assert_eq!(
+            path_string(&crate_paths.pubkey),
+            "::solana_program::pubkey::Pubkey"
+        );
+    }
+
+    #[test]
+    fn account_with_crate_paths_preset_and_overrides() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(loaders, crate_paths(preset = "solana_sdk_split"))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let crate_paths = extract_crate_paths(&account_struct).unwrap();
+        assert_eq!(
+            crate_paths,
+            CratePaths::from_preset(CratePathsPreset::SolanaSdkSplit)
+        );
+        assert_eq!(
+            path_string(&crate_paths.account_info),
+            "::solana_account_info::AccountInfo"
+        );
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(crate_paths(
+                program_error = "crate::error::ProgramError",
+                preset = "pinocchio"
+            ))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        let crate_paths = extract_crate_paths(&account_struct).unwrap();
+        assert!(crate_paths.is_pinocchio());
+        assert_eq!(
+            path_string(&crate_paths.program_error),
+            "crate::error::ProgramError"
+        );
+        assert_eq!(
+            path_string(&crate_paths.pubkey),
+            "::pinocchio::pubkey::Pubkey"
+        );
+    }
+
+    #[test]
+    fn account_with_invalid_crate_paths() {
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(crate_paths(preset = "anchor"))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_crate_paths(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("Unknown crate paths preset"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(crate_paths(token = "::spl_token::ID"))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_crate_paths(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("Unknown crate path 'token'"));
+        });
+
+        let account_struct = parse_struct(quote! {
+            #[derive(ShankAccount)]
+            #[shank(crate_paths(pubkey = "not a path"))]
+            struct AccountStruct {
+                count: u8,
+            }
+        });
+        assert_matches!(extract_crate_paths(&account_struct), Err(err) => {
+            assert!(err.to_string().contains("pubkey crate path is not a valid path"));
+        });
+    }
 }
@@ -3,8 +3,9 @@ use syn::{Attribute, Error as ParseError, ItemEnum, Result as ParseResult};
 
 use syn::Ident;
 
-use crate::instruction::InstructionVariantFields;
+use crate::instruction::{validate_pda_seeds, InstructionVariantFields};
 use crate::parsed_enum::ParsedEnum;
+use crate::parsed_struct::CratePaths;
 use crate::parsers::get_derive_attr;
 use crate::DERIVE_BUILDER_ATTR;
 use crate::{
@@ -21,6 +22,7 @@ use super::{BuilderArgument, BuilderArguments};
 pub struct Builder {
     pub ident: Ident,
     pub variants: Vec<BuilderVariant>,
+    pub crate_paths: CratePaths,
 }
 
 impl Builder {
@@ -57,7 +59,10 @@ impl TryFrom<&ParsedEnum> for Builder {
 
     fn try_from(parsed_enum: &ParsedEnum) -> ParseResult<Self> {
         let ParsedEnum {
-            ident, variants, ..
+            ident,
+            variants,
+            struct_attrs,
+            ..
         } = parsed_enum;
 
         let variants = variants
@@ -67,6 +72,7 @@ impl TryFrom<&ParsedEnum> for Builder {
         Ok(Self {
             ident: ident.clone(),
             variants,
+            crate_paths: struct_attrs.crate_paths(),
         })
     }
 }
@@ -121,6 +127,7 @@ impl TryFrom<&ParsedEnumVariant> for BuilderVariant {
         let attrs: &[Attribute] = attrs.as_ref();
         let accounts: InstructionAccounts = attrs.try_into()?;
         let arguments: BuilderArguments = attrs.try_into()?;
+        validate_pda_seeds(ident, &accounts.0, &field_tys)?;
 
         Ok(Self {
             ident: ident.clone(),
@@ -19,8 +19,8 @@ fn assert_context_variant(
     variant: &BuilderVariant,
     name: &str,
     expected_discriminant: usize,
-    expected_field_tys: &Vec<RustType>,
-    expected_args: &Vec<BuilderArgument>,
+    expected_field_tys: &[RustType],
+    expected_args: &[BuilderArgument],
     accounts_len: usize,
 ) {
     let BuilderVariant {
@@ -122,8 +122,8 @@ fn parse_c_style_instruction_with_context() {
         &parsed.variants[0],
         "CreateThing",
         0,
-        &vec![],
-        &vec![
+        &[],
+        &[
             BuilderArgument {
                 name: String::from("first_arg"),
                 ty: String::from("u64"),
@@ -141,8 +141,8 @@ fn parse_c_style_instruction_with_context() {
         &parsed.variants[1],
         "CloseThing",
         1,
-        &vec![],
-        &vec![BuilderArgument {
+        &[],
+        &[BuilderArgument {
             name: String::from("composite_arg"),
             ty: String::from("Vec"),
             generic_ty: Some(String::from("u64")),
@@ -1,8 +1,9 @@
 use anyhow::Error;
 use syn::Error as ParseError;
 
+pub const PARSE_ERROR_HINT: &str = "[ParseError] Run `cargo build` or `cargo check` in the program crate root for more details.";
+
 pub fn parse_error_into<T: Into<ParseError>>(parse_err: T) -> Error {
     let parse_err: ParseError = parse_err.into();
-    Error::new(parse_err)
-        .context("[ParseError] Run `cargo build` or `cargo check` in the program crate root for more details.".to_string())
+    Error::new(parse_err).context(PARSE_ERROR_HINT.to_string())
 }
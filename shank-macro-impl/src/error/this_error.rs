@@ -1,9 +1,9 @@
 use std::convert::TryFrom;
 
-use anyhow::{format_err, Result};
+use anyhow::Result;
 
 use crate::{parsed_enum::ParsedEnum, parsers::get_derive_attr};
-use syn::Result as ParseResult;
+use syn::{Error as ParseError, Result as ParseResult};
 
 use super::{ProgramError, ProgramErrors, DERIVE_THIS_ERROR_ATTR};
 
@@ -25,10 +25,12 @@ fn extract_this_error_enums<'a>(
 
     for x in filter_this_error_enums(enums) {
         let enm = ParsedEnum::try_from(x).map_err(|err| {
-            format_err!(
-                "Encountered an error parsing {} this_error enum.\n{}",
-                x.ident,
-                err
+            ParseError::new(
+                err.span(),
+                format!(
+                    "Encountered an error parsing {} this_error enum.\n{}",
+                    x.ident, err
+                ),
             )
         })?;
         error_enums.push(enm);
@@ -1,13 +1,68 @@
 use std::convert::TryFrom;
 
 use proc_macro2::Span;
+use quote::quote;
 use syn::{
-    punctuated::Punctuated, Attribute, Error as ParseError, Ident, Lit, Meta,
-    MetaList, MetaNameValue, NestedMeta, Result as ParseResult, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Error as ParseError, Ident, Lit, Meta, MetaList, MetaNameValue,
+    NestedMeta, Path, Result as ParseResult, Token,
 };
 
 const IX_ACCOUNT: &str = "account";
 
+/// The address an account defaults to when it isn't provided.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountDefault {
+    /// `#[account(default = "spl_token::ID")]`, the path of the pubkey which
+    /// is only known to Rust code.
+    Path(String),
+    /// `#[account(address = "<base58>")]`, the fixed address of the account.
+    Address(String),
+    /// `#[account(pda = Metadata, seeds(mint))]`, the PDA of the `account`
+    /// type derived from the `seeds` which name other accounts or args of the
+    /// instruction.
+    Pda { account: String, seeds: Vec<String> },
+}
+
+impl AccountDefault {
+    /// The bytes of the address, `None` for paths and PDAs.
+    pub fn address_bytes(&self) -> Option<[u8; 32]> {
+        match self {
+            AccountDefault::Address(address) => decode_address(address),
+            AccountDefault::Path(_) | AccountDefault::Pda { .. } => None,
+        }
+    }
+}
+
+pub(crate) fn decode_address(address: &str) -> Option<[u8; 32]> {
+    let mut bytes = [0u8; 32];
+    match bs58::decode(address).onto(&mut bytes) {
+        Ok(32) => Some(bytes),
+        _ => None,
+    }
+}
+
+/// An arg of the `#[account]` attr. `pda = Type` is parsed separately since
+/// its value isn't a literal and thus not a valid [Meta].
+enum AccountAttrArg {
+    Pda(Ident, Path),
+    Meta(NestedMeta),
+}
+
+impl Parse for AccountAttrArg {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let is_pda = input.peek2(Token![=])
+            && matches!(input.fork().parse::<Ident>(), Ok(ident) if ident == "pda");
+        if is_pda {
+            let ident = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            return Ok(AccountAttrArg::Pda(ident, input.parse()?));
+        }
+        Ok(AccountAttrArg::Meta(input.parse()?))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct InstructionAccount {
     pub ident: Ident,
@@ -18,6 +73,7 @@ pub struct InstructionAccount {
     pub optional_signer: bool,
     pub desc: Option<String>,
     pub optional: bool,
+    pub default: Option<AccountDefault>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -38,15 +94,30 @@ impl InstructionAccount {
     pub fn from_account_attr(
         attr: &Attribute,
     ) -> ParseResult<InstructionAccount> {
-        let meta = &attr.parse_meta()?;
+        let account = Self::from_account_attr_unvalidated(attr)?;
+        account.validate()?;
+        Ok(account)
+    }
+
+    /// Parses the account without validating that its flags can be combined.
+    /// Used by `shank lint` in order to report such accounts.
+    pub fn from_account_attr_unvalidated(
+        attr: &Attribute,
+    ) -> ParseResult<InstructionAccount> {
+        let ident = attr.path.get_ident().map_or_else(
+            || Ident::new("attr_ident", Span::call_site()),
+            |x| x.clone(),
+        );
+        let meta = match attr.parse_meta() {
+            Ok(meta) => meta,
+            Err(err) => {
+                return Self::parse_account_attr_with_pda(ident, attr, err)
+            }
+        };
 
         match meta {
             Meta::List(MetaList { nested, .. }) => {
-                let ident = attr.path.get_ident().map_or_else(
-                    || Ident::new("attr_ident", Span::call_site()),
-                    |x| x.clone(),
-                );
-                Self::parse_account_attr_args(ident, nested)
+                Self::parse_account_attr_args(ident, &nested, None)
             }
             Meta::Path(_) | Meta::NameValue(_) => Err(ParseError::new_spanned(
                 attr,
@@ -55,9 +126,40 @@ impl InstructionAccount {
         }
     }
 
+    /// Parses an `#[account]` attr which isn't a valid [Meta] since it
+    /// contains `pda = Type`, failing with the `meta_err` otherwise.
+    fn parse_account_attr_with_pda(
+        ident: Ident,
+        attr: &Attribute,
+        meta_err: ParseError,
+    ) -> ParseResult<InstructionAccount> {
+        let args = attr
+            .parse_args_with(
+                Punctuated::<AccountAttrArg, Token![,]>::parse_terminated,
+            )
+            .map_err(|_| meta_err)?;
+
+        let mut nested = Punctuated::<NestedMeta, Token![,]>::new();
+        let mut pda = None;
+        for arg in args {
+            match arg {
+                AccountAttrArg::Meta(meta) => nested.push(meta),
+                AccountAttrArg::Pda(ident, _) if pda.is_some() => {
+                    return Err(ParseError::new_spanned(
+                        ident,
+                        "Only one pda can be assigned",
+                    ))
+                }
+                AccountAttrArg::Pda(ident, path) => pda = Some((ident, path)),
+            }
+        }
+        Self::parse_account_attr_args(ident, &nested, pda)
+    }
+
     fn parse_account_attr_args(
         ident: Ident,
         nested: &Punctuated<NestedMeta, Token![,]>,
+        pda: Option<(Ident, Path)>,
     ) -> ParseResult<InstructionAccount> {
         if nested.is_empty() {
             return Err(ParseError::new_spanned(
@@ -73,6 +175,8 @@ impl InstructionAccount {
         let mut desc = None;
         let mut account_name = None;
         let mut optional = false;
+        let mut default = None;
+        let mut seeds: Option<(&MetaList, Vec<String>)> = None;
 
         for meta in nested {
             if let Some((ident, name, value)) =
@@ -88,9 +192,33 @@ impl InstructionAccount {
                         ))
                     }
                     "name" => account_name = Some(value),
+                    "default" | "address" if default.is_some() => {
+                        return Err(ParseError::new_spanned(
+                            ident,
+                            "Only one of default, address or pda can be assigned",
+                        ))
+                    }
+                    "default" => {
+                        if syn::parse_str::<Path>(&value).is_err() {
+                            return Err(ParseError::new_spanned(
+                                ident,
+                                "default needs to be the path of a pubkey, i.e. \"spl_token::ID\"",
+                            ));
+                        }
+                        default = Some(AccountDefault::Path(value));
+                    }
+                    "address" => {
+                        if decode_address(&value).is_none() {
+                            return Err(ParseError::new_spanned(
+                                ident,
+                                "address needs to be a base58 encoded pubkey",
+                            ));
+                        }
+                        default = Some(AccountDefault::Address(value));
+                    }
                     _ => return Err(ParseError::new_spanned(
                         ident,
-                        "Only desc/description or name can be assigned strings",
+                        "Only desc/description, name, default or address can be assigned strings",
                     )),
                 };
             } else if let Some((ident, name)) =
@@ -111,6 +239,26 @@ impl InstructionAccount {
                         ));
                     }
                 };
+            } else if let NestedMeta::Meta(Meta::List(list)) = meta {
+                // seeds of the pda
+                if !list.path.is_ident("seeds") {
+                    return Err(ParseError::new_spanned(
+                        &list.path,
+                        "Invalid/unknown account meta configuration",
+                    ));
+                }
+                let names = list
+                    .nested
+                    .iter()
+                    .map(|seed| match identifier_from_nested_meta(seed) {
+                        Some((_, name)) => Ok(name),
+                        None => Err(ParseError::new_spanned(
+                            seed,
+                            "pda seeds need to be names of accounts or args of the instruction",
+                        )),
+                    })
+                    .collect::<ParseResult<Vec<String>>>()?;
+                seeds = Some((list, names));
             } else {
                 // account index (optional)
                 match meta {
@@ -126,12 +274,28 @@ impl InstructionAccount {
                 }
             }
         }
-        if signer && optional_signer {
-            return Err(ParseError::new_spanned(
-                ident,
-                "Account cannot be both signer and optional_signer",
-            ));
+        match (pda, seeds) {
+            (Some((ident, _)), _) if default.is_some() => {
+                return Err(ParseError::new_spanned(
+                    ident,
+                    "Only one of default, address or pda can be assigned",
+                ))
+            }
+            (Some((_, path)), seeds) => {
+                default = Some(AccountDefault::Pda {
+                    account: quote! { #path }.to_string().replace(' ', ""),
+                    seeds: seeds.map(|(_, names)| names).unwrap_or_default(),
+                });
+            }
+            (None, Some((list, _))) => {
+                return Err(ParseError::new_spanned(
+                    list,
+                    "seeds can only be provided along with a pda",
+                ))
+            }
+            (None, None) => {}
         }
+
         match account_name {
             Some(name) => Ok(Self {
                 ident,
@@ -142,12 +306,29 @@ impl InstructionAccount {
                 optional_signer,
                 desc,
                 optional,
+                default,
             }),
             None => {
                 Err(ParseError::new_spanned(nested, "Missing account name"))
             }
         }
     }
+
+    fn validate(&self) -> ParseResult<()> {
+        if self.signer && self.optional_signer {
+            return Err(ParseError::new_spanned(
+                &self.ident,
+                "Account cannot be both signer and optional_signer",
+            ));
+        }
+        if self.optional && self.default.is_some() {
+            return Err(ParseError::new_spanned(
+                &self.ident,
+                "Account cannot be both optional and have a default address",
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<&[Attribute]> for InstructionAccounts {
@@ -164,6 +345,19 @@ impl TryFrom<&[Attribute]> for InstructionAccounts {
     }
 }
 
+impl InstructionAccounts {
+    /// Parses all `#[account]` attrs via [InstructionAccount::from_account_attr_unvalidated].
+    pub fn from_attrs_unvalidated(attrs: &[Attribute]) -> ParseResult<Self> {
+        let accounts = attrs
+            .iter()
+            .filter_map(InstructionAccount::is_account_attr)
+            .map(InstructionAccount::from_account_attr_unvalidated)
+            .collect::<ParseResult<Vec<InstructionAccount>>>()?;
+
+        Ok(InstructionAccounts(accounts))
+    }
+}
+
 // -----------------
 // Meta Extractors
 // -----------------
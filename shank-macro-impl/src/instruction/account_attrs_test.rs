@@ -7,7 +7,7 @@ use syn::ItemEnum;
 
 use syn::{Attribute, Result as ParseResult};
 
-use crate::instruction::account_attrs::InstructionAccount;
+use crate::instruction::account_attrs::{AccountDefault, InstructionAccount};
 
 use super::account_attrs::InstructionAccounts;
 
@@ -377,6 +377,158 @@ fn account_invalid_empty_name() {
         Err(err) if err.to_string().contains("account name cannot be empty"));
 }
 
+#[test]
+fn account_default_and_address() {
+    let accounts = parse_first_enum_variant_attrs(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instructions {
+            #[account(0, name = "token_program", default = "spl_token::ID")]
+            #[account(1, name = "metadata_program", address = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")]
+            #[account(2, name = "authority", sig)]
+            Indexed
+        }
+    })
+    .expect("Should parse fine");
+
+    let defaults = accounts
+        .0
+        .iter()
+        .map(|account| account.default.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        defaults,
+        vec![
+            Some(AccountDefault::Path("spl_token::ID".to_string())),
+            Some(AccountDefault::Address(
+                "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s".to_string()
+            )),
+            None,
+        ]
+    );
+    assert!(defaults[1].as_ref().unwrap().address_bytes().is_some());
+}
+
+#[test]
+fn account_invalid_default_and_address() {
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "token_program", address = "not-base58")]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("address needs to be a base58 encoded pubkey"));
+
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "token_program", default = "spl token")]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("default needs to be the path of a pubkey"));
+
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "token_program", default = "spl_token::ID", address = "11111111111111111111111111111111")]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("Only one of default, address or pda"));
+
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "token_program", optional, default = "spl_token::ID")]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("cannot be both optional and have a default address"));
+}
+
+#[test]
+fn account_pda() {
+    let accounts = parse_first_enum_variant_attrs(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instructions {
+            #[account(0, writable, name = "metadata", pda = state::Metadata, seeds(mint, update_authority))]
+            #[account(1, name = "mint")]
+            #[account(2, name = "update_authority", sig)]
+            Indexed
+        }
+    })
+    .expect("Should parse fine");
+
+    let metadata = &accounts.0[0];
+    assert_instruction_account_matches(
+        metadata,
+        InstructionAccountWithoutIdent {
+            index: Some(0),
+            name: "metadata".to_string(),
+            writable: true,
+            signer: false,
+            desc: None,
+            optional: false,
+        },
+    );
+    assert_eq!(
+        metadata.default,
+        Some(AccountDefault::Pda {
+            account: "state::Metadata".to_string(),
+            seeds: vec!["mint".to_string(), "update_authority".to_string()],
+        })
+    );
+    assert_eq!(metadata.default.as_ref().unwrap().address_bytes(), None);
+}
+
+#[test]
+fn account_invalid_pda() {
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "metadata", seeds(mint))]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("seeds can only be provided along with a pda"));
+
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "metadata", pda = Metadata, seeds("mint"))]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("pda seeds need to be names of accounts or args"));
+
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "metadata", pda = Metadata, address = "11111111111111111111111111111111")]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("Only one of default, address or pda"));
+
+    assert_matches!(
+        parse_first_enum_variant_attrs(quote! {
+            #[derive(ShankInstruction)]
+            pub enum Instructions {
+                #[account(name = "metadata", optional, pda = Metadata, seeds(mint))]
+                NotIndexed
+            }
+        }),
+        Err(err) if err.to_string().contains("cannot be both optional and have a default"));
+}
+
 #[test]
 
 fn account_valid_sparse_indexes() {
@@ -104,7 +104,7 @@ mod tests {
         assert!(res
             .unwrap_err()
             .to_string()
-            .contains("Only desc/description or name"));
+            .contains("Only desc/description, name"));
     }
 
     #[test]
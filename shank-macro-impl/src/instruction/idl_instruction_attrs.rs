@@ -98,6 +98,7 @@ impl IdlInstruction {
                 optional_signer: false,
                 writable: true,
                 optional: false,
+                default: None,
             }, InstructionAccount {
                 ident: ident.clone(),
                 index: Some(1),
@@ -107,6 +108,7 @@ impl IdlInstruction {
                 optional_signer: false,
                 writable: true,
                 optional: false,
+                default: None,
             }, InstructionAccount {
                 ident: ident.clone(),
                 index: Some(2),
@@ -116,6 +118,7 @@ impl IdlInstruction {
                 optional_signer: false,
                 writable: false,
                 optional: false,
+                default: None,
             }, InstructionAccount {
                 ident: ident.clone(),
                 index: Some(3),
@@ -125,6 +128,7 @@ impl IdlInstruction {
                 optional_signer: false,
                 writable: false,
                 optional: false,
+                default: None,
             }, InstructionAccount {
                 ident,
                 index: Some(4),
@@ -134,6 +138,7 @@ impl IdlInstruction {
                 optional_signer: false,
                 writable: false,
                 optional: false,
+                default: None,
             }]),
             IdlInstruction::CreateBuffer =>  
                 InstructionAccounts(vec![InstructionAccount {
@@ -145,6 +150,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: true,
                         optional: false,
+                        default: None,
                     }, InstructionAccount {
                         ident,
                         index: Some(1),
@@ -154,6 +160,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: false,
                         optional: false,
+                        default: None,
                     }]),
             IdlInstruction::SetBuffer => 
                 InstructionAccounts(vec![InstructionAccount {
@@ -165,6 +172,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: true,
                         optional: false,
+                        default: None,
                     }, InstructionAccount {
                         ident: ident.clone(),
                         index: Some(1),
@@ -174,6 +182,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: true,
                         optional: false,
+                        default: None,
                     }, InstructionAccount {
                         ident,
                         index: Some(2),
@@ -183,6 +192,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: false,
                         optional: false,
+                        default: None,
                     }]),
             IdlInstruction::SetAuthority | IdlInstruction::Write => 
                 InstructionAccounts(vec![InstructionAccount {
@@ -194,6 +204,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: true,
                         optional: false,
+                        default: None,
                     }, InstructionAccount {
                         ident,
                         index: Some(2),
@@ -203,6 +214,7 @@ impl IdlInstruction {
                         optional_signer: false,
                         writable: false,
                         optional: false,
+                        default: None,
                     }]),
         }
     }
@@ -8,14 +8,15 @@ use syn::Ident;
 
 use crate::{
     parsed_enum::{ParsedEnum, ParsedEnumVariant},
+    parsed_struct::CratePaths,
     parsers::get_derive_attr,
     types::RustType,
     DERIVE_INSTRUCTION_ATTR,
 };
 
 use super::{
-    account_attrs::InstructionAccount, IdlInstruction, InstructionStrategies,
-    InstructionStrategy,
+    account_attrs::{AccountDefault, InstructionAccount},
+    IdlInstruction, InstructionStrategies, InstructionStrategy,
 };
 
 // -----------------
@@ -25,6 +26,16 @@ use super::{
 pub struct Instruction {
     pub ident: Ident,
     pub variants: Vec<InstructionVariant>,
+    pub crate_paths: CratePaths,
+    /// Whether `unpack` and the `Processor` dispatch are derived, see
+    /// `#[shank(processor)]`.
+    pub processor: bool,
+    /// Whether `data`, `discriminant` and the discriminant consts are derived,
+    /// see `#[shank(instruction_data)]`.
+    pub instruction_data: bool,
+    /// Whether the contexts derive `context_checked` in addition to `context`,
+    /// see `#[shank(context_checked)]`.
+    pub context_checked: bool,
 }
 
 impl Instruction {
@@ -62,16 +73,35 @@ impl TryFrom<&ParsedEnum> for Instruction {
 
     fn try_from(parsed_enum: &ParsedEnum) -> ParseResult<Self> {
         let ParsedEnum {
-            ident, variants, ..
+            ident,
+            variants,
+            struct_attrs,
+            ..
         } = parsed_enum;
 
-        let variants = variants
-            .iter()
-            .map(InstructionVariant::try_from)
-            .collect::<ParseResult<Vec<InstructionVariant>>>()?;
+        // Report problems with all variants at once instead of only the first
+        let mut errors: Option<ParseError> = None;
+        let mut instruction_variants = Vec::with_capacity(variants.len());
+        for variant in variants {
+            match InstructionVariant::try_from(variant) {
+                Ok(variant) => instruction_variants.push(variant),
+                Err(err) => match errors.as_mut() {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                },
+            }
+        }
+        if let Some(errors) = errors {
+            return Err(errors);
+        }
+        let variants = instruction_variants;
         Ok(Self {
             ident: ident.clone(),
             variants,
+            crate_paths: struct_attrs.crate_paths(),
+            processor: struct_attrs.is_processor(),
+            instruction_data: struct_attrs.is_instruction_data(),
+            context_checked: struct_attrs.is_context_checked(),
         })
     }
 }
@@ -156,6 +186,8 @@ impl TryFrom<&ParsedEnumVariant> for InstructionVariant {
             }
         }
 
+        validate_pda_seeds(ident, &accounts.0, &field_tys)?;
+
         Ok(Self {
             ident: ident.clone(),
             field_tys,
@@ -165,3 +197,49 @@ impl TryFrom<&ParsedEnumVariant> for InstructionVariant {
         })
     }
 }
+
+/// Validates that the seeds of pda accounts name other accounts or named args
+/// of the instruction variant.
+pub(crate) fn validate_pda_seeds(
+    ident: &Ident,
+    accounts: &[InstructionAccount],
+    field_tys: &InstructionVariantFields,
+) -> ParseResult<()> {
+    for account in accounts {
+        let seeds = match &account.default {
+            Some(AccountDefault::Pda { seeds, .. }) => seeds,
+            _ => continue,
+        };
+        for seed in seeds {
+            let seed_account = accounts.iter().find(|other| {
+                &other.name == seed && other.name != account.name
+            });
+            let is_arg = matches!(
+                field_tys,
+                InstructionVariantFields::Named(fields) if fields.iter().any(|(name, _)| name == seed)
+            );
+            match seed_account {
+                Some(seed_account) if seed_account.optional => {
+                    return Err(ParseError::new_spanned(
+                        &account.ident,
+                        format!(
+                            "pda seed '{}' of account '{}' cannot be an optional account",
+                            seed, account.name
+                        ),
+                    ))
+                }
+                None if !is_arg => {
+                    return Err(ParseError::new_spanned(
+                        &account.ident,
+                        format!(
+                            "pda seed '{}' of account '{}' is neither an account nor a named arg of instruction variant '{}'",
+                            seed, account.name, ident
+                        ),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
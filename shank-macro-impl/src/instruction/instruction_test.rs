@@ -4,6 +4,7 @@ use syn::{ItemEnum, Result as ParseResult};
 
 use crate::{
     instruction::InstructionVariantFields,
+    parsed_struct::{CratePaths, CratePathsPreset},
     types::{Primitive, RustType},
 };
 
@@ -19,7 +20,7 @@ fn assert_instruction_variant(
     variant: &InstructionVariant,
     name: &str,
     expected_discriminant: usize,
-    expected_field_tys: &Vec<RustType>,
+    expected_field_tys: &[RustType],
     accounts_len: usize,
 ) {
     let InstructionVariant {
@@ -97,14 +98,14 @@ fn parse_c_style_instruction() {
         &parsed.variants[0],
         "CreateThing",
         0,
-        &vec![],
+        &[],
         2,
     );
     assert_instruction_variant(
         &parsed.variants[1],
         "CloseThing",
         1,
-        &vec![],
+        &[],
         1,
     );
 }
@@ -129,14 +130,14 @@ fn parse_custom_field_variant_instruction() {
         &parsed.variants[0],
         "CreateThing",
         0,
-        &vec![],
+        &[],
         0,
     );
     assert_instruction_variant(
         &parsed.variants[1],
         "CloseThing",
         1,
-        &vec![RustType::owned_custom_value("CloseArgs", "CloseArgs")],
+        &[RustType::owned_custom_value("CloseArgs", "CloseArgs")],
         1,
     );
 }
@@ -162,14 +163,14 @@ fn parse_u8_field_variant_instruction() {
         &parsed.variants[0],
         "CreateThing",
         0,
-        &vec![],
+        &[],
         1,
     );
     assert_instruction_variant(
         &parsed.variants[1],
         "CloseThing",
         1,
-        &vec![RustType::owned_primitive("u8", Primitive::U8)],
+        &[RustType::owned_primitive("u8", Primitive::U8)],
         1,
     );
 }
@@ -207,6 +208,79 @@ fn parse_non_instruction_enum() {
     );
 }
 
+#[test]
+fn parse_instruction_crate_paths() {
+    let instruction = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instruction {
+            #[account(0, name = "creator", sig)]
+            CreateThing,
+        }
+    })
+    .expect("Should parse fine")
+    .unwrap();
+    assert_eq!(instruction.crate_paths, CratePaths::default());
+
+    let instruction = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        #[shank(pinocchio)]
+        pub enum Instruction {
+            #[account(0, name = "creator", sig)]
+            CreateThing,
+        }
+    })
+    .expect("Should parse fine")
+    .unwrap();
+    assert_eq!(
+        instruction.crate_paths,
+        CratePaths::from_preset(CratePathsPreset::Pinocchio)
+    );
+
+    let err = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        #[shank(pinocchio, crate_paths(preset = "solana_program"))]
+        pub enum Instruction {
+            #[account(0, name = "creator", sig)]
+            CreateThing,
+        }
+    })
+    .expect_err("Should fail to parse conflicting crate paths");
+    assert_eq!(
+        err.to_string(),
+        "Only one #[shank(crate_paths)] allowed per type"
+    );
+}
+
+#[test]
+fn parse_instruction_processor_instruction_data_and_context_checked() {
+    let instruction = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instruction {
+            #[account(0, name = "creator", sig)]
+            CreateThing,
+        }
+    })
+    .expect("Should parse fine")
+    .unwrap();
+    assert!(!instruction.processor);
+    assert!(!instruction.instruction_data);
+    assert!(!instruction.context_checked);
+
+    let instruction = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        #[shank(pinocchio, processor, instruction_data, context_checked)]
+        pub enum Instruction {
+            #[account(0, name = "creator", sig)]
+            CreateThing,
+        }
+    })
+    .expect("Should parse fine")
+    .unwrap();
+    assert!(instruction.processor);
+    assert!(instruction.instruction_data);
+    assert!(instruction.context_checked);
+}
+
 // duplicate index tests
 #[test]
 fn fail_duplicate_account_indices() {
@@ -240,3 +314,75 @@ fn fail_duplicate_account_indices_mixed() {
     let err = result.err().unwrap();
     assert_eq!(err.to_string(), "Duplicate account index 0 found in instruction variant 'CreateThing'");
 }
+
+#[test]
+fn fail_multiple_invalid_variants_reports_all() {
+    let result = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instruction {
+            #[account(0, name = "creator", sg)]
+            CreateThing,
+            #[account(0, name = "closer", sig)]
+            #[account(0, name = "thing", mut)]
+            CloseThing,
+        }
+    });
+
+    let errors = result
+        .expect_err("should fail")
+        .into_iter()
+        .map(|err| err.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(
+        errors,
+        vec![
+            "Invalid/unknown account meta configuration".to_string(),
+            "Duplicate account index 0 found in instruction variant 'CloseThing'"
+                .to_string()
+        ]
+    );
+}
+
+// pda seed tests
+#[test]
+fn parse_pda_account_with_account_and_arg_seeds() {
+    let result = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instruction {
+            #[account(0, name = "metadata", mut, pda = Metadata, seeds(mint, edition))]
+            #[account(1, name = "mint")]
+            CreateMetadata { edition: u64 },
+        }
+    });
+
+    assert!(result.is_ok(), "Should accept seeds naming accounts and args");
+}
+
+#[test]
+fn fail_pda_account_with_unknown_seed() {
+    let result = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instruction {
+            #[account(0, name = "metadata", mut, pda = Metadata, seeds(mint))]
+            CreateMetadata,
+        }
+    });
+
+    let err = result.err().unwrap();
+    assert_eq!(err.to_string(), "pda seed 'mint' of account 'metadata' is neither an account nor a named arg of instruction variant 'CreateMetadata'");
+}
+
+#[test]
+fn fail_pda_account_with_optional_seed() {
+    let result = parse_instruction(quote! {
+        #[derive(ShankInstruction)]
+        pub enum Instruction {
+            #[account(0, name = "metadata", mut, pda = Metadata, seeds(mint))]
+            #[account(1, name = "mint", optional)]
+            CreateMetadata,
+        }
+    });
+
+    let err = result.err().unwrap();
+    assert_eq!(err.to_string(), "pda seed 'mint' of account 'metadata' cannot be an optional account");
+}
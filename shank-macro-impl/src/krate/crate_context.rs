@@ -13,19 +13,19 @@ pub struct CrateContext {
 
 impl CrateContext {
     pub fn consts(&self) -> impl Iterator<Item = &syn::ItemConst> {
-        self.modules.iter().flat_map(|(_, ctx)| ctx.consts())
+        self.modules.values().flat_map(|ctx| ctx.consts())
     }
 
     pub fn structs(&self) -> impl Iterator<Item = &syn::ItemStruct> {
-        self.modules.iter().flat_map(|(_, ctx)| ctx.structs())
+        self.modules.values().flat_map(|ctx| ctx.structs())
     }
 
     pub fn enums(&self) -> impl Iterator<Item = &syn::ItemEnum> {
-        self.modules.iter().flat_map(|(_, ctx)| ctx.enums())
+        self.modules.values().flat_map(|ctx| ctx.enums())
     }
 
     pub fn macros(&self) -> impl Iterator<Item = &syn::ItemMacro> {
-        self.modules.iter().flat_map(|(_, ctx)| ctx.macros())
+        self.modules.values().flat_map(|ctx| ctx.macros())
     }
 
     pub fn modules(&self) -> impl Iterator<Item = ModuleContext<'_>> {
@@ -39,13 +39,13 @@ impl CrateContext {
     }
 
     pub fn all_items(&self) -> impl Iterator<Item = &syn::Item> {
-        self.modules.iter().flat_map(|(_, ctx)| ctx.all_items())
+        self.modules.values().flat_map(|ctx| ctx.all_items())
     }
 
     pub fn all_items_vec(&self) -> Vec<syn::Item> {
         self.modules
-            .iter()
-            .flat_map(|(_, ctx)| ctx.all_items())
+            .values()
+            .flat_map(|ctx| ctx.all_items())
             .cloned()
             .collect()
     }
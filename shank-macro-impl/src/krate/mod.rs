@@ -2,3 +2,4 @@ mod crate_context;
 mod module_context;
 
 pub use crate_context::*;
+pub use module_context::*;
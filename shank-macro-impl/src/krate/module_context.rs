@@ -18,6 +18,23 @@ impl<'krate> ModuleContext<'krate> {
     pub fn items(&self) -> impl Iterator<Item = &syn::Item> {
         self.detail.items.iter()
     }
+
+    /// The source file containing the items of this module.
+    pub fn file(&self) -> &'krate Path {
+        &self.detail.file
+    }
+
+    pub fn structs(&self) -> impl Iterator<Item = &'krate syn::ItemStruct> {
+        self.detail.structs()
+    }
+
+    pub fn enums(&self) -> impl Iterator<Item = &'krate syn::ItemEnum> {
+        self.detail.enums()
+    }
+
+    pub fn macros(&self) -> impl Iterator<Item = &'krate syn::ItemMacro> {
+        self.detail.macros()
+    }
 }
 
 #[derive(Debug)]
@@ -11,6 +11,7 @@ pub mod parsed_macro;
 pub mod parsed_struct;
 pub mod parsers;
 pub mod types;
+pub mod well_known;
 
 pub const DERIVE_ACCOUNT_ATTR: &str = "ShankAccount";
 pub const DERIVE_CONTEXT_ATTR: &str = "ShankContext";
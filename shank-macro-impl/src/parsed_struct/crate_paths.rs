@@ -0,0 +1,282 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{
+    punctuated::Punctuated, token::Comma, Error as ParseError, Lit, LitStr,
+    Meta, MetaNameValue, NestedMeta, Path, Result as ParseResult,
+};
+
+const CRATE_PATHS_FORMATS: &str = r##"Examples of supported crate paths:
+#[shank(crate_paths(preset = "solana_program"))]
+#[shank(crate_paths(preset = "solana_sdk_split"))]
+#[shank(crate_paths(preset = "pinocchio"))]
+#[shank(crate_paths(pubkey = "::my_sdk::Pubkey", program_error = "::my_sdk::ProgramError"))]
+
+Supported paths: pubkey, find_program_address, create_program_address,
+pubkey_error, account_info, program_error, instruction, account_meta,
+invoke_signed, system_program, sysvar_instructions"##;
+
+// -----------------
+// CratePathsPreset
+// -----------------
+/// The set of crates the generated code targets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CratePathsPreset {
+    /// The `solana-program` crate.
+    #[default]
+    SolanaProgram,
+    /// The split SDK crates, i.e. `solana-pubkey` and `solana-account-info`.
+    SolanaSdkSplit,
+    /// The `pinocchio` crate whose `AccountInfo` exposes accessor methods.
+    ///
+    /// Not supported by `ShankBuilder` and thus the instruction, account meta
+    /// and invoke paths are never rendered.
+    Pinocchio,
+}
+
+impl CratePathsPreset {
+    fn from_lit(lit: &LitStr) -> ParseResult<Self> {
+        match lit.value().as_str() {
+            "solana_program" => Ok(Self::SolanaProgram),
+            "solana_sdk_split" => Ok(Self::SolanaSdkSplit),
+            "pinocchio" => Ok(Self::Pinocchio),
+            _ => Err(ParseError::new_spanned(
+                lit,
+                format!("Unknown crate paths preset\n{}", CRATE_PATHS_FORMATS),
+            )),
+        }
+    }
+}
+
+// -----------------
+// CratePaths
+// -----------------
+/// The paths of the Solana types and ids the derived code refers to,
+/// configured via `#[shank(crate_paths(..))]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CratePaths {
+    pub preset: CratePathsPreset,
+    pub pubkey: Path,
+    /// The fn deriving a PDA from its seeds and the program id.
+    pub find_program_address: Path,
+    /// The fn creating a PDA from its seeds including the bump and the
+    /// program id.
+    pub create_program_address: Path,
+    /// The error returned by `create_program_address`.
+    pub pubkey_error: Path,
+    pub account_info: Path,
+    pub program_error: Path,
+    pub instruction: Path,
+    pub account_meta: Path,
+    /// The fn invoking another program with the provided signer seeds.
+    pub invoke_signed: Path,
+    /// The id of the system program.
+    pub system_program: Path,
+    /// The id of the instructions sysvar.
+    pub sysvar_instructions: Path,
+}
+
+fn path(path: &str) -> Path {
+    syn::parse_str(path).expect("preset paths should be valid")
+}
+
+impl CratePaths {
+    pub fn from_preset(preset: CratePathsPreset) -> Self {
+        match preset {
+            CratePathsPreset::SolanaProgram => Self {
+                preset,
+                pubkey: path("::solana_program::pubkey::Pubkey"),
+                find_program_address: path(
+                    "::solana_program::pubkey::Pubkey::find_program_address",
+                ),
+                create_program_address: path(
+                    "::solana_program::pubkey::Pubkey::create_program_address",
+                ),
+                pubkey_error: path("::solana_program::pubkey::PubkeyError"),
+                account_info: path(
+                    "::solana_program::account_info::AccountInfo",
+                ),
+                program_error: path(
+                    "::solana_program::program_error::ProgramError",
+                ),
+                instruction: path("::solana_program::instruction::Instruction"),
+                account_meta: path(
+                    "::solana_program::instruction::AccountMeta",
+                ),
+                invoke_signed: path("::solana_program::program::invoke_signed"),
+                system_program: path("::solana_program::system_program::ID"),
+                sysvar_instructions: path(
+                    "::solana_program::sysvar::instructions::ID",
+                ),
+            },
+            CratePathsPreset::SolanaSdkSplit => Self {
+                preset,
+                pubkey: path("::solana_pubkey::Pubkey"),
+                find_program_address: path(
+                    "::solana_pubkey::Pubkey::find_program_address",
+                ),
+                create_program_address: path(
+                    "::solana_pubkey::Pubkey::create_program_address",
+                ),
+                pubkey_error: path("::solana_pubkey::PubkeyError"),
+                account_info: path("::solana_account_info::AccountInfo"),
+                program_error: path("::solana_program_error::ProgramError"),
+                instruction: path("::solana_instruction::Instruction"),
+                account_meta: path("::solana_instruction::AccountMeta"),
+                invoke_signed: path("::solana_cpi::invoke_signed"),
+                system_program: path("::solana_sdk_ids::system_program::ID"),
+                sysvar_instructions: path(
+                    "::solana_sdk_ids::sysvar::instructions::ID",
+                ),
+            },
+            CratePathsPreset::Pinocchio => Self {
+                preset,
+                pubkey: path("::pinocchio::pubkey::Pubkey"),
+                find_program_address: path(
+                    "::pinocchio::pubkey::find_program_address",
+                ),
+                create_program_address: path(
+                    "::pinocchio::pubkey::create_program_address",
+                ),
+                pubkey_error: path("::pinocchio::program_error::ProgramError"),
+                account_info: path("::pinocchio::account_info::AccountInfo"),
+                program_error: path("::pinocchio::program_error::ProgramError"),
+                instruction: path("::pinocchio::instruction::Instruction"),
+                account_meta: path("::pinocchio::instruction::AccountMeta"),
+                invoke_signed: path("::pinocchio::cpi::invoke_signed"),
+                system_program: path("::pinocchio_system::ID"),
+                sysvar_instructions: path(
+                    "::pinocchio::sysvars::instructions::INSTRUCTIONS_ID",
+                ),
+            },
+        }
+    }
+
+    /// Parses the options of `#[shank(crate_paths(..))]`, applying the paths
+    /// provided explicitly on top of the preset.
+    pub fn try_from_nested(
+        nested: &Punctuated<NestedMeta, Comma>,
+    ) -> ParseResult<Self> {
+        let mut options = Vec::with_capacity(nested.len());
+        for arg in nested.iter() {
+            match arg {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.get_ident().is_some() => {
+                    options.push((path.get_ident().unwrap().to_string(), lit))
+                }
+                _ => {
+                    return Err(ParseError::new_spanned(
+                        arg,
+                        format!(
+                            "Invalid crate paths option\n{}",
+                            CRATE_PATHS_FORMATS
+                        ),
+                    ))
+                }
+            }
+        }
+
+        let preset = match options.iter().find(|(name, _)| name == "preset") {
+            Some((_, lit)) => CratePathsPreset::from_lit(lit)?,
+            None => CratePathsPreset::default(),
+        };
+        let mut crate_paths = Self::from_preset(preset);
+        for (name, lit) in options {
+            let target = match name.as_str() {
+                "preset" => continue,
+                "pubkey" => &mut crate_paths.pubkey,
+                "find_program_address" => &mut crate_paths.find_program_address,
+                "create_program_address" => {
+                    &mut crate_paths.create_program_address
+                }
+                "pubkey_error" => &mut crate_paths.pubkey_error,
+                "account_info" => &mut crate_paths.account_info,
+                "program_error" => &mut crate_paths.program_error,
+                "instruction" => &mut crate_paths.instruction,
+                "account_meta" => &mut crate_paths.account_meta,
+                "invoke_signed" => &mut crate_paths.invoke_signed,
+                "system_program" => &mut crate_paths.system_program,
+                "sysvar_instructions" => &mut crate_paths.sysvar_instructions,
+                _ => {
+                    return Err(ParseError::new_spanned(
+                        lit,
+                        format!(
+                            "Unknown crate path '{}'\n{}",
+                            name, CRATE_PATHS_FORMATS
+                        ),
+                    ))
+                }
+            };
+            *target = lit.parse::<Path>().map_err(|_| {
+                ParseError::new_spanned(
+                    lit,
+                    format!("The {} crate path is not a valid path", name),
+                )
+            })?;
+        }
+        Ok(crate_paths)
+    }
+
+    pub fn is_pinocchio(&self) -> bool {
+        self.preset == CratePathsPreset::Pinocchio
+    }
+
+    /// Renders reading the `field` of the provided account info which
+    /// pinocchio exposes via a method of the same name.
+    fn render_account_field(
+        &self,
+        account: &TokenStream,
+        field: &str,
+    ) -> TokenStream {
+        let field = Ident::new(field, Span::call_site());
+        if self.is_pinocchio() {
+            quote! { #account.#field() }
+        } else {
+            quote! { #account.#field }
+        }
+    }
+
+    /// Renders the expression of the key of the provided account info.
+    pub fn render_account_key(&self, account: &TokenStream) -> TokenStream {
+        self.render_account_field(account, "key")
+    }
+
+    /// Renders the expression of whether the provided account info signed.
+    pub fn render_account_is_signer(
+        &self,
+        account: &TokenStream,
+    ) -> TokenStream {
+        self.render_account_field(account, "is_signer")
+    }
+
+    /// Renders the expression of whether the provided account info is
+    /// writable.
+    pub fn render_account_is_writable(
+        &self,
+        account: &TokenStream,
+    ) -> TokenStream {
+        self.render_account_field(account, "is_writable")
+    }
+
+    /// Renders the condition checking that the provided account info is not
+    /// owned by the `owner` program.
+    pub fn render_is_not_owned_by(
+        &self,
+        account: &TokenStream,
+        owner: &TokenStream,
+    ) -> TokenStream {
+        if self.is_pinocchio() {
+            quote! { !#account.is_owned_by(#owner) }
+        } else {
+            quote! { #account.owner != #owner }
+        }
+    }
+}
+
+impl Default for CratePaths {
+    fn default() -> Self {
+        Self::from_preset(CratePathsPreset::default())
+    }
+}
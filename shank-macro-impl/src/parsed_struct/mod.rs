@@ -1,11 +1,13 @@
 use proc_macro2::TokenStream;
 
+mod crate_paths;
 #[allow(clippy::module_inception)]
 mod parsed_struct;
 mod seed;
 mod struct_attr;
 mod struct_field_attr;
 
+pub use crate_paths::*;
 pub use parsed_struct::*;
 pub use seed::*;
 pub use struct_attr::*;
@@ -3,10 +3,11 @@ use std::{
     fmt::Display,
 };
 
+use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Error as ParseError, Field, Ident, ItemStruct,
-    Result as ParseResult,
+    Attribute, Error as ParseError, Field, Ident, ItemStruct, Meta, NestedMeta,
+    Result as ParseResult, Type,
 };
 
 use crate::{parsed_struct::struct_attr::StructAttrs, types::RustType};
@@ -105,6 +106,73 @@ fn field_has_skip_attr(field: &Field) -> bool {
     field.attrs.iter().any(|attr| attr.path.is_ident("skip"))
 }
 
+/// Verifies that the field `#[seeds(.., bump = field)]` refers to is a `u8`
+/// field of the struct.
+fn verify_seeds_bump_field(item: &ItemStruct, bump: &Ident) -> ParseResult<()> {
+    let field = item
+        .fields
+        .iter()
+        .find(|field| field.ident.as_ref() == Some(bump));
+    match field {
+        Some(Field {
+            ty: Type::Path(ty), ..
+        }) if ty.path.is_ident("u8") => Ok(()),
+        Some(field) => Err(ParseError::new_spanned(
+            &field.ty,
+            format!("The seeds bump field '{}' needs to be a u8", bump),
+        )),
+        None => Err(ParseError::new_spanned(
+            bump,
+            format!("The seeds bump '{}' is not a field of the struct", bump),
+        )),
+    }
+}
+
+/// Verifies that a `#[shank(zero_copy)]` struct is `#[repr(C)]` since its
+/// layout is described in the IDL with the offsets of a `repr(C)` struct.
+///
+/// `packed` and `align` modifiers are rejected as well since they change
+/// those offsets.
+fn verify_zero_copy_repr(item: &ItemStruct) -> ParseResult<()> {
+    let modifiers_error = |tokens: &dyn ToTokens| {
+        ParseError::new_spanned(
+            tokens,
+            format!(
+                "The zero_copy struct '{}' needs to be #[repr(C)] without packed or align modifiers",
+                item.ident
+            ),
+        )
+    };
+
+    // The modifiers may be split across attributes, i.e. #[repr(C)] #[repr(packed)]
+    let mut is_repr_c = false;
+    for attr in item.attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let meta = attr.parse_meta()?;
+        let list = match &meta {
+            Meta::List(list) => list,
+            _ => return Err(modifiers_error(&meta)),
+        };
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C") => {
+                    is_repr_c = true
+                }
+                _ => return Err(modifiers_error(nested)),
+            }
+        }
+    }
+    if !is_repr_c {
+        return Err(ParseError::new_spanned(
+            &item.ident,
+            format!(
+                "The zero_copy struct '{}' needs to be #[repr(C)]",
+                item.ident
+            ),
+        ));
+    }
+    Ok(())
+}
+
 impl TryFrom<&ItemStruct> for ParsedStruct {
     type Error = ParseError;
 
@@ -125,6 +193,12 @@ impl TryFrom<&ItemStruct> for ParsedStruct {
             }
         };
         let struct_attrs = StructAttrs::try_from(item.attrs.as_slice())?;
+        if let Some(bump) = struct_attrs.seeds_bump() {
+            verify_seeds_bump_field(item, bump)?;
+        }
+        if struct_attrs.is_zero_copy() {
+            verify_zero_copy_repr(item)?;
+        }
         Ok(ParsedStruct {
             ident: item.ident.clone(),
             fields,
@@ -229,9 +229,7 @@ mod account_collection_examples {
 mod account_with_padding_examples {
     use super::*;
     fn padding_attrs() -> Vec<StructFieldAttr> {
-        let mut attrs = Vec::new();
-        attrs.push(StructFieldAttr::Padding);
-        attrs
+        vec![StructFieldAttr::Padding]
     }
 
     #[test]
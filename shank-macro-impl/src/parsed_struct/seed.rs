@@ -1,4 +1,5 @@
-use crate::types::{Primitive, RustType, TypeKind, Value};
+use crate::instruction::decode_address;
+use crate::types::{ParsedReference, Primitive, RustType, TypeKind, Value};
 use std::convert::TryFrom;
 use syn::{Error as ParseError, Result as ParseResult};
 
@@ -9,6 +10,31 @@ pub const FULL_PUBKEY_TY: &str = "::solana_program::pubkey::Pubkey";
 pub const ACCOUNT_INFO_TY: &str = "AccountInfo";
 pub const FULL_ACCOUNT_INFO_TY: &str =
     "::solana_program::account_info::AccountInfo";
+/// The max number of seeds of a PDA, including the bump seed.
+pub const MAX_SEEDS: usize = 16;
+/// The max length of a single seed in bytes.
+pub const MAX_SEED_LEN: usize = 32;
+
+/// The program the PDA of an account is derived for when it isn't the program
+/// itself, see `#[seeds(.., program = ..)]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SeedsProgram {
+    /// `program = spl_associated_token_account::ID`, the path of the program
+    /// id which is only known to Rust code.
+    Path(String),
+    /// `program = "<base58>"`, the address of the program.
+    Address(String),
+}
+
+impl SeedsProgram {
+    /// The bytes of the address, `None` for paths.
+    pub fn address_bytes(&self) -> Option<[u8; 32]> {
+        match self {
+            SeedsProgram::Address(address) => decode_address(address),
+            SeedsProgram::Path(_) => None,
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Seed {
@@ -43,6 +69,31 @@ impl Seed {
     }
 }
 
+/// Describes how the value of a seed arg is converted to the bytes of the seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedEncoding {
+    /// Pubkeys and account infos contribute their key via `as_ref()` and
+    /// strings via `as_bytes()`
+    Bytes,
+    /// A single byte
+    Byte,
+    /// An integer included as its `to_le_bytes()` of the given length
+    LeBytes(usize),
+    /// The discriminant of a fieldless enum included as a single byte
+    EnumDiscriminant,
+}
+
+impl SeedEncoding {
+    /// The number of bytes of the seed if it is known at compile time.
+    pub fn fixed_len(&self) -> Option<usize> {
+        match self {
+            SeedEncoding::Bytes => None,
+            SeedEncoding::Byte | SeedEncoding::EnumDiscriminant => Some(1),
+            SeedEncoding::LeBytes(len) => Some(*len),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SeedArg {
     pub name: String,
@@ -53,6 +104,48 @@ impl SeedArg {
     fn new(name: String, desc: String, ty: RustType) -> Self {
         Self { name, desc, ty }
     }
+
+    pub fn encoding(&self) -> SeedEncoding {
+        match &self.ty.kind {
+            TypeKind::Primitive(Primitive::U8) => SeedEncoding::Byte,
+            TypeKind::Primitive(Primitive::U16) => SeedEncoding::LeBytes(2),
+            TypeKind::Primitive(Primitive::U32) => SeedEncoding::LeBytes(4),
+            TypeKind::Primitive(Primitive::U64)
+            | TypeKind::Primitive(Primitive::I64) => SeedEncoding::LeBytes(8),
+            TypeKind::Value(Value::Custom(x))
+                if self.ty.reference == ParsedReference::Owned
+                    && !is_key_type(x) =>
+            {
+                SeedEncoding::EnumDiscriminant
+            }
+            _ => SeedEncoding::Bytes,
+        }
+    }
+}
+
+/// The name of the type without the path it is referenced by.
+pub fn type_name(ty: &str) -> &str {
+    ty.rsplit("::").next().unwrap_or(ty)
+}
+
+/// Std types which are referenced by name like custom types, but aren't
+/// fieldless enums and thus cannot be converted to a seed via `as u8`.
+const NON_ENUM_TYS: [&str; 13] = [
+    "Vec", "VecDeque", "Option", "Result", "Box", "Rc", "Arc", "Cell",
+    "RefCell", "HashMap", "BTreeMap", "HashSet", "BTreeSet",
+];
+
+/// Whether a custom type other than a pubkey or account info may be used as
+/// seed, which is only the case for fieldless enums. Since those cannot be
+/// told apart from structs by name this only rules out known std types.
+pub fn is_custom_seed_type(ty: &str) -> bool {
+    !NON_ENUM_TYS.contains(&type_name(ty))
+}
+
+/// Whether the type is a pubkey or account info, no matter the path it is
+/// referenced by.
+fn is_key_type(ty: &str) -> bool {
+    matches!(type_name(ty), PUBKEY_TY | ACCOUNT_INFO_TY)
 }
 
 #[derive(Debug)]
@@ -89,36 +182,55 @@ impl TryFrom<&Seed> for ProcessedSeed {
                 ))
             }
             Seed::Param(name, desc, maybe_kind) => {
-                let ty = match maybe_kind.as_ref().map(String::as_str) {
-                    Some(PUBKEY_TY) | None => {
-                        let kind = TypeKind::Value(Value::Custom(
-                            FULL_PUBKEY_TY.to_string(),
-                        ));
-                        RustType::reference(PUBKEY_TY, kind, None)
-                    }
-                    Some(ACCOUNT_INFO_TY) => {
+                // Pubkeys and account infos may be referenced via their path,
+                // i.e. solana_program::pubkey::Pubkey
+                let ty = match maybe_kind.as_deref() {
+                    Some(ty_name) if type_name(ty_name) == ACCOUNT_INFO_TY => {
                         let kind = TypeKind::Value(Value::Custom(
                             FULL_ACCOUNT_INFO_TY.to_string(),
                         ));
                         RustType::reference(ACCOUNT_INFO_TY, kind, None)
                     }
-                    Some(ty_name) => {
+                    Some(ty_name) if type_name(ty_name) != PUBKEY_TY => {
                         let ty = RustType::try_from(ty_name)?;
-                        match ty.get_primitive() {
-                            Some(Primitive::U8) => {
-                                // u8 is the only primitive we allow for seeds and it is the only
-                                // type that we don't pass by ref
-                                // Instead when passed to the seeds fn it is wrapped as &[u8]
-                                RustType::owned("u8", ty.kind)
+                        match (ty.get_primitive(), &ty.kind) {
+                            // Integers and enums are passed by value and converted to bytes
+                            // when deriving the PDA.
+                            // When passed to the seeds fn they are already converted, i.e.
+                            // a u64 is passed as &[u8; 8]
+                            (
+                                Some(
+                                    Primitive::U8
+                                    | Primitive::U16
+                                    | Primitive::U32
+                                    | Primitive::U64
+                                    | Primitive::I64,
+                                ),
+                                _,
+                            ) => ty.as_owned(),
+                            // Enums may be referenced via their path which we keep in
+                            // order to render it
+                            (None, TypeKind::Value(Value::Custom(_))) => {
+                                RustType::owned_custom_value(
+                                    type_name(ty_name),
+                                    ty_name,
+                                )
                             }
-                            Some(_) => {
+                            (Some(_), _) => {
                                 return Err(ParseError::new_spanned(
                                     ty.ident,
-                                    "Only u8 primitive is allowed for seeds. All other primitives need to be passed as strings."));
+                                    "Only u8, u16, u32, u64 and i64 primitives are allowed for seeds. All other primitives need to be passed as strings."));
                             }
-                            None => ty.as_reference(None),
+                            (None, _) => ty.as_reference(None),
                         }
                     }
+                    // Params without a type are pubkeys as well
+                    Some(_) | None => {
+                        let kind = TypeKind::Value(Value::Custom(
+                            FULL_PUBKEY_TY.to_string(),
+                        ));
+                        RustType::reference(PUBKEY_TY, kind, None)
+                    }
                 };
                 Ok(ProcessedSeed::new(
                     seed.clone(),
@@ -192,4 +304,59 @@ mod tests {
             assert_eq!(&format!("{:?}", ty.kind), "TypeKind::Primitive(Primitive::U8)")
         });
     }
+
+    #[test]
+    fn process_seed_integers() {
+        for (ty, len) in [("u16", 2), ("u32", 4), ("u64", 8), ("i64", 8)] {
+            let seed = Seed::Param(
+                "num".to_string(),
+                "num desc".to_string(),
+                Some(ty.to_string()),
+            );
+            let ProcessedSeed { arg, .. } = ProcessedSeed::try_from(&seed)
+                .expect("Should parse seed without error");
+
+            assert_matches!(arg, Some(arg) => {
+                assert_eq!(arg.ty.ident.to_string().as_str(), ty);
+                assert_eq!(arg.ty.reference, ParsedReference::Owned);
+                assert_eq!(arg.encoding(), SeedEncoding::LeBytes(len));
+            });
+        }
+    }
+
+    #[test]
+    fn process_seed_enum() {
+        let seed = Seed::Param(
+            "role".to_string(),
+            "role desc".to_string(),
+            Some("state::Role".to_string()),
+        );
+        let ProcessedSeed { arg, .. } = ProcessedSeed::try_from(&seed)
+            .expect("Should parse seed without error");
+
+        assert_matches!(arg, Some(arg) => {
+            assert_eq!(arg.ty.ident.to_string().as_str(), "Role");
+            assert_eq!(&format!("{:?}", arg.ty.kind), "TypeKind::Value(Value::Custom(\"state::Role\"))");
+            assert_eq!(arg.encoding(), SeedEncoding::EnumDiscriminant);
+        });
+    }
+
+    #[test]
+    fn process_seed_string_and_unsupported_primitive() {
+        let seed = Seed::Param(
+            "name".to_string(),
+            "name desc".to_string(),
+            Some("String".to_string()),
+        );
+        let ProcessedSeed { arg, .. } = ProcessedSeed::try_from(&seed)
+            .expect("Should parse seed without error");
+        assert_eq!(arg.unwrap().encoding(), SeedEncoding::Bytes);
+
+        let seed = Seed::Param(
+            "num".to_string(),
+            "num desc".to_string(),
+            Some("i32".to_string()),
+        );
+        assert!(ProcessedSeed::try_from(&seed).is_err());
+    }
 }
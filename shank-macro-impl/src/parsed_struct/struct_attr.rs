@@ -1,15 +1,36 @@
 use std::{collections::HashSet, convert::TryFrom, slice::Iter};
 
 use proc_macro2::Span;
+use quote::quote;
 use syn::{
-    punctuated::Punctuated, token::Comma, Attribute, Error as ParseError, Lit,
-    Meta, MetaList, NestedMeta, Path, Result as ParseResult,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Comma,
+    Attribute, Error as ParseError, Ident, Lit, LitStr, Meta, MetaList,
+    MetaNameValue, NestedMeta, Path, Result as ParseResult, Token,
 };
 
-use super::{ProcessedSeed, Seed};
+use super::{
+    is_custom_seed_type, CratePaths, CratePathsPreset, ProcessedSeed, Seed,
+    SeedsProgram, MAX_SEEDS, MAX_SEED_LEN,
+};
 
 const SUPPORTED_FORMATS: &str = r##"Examples of supported seeds:
-#[seeds("literal", program_id, pubkey("description"), byte("desc", u8), other_type("desc", u32))]"##;
+#[seeds("literal", program_id, pubkey("description"), byte("desc", u8), index("desc", u64), name("desc", String), kind("desc", SomeEnum))]
+#[seeds(wallet("desc"), token_program("desc"), mint("desc"), program = spl_associated_token_account::ID)]
+#[seeds("metadata", program_id, mint("desc"), program = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")]
+#[seeds("vault", owner("desc"), bump = bump)]"##;
+
+const SHANK_ATTR_FORMATS: &str = r##"Examples of supported shank attributes:
+#[shank(zero_copy)]
+#[shank(loaders)]
+#[shank(loaders(discriminator = 1, min_len = 42))]
+#[shank(loaders(discriminator = b"vault", min_len = 42))]
+#[shank(crate_paths(preset = "solana_sdk_split"))]
+#[shank(pinocchio)]
+#[shank(processor)]
+#[shank(instruction_data)]
+#[shank(context_checked)]"##;
 
 // -----------------
 // StructAttr
@@ -18,6 +39,14 @@ const SUPPORTED_FORMATS: &str = r##"Examples of supported seeds:
 pub enum StructAttr {
     Seeds(Seeds),
     PodSentinel(Vec<u8>),
+    Loaders(Loaders),
+    ZeroCopy,
+    CratePaths(Box<CratePaths>),
+    Processor,
+    InstructionData,
+    ContextChecked,
+    SeedsProgram(SeedsProgram),
+    SeedsBump(Ident),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -52,6 +81,14 @@ impl From<&StructAttr> for String {
         match attr {
             StructAttr::Seeds(_seeds) => "seeds".to_string(),
             StructAttr::PodSentinel(_) => "pod_sentinel".to_string(),
+            StructAttr::Loaders(_) => "loaders".to_string(),
+            StructAttr::ZeroCopy => "zero_copy".to_string(),
+            StructAttr::CratePaths(_) => "crate_paths".to_string(),
+            StructAttr::Processor => "processor".to_string(),
+            StructAttr::InstructionData => "instruction_data".to_string(),
+            StructAttr::ContextChecked => "context_checked".to_string(),
+            StructAttr::SeedsProgram(_) => "seeds_program".to_string(),
+            StructAttr::SeedsBump(_) => "seeds_bump".to_string(),
         }
     }
 }
@@ -70,6 +107,33 @@ impl StructAttr {
             _ => None,
         }
     }
+
+    pub fn into_loaders(self) -> Option<Loaders> {
+        match self {
+            StructAttr::Loaders(loaders) => Some(loaders),
+            _ => None,
+        }
+    }
+}
+
+/// Configures the `from_account_info` and `from_bytes` loaders derived for an
+/// account via `#[shank(loaders(..))]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Loaders {
+    /// The bytes the account data needs to start with.
+    pub discriminator: Vec<u8>,
+    /// The minimum length of the account data.
+    pub min_len: Option<usize>,
+}
+
+impl Loaders {
+    /// The minimum length of the account data which defaults to the length of
+    /// the discriminator.
+    pub fn min_len(&self) -> usize {
+        self.min_len
+            .unwrap_or(self.discriminator.len())
+            .max(self.discriminator.len())
+    }
 }
 
 // -----------------
@@ -98,6 +162,60 @@ impl StructAttrs {
     pub fn insert(&mut self, attr: StructAttr) -> bool {
         self.0.insert(attr)
     }
+    /// Whether the type is a `#[repr(C)]` struct read via bytemuck as
+    /// indicated by `#[shank(zero_copy)]`.
+    pub fn is_zero_copy(&self) -> bool {
+        self.0.contains(&StructAttr::ZeroCopy)
+    }
+    /// Whether the instruction unpacking and dispatch is derived as indicated
+    /// by `#[shank(processor)]`.
+    pub fn is_processor(&self) -> bool {
+        self.0.contains(&StructAttr::Processor)
+    }
+    /// Whether the instruction `data` and discriminants are derived as
+    /// indicated by `#[shank(instruction_data)]`.
+    pub fn is_instruction_data(&self) -> bool {
+        self.0.contains(&StructAttr::InstructionData)
+    }
+    /// Whether the contexts also derive `context_checked` as indicated by
+    /// `#[shank(context_checked)]`.
+    pub fn is_context_checked(&self) -> bool {
+        self.0.contains(&StructAttr::ContextChecked)
+    }
+    pub fn loaders(&self) -> Option<&Loaders> {
+        self.0.iter().find_map(|attr| match attr {
+            StructAttr::Loaders(loaders) => Some(loaders),
+            _ => None,
+        })
+    }
+    /// The program the PDA of the account is derived for if it isn't the
+    /// program itself.
+    pub fn seeds_program(&self) -> Option<&SeedsProgram> {
+        self.0.iter().find_map(|attr| match attr {
+            StructAttr::SeedsProgram(program) => Some(program),
+            _ => None,
+        })
+    }
+    /// The field of the account storing the bump of its PDA.
+    pub fn seeds_bump(&self) -> Option<&Ident> {
+        self.0.iter().find_map(|attr| match attr {
+            StructAttr::SeedsBump(field) => Some(field),
+            _ => None,
+        })
+    }
+    /// The paths of the Solana crates the derived code refers to, defaulting
+    /// to `solana_program`.
+    pub fn crate_paths(&self) -> CratePaths {
+        self.0
+            .iter()
+            .find_map(|attr| match attr {
+                StructAttr::CratePaths(crate_paths) => {
+                    Some(crate_paths.as_ref().clone())
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
 }
 
 impl Default for StructAttrs {
@@ -106,6 +224,47 @@ impl Default for StructAttrs {
     }
 }
 
+/// An arg of the `#[seeds]` attr. `program = path` and `bump = field` are
+/// parsed separately since their values aren't literals and thus not a valid
+/// [Meta].
+enum SeedsAttrArg {
+    Program(SeedsProgram),
+    Bump(Ident),
+    Meta(NestedMeta),
+}
+
+impl Parse for SeedsAttrArg {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let name = match input.fork().parse::<Ident>() {
+            Ok(ident) if input.peek2(Token![=]) => ident.to_string(),
+            _ => return Ok(SeedsAttrArg::Meta(input.parse()?)),
+        };
+        if name != "program" && name != "bump" {
+            return Ok(SeedsAttrArg::Meta(input.parse()?));
+        }
+        input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        if name == "bump" {
+            return Ok(SeedsAttrArg::Bump(input.parse()?));
+        }
+        if input.peek(LitStr) {
+            let lit = input.parse::<LitStr>()?;
+            let program = SeedsProgram::Address(lit.value());
+            if program.address_bytes().is_none() {
+                return Err(ParseError::new_spanned(
+                    lit,
+                    "The seeds program needs to be a base58 encoded pubkey or the path of one",
+                ));
+            }
+            return Ok(SeedsAttrArg::Program(program));
+        }
+        let path = input.parse::<Path>()?;
+        Ok(SeedsAttrArg::Program(SeedsProgram::Path(
+            quote! { #path }.to_string().replace(' ', ""),
+        )))
+    }
+}
+
 // TODO(thlorenz): Include the stringified representation of invalid seeds when possible in order
 // to improve error messages during IDL generation via shank-cli
 impl TryFrom<&[Attribute]> for StructAttrs {
@@ -142,25 +301,69 @@ impl TryFrom<&[Attribute]> for StructAttrs {
             ));
         }
 
-        // For now we only handle seeds as attributes on the `struct` itself
-        if seed_attrs.is_empty() && pod_sentinel_attrs.is_empty() {
+        // Parse shank attributes
+        let shank_attrs: Vec<&Attribute> = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("shank"))
+            .collect();
+
+        if seed_attrs.is_empty()
+            && pod_sentinel_attrs.is_empty()
+            && shank_attrs.is_empty()
+        {
             return Ok(StructAttrs(HashSet::new()));
         }
 
+        for shank_attr in shank_attrs {
+            for attr in parse_shank_attr(shank_attr)? {
+                let name = String::from(&attr);
+                if struct_attrs.iter().any(|x| String::from(x) == name) {
+                    return Err(ParseError::new_spanned(
+                        shank_attr,
+                        format!("Only one #[shank({})] allowed per type", name),
+                    ));
+                }
+                struct_attrs.insert(attr);
+            }
+        }
+
         // Process seeds attribute if present
         if let Some(seed_attr) = seed_attrs.first() {
-            let seed_attrs_meta = seed_attr.parse_meta()?;
-            let nested_args: Punctuated<NestedMeta, Comma> = {
-                use syn::Meta::*;
-                match seed_attrs_meta {
-                    List(MetaList { nested, .. }) => nested,
-                    Path(_) | NameValue(_) => {
-                        return Err(ParseError::new(Span::call_site(), "seeds requires a comma-separated list of seeds, e.g., #[seeds(\"const\", pubkey(\"description\"))]"));
-                    }
-                }
-            };
+            if let Ok(Meta::Path(_) | Meta::NameValue(_)) =
+                seed_attr.parse_meta()
+            {
+                return Err(ParseError::new(Span::call_site(), "seeds requires a comma-separated list of seeds, e.g., #[seeds(\"const\", pubkey(\"description\"))]"));
+            }
+            let nested_args = seed_attr.parse_args_with(
+                Punctuated::<SeedsAttrArg, Comma>::parse_terminated,
+            )?;
             let mut seeds = vec![];
+            let mut seeds_program = None;
+            let mut seeds_bump = None;
             for arg in nested_args.iter() {
+                let arg = match arg {
+                    SeedsAttrArg::Program(program) => {
+                        if seeds_program.is_some() {
+                            return Err(ParseError::new_spanned(
+                                seed_attr,
+                                "Only one program allowed per #[seeds(..)]",
+                            ));
+                        }
+                        seeds_program = Some(program.clone());
+                        continue;
+                    }
+                    SeedsAttrArg::Bump(field) => {
+                        if seeds_bump.is_some() {
+                            return Err(ParseError::new_spanned(
+                                field,
+                                "Only one bump allowed per #[seeds(..)]",
+                            ));
+                        }
+                        seeds_bump = Some(field.clone());
+                        continue;
+                    }
+                    SeedsAttrArg::Meta(arg) => arg,
+                };
                 let seed = match arg {
                     NestedMeta::Meta(meta) => {
                         match meta {
@@ -238,15 +441,42 @@ impl TryFrom<&[Attribute]> for StructAttrs {
                     }
                     // #[seeds("some:literal:string")]
                     NestedMeta::Lit(lit) => {
-                        let seed = Seed::Literal(extract_lit_str(lit)?);
-                        Ok(seed)
+                        let literal = extract_lit_str(lit)?;
+                        if literal.len() > MAX_SEED_LEN {
+                            return Err(ParseError::new(
+                                lit.span(),
+                                format!(
+                                    "The seed literal is {} bytes long, but seeds can be at most {} bytes",
+                                    literal.len(),
+                                    MAX_SEED_LEN
+                                ),
+                            ));
+                        }
+                        Ok(Seed::Literal(literal))
                     }
                 }?;
+                // The bump seed is added to the seeds when deriving the PDA
+                if seeds.len() + 1 == MAX_SEEDS {
+                    return Err(ParseError::new_spanned(
+                        arg,
+                        format!(
+                            "An account can have at most {} seeds since a PDA is derived from at most {} seeds including the bump",
+                            MAX_SEEDS - 1,
+                            MAX_SEEDS
+                        ),
+                    ));
+                }
                 seeds.push(seed);
             }
 
             let seeds_struct_attr = StructAttr::Seeds(Seeds(seeds));
             struct_attrs.insert(seeds_struct_attr);
+            if let Some(program) = seeds_program {
+                struct_attrs.insert(StructAttr::SeedsProgram(program));
+            }
+            if let Some(field) = seeds_bump {
+                struct_attrs.insert(StructAttr::SeedsBump(field));
+            }
         }
 
         // Process pod_sentinel attribute if present
@@ -304,6 +534,133 @@ impl TryFrom<&[Attribute]> for StructAttrs {
     }
 }
 
+/// Parses `#[shank(..)]` into the struct attributes it configures.
+fn parse_shank_attr(attr: &Attribute) -> ParseResult<Vec<StructAttr>> {
+    let nested = match attr.parse_meta()? {
+        Meta::List(MetaList { nested, .. }) => nested,
+        meta => {
+            return Err(ParseError::new_spanned(
+                meta,
+                format!(
+                    "shank requires a list of options\n{}",
+                    SHANK_ATTR_FORMATS
+                ),
+            ))
+        }
+    };
+
+    let mut struct_attrs = vec![];
+    for arg in nested.iter() {
+        let struct_attr = match arg {
+            // #[shank(zero_copy)]
+            NestedMeta::Meta(Meta::Path(path))
+                if path.is_ident("zero_copy") =>
+            {
+                StructAttr::ZeroCopy
+            }
+            // #[shank(loaders)]
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("loaders") => {
+                StructAttr::Loaders(Loaders::default())
+            }
+            // #[shank(loaders(discriminator = 1, min_len = 42))]
+            NestedMeta::Meta(Meta::List(list))
+                if list.path.is_ident("loaders") =>
+            {
+                StructAttr::Loaders(parse_loaders(&list.nested)?)
+            }
+            // #[shank(crate_paths(preset = "pinocchio"))]
+            NestedMeta::Meta(Meta::List(list))
+                if list.path.is_ident("crate_paths") =>
+            {
+                StructAttr::CratePaths(Box::new(CratePaths::try_from_nested(
+                    &list.nested,
+                )?))
+            }
+            // #[shank(pinocchio)] is short for the pinocchio crate paths preset
+            NestedMeta::Meta(Meta::Path(path))
+                if path.is_ident("pinocchio") =>
+            {
+                StructAttr::CratePaths(Box::new(CratePaths::from_preset(
+                    CratePathsPreset::Pinocchio,
+                )))
+            }
+            // #[shank(processor)]
+            NestedMeta::Meta(Meta::Path(path))
+                if path.is_ident("processor") =>
+            {
+                StructAttr::Processor
+            }
+            // #[shank(instruction_data)]
+            NestedMeta::Meta(Meta::Path(path))
+                if path.is_ident("instruction_data") =>
+            {
+                StructAttr::InstructionData
+            }
+            // #[shank(context_checked)]
+            NestedMeta::Meta(Meta::Path(path))
+                if path.is_ident("context_checked") =>
+            {
+                StructAttr::ContextChecked
+            }
+            _ => {
+                return Err(ParseError::new_spanned(
+                    arg,
+                    format!("Unknown shank attribute\n{}", SHANK_ATTR_FORMATS),
+                ))
+            }
+        };
+        struct_attrs.push(struct_attr);
+    }
+    Ok(struct_attrs)
+}
+
+fn parse_loaders(
+    nested: &Punctuated<NestedMeta, Comma>,
+) -> ParseResult<Loaders> {
+    let mut loaders = Loaders::default();
+    for arg in nested.iter() {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit,
+                ..
+            })) if path.is_ident("discriminator") => {
+                loaders.discriminator = match lit {
+                    Lit::Int(int_lit) => {
+                        vec![int_lit.base10_parse::<u8>().map_err(|_| {
+                            ParseError::new(
+                                int_lit.span(),
+                                "A discriminator integer needs to be a u8, use a byte string for longer discriminators",
+                            )
+                        })?]
+                    }
+                    Lit::ByteStr(byte_str) => byte_str.value(),
+                    _ => {
+                        return Err(ParseError::new(
+                            lit.span(),
+                            "The discriminator needs to be a u8 integer or a byte string",
+                        ))
+                    }
+                };
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Int(int_lit),
+                ..
+            })) if path.is_ident("min_len") => {
+                loaders.min_len = Some(int_lit.base10_parse::<usize>()?);
+            }
+            _ => {
+                return Err(ParseError::new_spanned(
+                    arg,
+                    format!("Invalid loaders option\n{}", SHANK_ATTR_FORMATS),
+                ))
+            }
+        }
+    }
+    Ok(loaders)
+}
+
 fn param_args(
     meta: &Punctuated<NestedMeta, Comma>,
     span: &Span,
@@ -328,8 +685,22 @@ fn param_args(
     let ty: Option<String> = match ty_meta {
         Some(ty_meta) => {
             match ty_meta {
+                // Enum seeds may be referenced via their path, i.e. state::Role
                 NestedMeta::Meta(Meta::Path(path)) => {
-                    Ok(Some(path.get_ident().unwrap().to_string()))
+                    let ty = path
+                        .segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string())
+                        .collect::<Vec<String>>()
+                        .join("::");
+                    if is_custom_seed_type(&ty) {
+                        Ok(Some(ty))
+                    } else {
+                        Err(ParseError::new_spanned(
+                            path,
+                            format!("Custom seed types need to be fieldless enums which are Copy, found {}.\n{}", ty, SUPPORTED_FORMATS),
+                        ))
+                    }
                 }
                 NestedMeta::Meta(Meta::List(list)) => Err(ParseError::new(
                     list.path.get_ident().unwrap().span(),
@@ -49,6 +49,12 @@ pub fn get_derive_names(attrs: &[Attribute]) -> Vec<String> {
 }
 
 pub fn attr_is_derive(attr: &&Attribute, derive: &str) -> bool {
+    // Other attributes aren't necessarily valid metas, i.e.
+    // `#[seeds(.., program = some::path::ID)]`, thus they are skipped before
+    // being parsed
+    if !attr.path.segments.iter().any(|x| x.ident == "derive") {
+        return false;
+    }
     let meta = &attr.parse_meta();
 
     match meta {
@@ -77,3 +83,47 @@ pub fn get_derive_attr<'a>(
 ) -> Option<&'a Attribute> {
     attrs.iter().find(|attr| attr_is_derive(attr, derive))
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::{parse_quote, ItemStruct};
+
+    use super::*;
+
+    #[test]
+    fn derive_attr_next_to_attrs_which_are_not_metas() {
+        let item: ItemStruct = parse_quote! {
+            #[seeds("vault", owner("The owner"), program = some::path::ID)]
+            #[derive(Clone, ShankAccount)]
+            pub struct Vault {
+                pub owner: Pubkey,
+            }
+        };
+        assert!(item.attrs[0].parse_meta().is_err());
+        assert!(!attr_is_derive(&&item.attrs[0], "ShankAccount"));
+
+        let attr = get_derive_attr(&item.attrs, "ShankAccount")
+            .expect("Should find derive attr");
+        assert_eq!(
+            quote! { #attr }.to_string(),
+            quote! { #[derive(Clone, ShankAccount)] }.to_string()
+        );
+    }
+
+    #[test]
+    fn derive_attr_next_to_seeds_with_bump() {
+        let item: ItemStruct = parse_quote! {
+            #[derive(ShankAccount)]
+            #[seeds("vault", owner("The owner"), bump = bump)]
+            pub struct Vault {
+                pub owner: Pubkey,
+                pub bump: u8,
+            }
+        };
+        assert!(item.attrs[1].parse_meta().is_err());
+        assert!(!attr_is_derive(&&item.attrs[1], "ShankAccount"));
+        assert!(get_derive_attr(&item.attrs, "ShankAccount").is_some());
+        assert!(get_derive_attr(&item.attrs, "ShankInstruction").is_none());
+    }
+}
@@ -17,15 +17,22 @@ impl RustType {
                         let inner = inners[0].render();
                         quote!([#inner; #n])
                     }
-                    Vec => todo!("Render Vec composite"),
-                    Tuple => todo!("Render Tuple composite"),
-                    Option => todo!("Render Option composite"),
-                    PodOption => todo!("Render PodOption composite"),
-                    HashMap => todo!("Render HashMap composite"),
-                    BTreeMap => todo!("Render BTreeMap composite"),
-                    HashSet => todo!("Render HashSet composite"),
-                    BTreeSet => todo!("Render BTreeSet composite"),
-                    Custom(_) => todo!("Render Custom composite"),
+                    // Single element tuples need a trailing comma
+                    Tuple if inners.len() == 1 => {
+                        let inner = inners[0].render();
+                        quote!((#inner,))
+                    }
+                    Tuple => {
+                        let inners = inners.iter().map(RustType::render);
+                        quote!((#(#inners),*))
+                    }
+                    // Rendered via the identifier the type was declared with
+                    Vec | Option | PodOption | HashMap | BTreeMap | HashSet
+                    | BTreeSet | Custom(_) => {
+                        let ident = &self.ident;
+                        let inners = inners.iter().map(RustType::render);
+                        quote!(#ident<#(#inners),*>)
+                    }
                 }
             }
             TypeKind::Unit => todo!("should not render unit rust type"),
@@ -356,4 +363,25 @@ mod tests {
                 .unwrap(),
         );
     }
+
+    // -----------------
+    // Composites
+    // -----------------
+    #[test]
+    fn composites() {
+        use crate::types::{resolve_rust_ty, RustTypeContext};
+
+        for ty in [
+            quote! { Option<u8> },
+            quote! { Vec<String> },
+            quote! { HashMap<String, Vec<u64> > },
+            quote! { (u8, Option<Schedule>) },
+            quote! { [u8; 32usize] },
+        ] {
+            let parsed = syn::parse2::<syn::Type>(ty.clone()).unwrap();
+            let rust_ty =
+                resolve_rust_ty(&parsed, RustTypeContext::Default).unwrap();
+            assert_tokens_match(rust_ty.render(), ty);
+        }
+    }
 }
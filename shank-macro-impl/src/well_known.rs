@@ -0,0 +1,97 @@
+/// A program or sysvar whose address is known, which accounts of the same
+/// name default to and whose id may be referenced via its path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WellKnownAccount {
+    /// The name of the instruction accounts defaulting to it.
+    pub name: &'static str,
+    /// The path of its id in Rust code.
+    pub path: &'static str,
+    /// Its base58 encoded address.
+    pub address: &'static str,
+}
+
+pub const WELL_KNOWN_ACCOUNTS: [WellKnownAccount; 7] = [
+    WellKnownAccount {
+        name: "system_program",
+        path: "solana_program::system_program::ID",
+        address: "11111111111111111111111111111111",
+    },
+    WellKnownAccount {
+        name: "spl_token_program",
+        path: "spl_token::ID",
+        address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    },
+    WellKnownAccount {
+        name: "spl_token_2022_program",
+        path: "spl_token_2022::ID",
+        address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+    },
+    WellKnownAccount {
+        name: "spl_ata_program",
+        path: "spl_associated_token_account::ID",
+        address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+    },
+    WellKnownAccount {
+        name: "sysvar_instructions",
+        path: "solana_program::sysvar::instructions::ID",
+        address: "Sysvar1nstructions1111111111111111111111111",
+    },
+    WellKnownAccount {
+        name: "token_metadata_program",
+        path: "mpl_token_metadata::ID",
+        address: "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+    },
+    WellKnownAccount {
+        name: "authorization_rules_program",
+        path: "mpl_token_auth_rules::ID",
+        address: "auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg",
+    },
+];
+
+/// The well known account which accounts with the provided name default to.
+pub fn well_known_account_named(
+    name: &str,
+) -> Option<&'static WellKnownAccount> {
+    WELL_KNOWN_ACCOUNTS
+        .iter()
+        .find(|account| account.name == name)
+}
+
+/// The address of the well known account whose id is referenced via the
+/// provided path, i.e. `spl_token::ID`.
+pub fn well_known_address(path: &str) -> Option<&'static str> {
+    let path = path.trim_start_matches("::");
+    WELL_KNOWN_ACCOUNTS
+        .iter()
+        .find(|account| account.path == path)
+        .map(|account| account.address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_known_addresses_are_valid() {
+        for account in WELL_KNOWN_ACCOUNTS {
+            assert!(
+                crate::instruction::AccountDefault::Address(
+                    account.address.to_string()
+                )
+                .address_bytes()
+                .is_some(),
+                "{} should have a valid address",
+                account.name
+            );
+        }
+    }
+
+    #[test]
+    fn well_known_address_by_path() {
+        assert_eq!(
+            well_known_address("::spl_token::ID"),
+            Some("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+        );
+        assert_eq!(well_known_address("rewards::ID"), None);
+    }
+}
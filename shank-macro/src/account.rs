@@ -1,6 +1,7 @@
 use std::convert::TryFrom;
 
 use proc_macro2::TokenStream;
+use quote::quote;
 use shank_macro_impl::{
     parsed_struct::ParsedStruct, parsers::get_derive_attr, DERIVE_ACCOUNT_ATTR,
 };
@@ -12,11 +13,26 @@ pub fn derive_account(input: DeriveInput) -> ParseResult<TokenStream> {
     match item {
         Item::Struct(struct_item) => {
             let parsed_struct = ParsedStruct::try_from(&struct_item)?;
-            shank_render::pda::render_pda_and_seeds_impl(
+            let pda_and_seeds_impl =
+                shank_render::pda::render_pda_and_seeds_impl(
+                    &parsed_struct.struct_attrs,
+                    &parsed_struct.ident,
+                    true,
+                )?;
+            let seeds_assertions =
+                shank_render::pda::render_seeds_assertions_impl(
+                    &parsed_struct.struct_attrs,
+                    &parsed_struct.ident,
+                )?;
+            let loaders_impl = shank_render::loaders::render_loaders_impl(
                 &parsed_struct.struct_attrs,
                 &parsed_struct.ident,
-                true,
-            )
+            )?;
+            Ok(quote! {
+                #pda_and_seeds_impl
+                #seeds_assertions
+                #loaders_impl
+            })
         }
         _ => Err(ParseError::new_spanned(
             &attr,
@@ -9,8 +9,25 @@ pub fn derive_instruction(input: DeriveInput) -> ParseResult<TokenStream> {
     let item = Item::from(input);
     match item {
         Item::Enum(enum_item) => {
-            Instruction::try_from_item_enum(&enum_item, true)
-                .map(|_| TokenStream::new())
+            let instruction =
+                match Instruction::try_from_item_enum(&enum_item, true)? {
+                    Some(instruction) => instruction,
+                    None => return Ok(TokenStream::new()),
+                };
+            let mut tokens = TokenStream::new();
+            if instruction.instruction_data {
+                tokens.extend(
+                    shank_render::instruction::render_instruction_data_impl(
+                        &instruction,
+                    )?,
+                );
+            }
+            if instruction.processor {
+                tokens.extend(shank_render::processor::render_processor_impl(
+                    &instruction,
+                )?);
+            }
+            Ok(tokens)
         }
         _ => Err(ParseError::new_spanned(
             &attr,
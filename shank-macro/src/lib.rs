@@ -99,10 +99,10 @@ mod instruction;
 /// A seed takes one of the following patterns:
 ///
 /// - `"literal"` this will be hardcoded into the seed/pda methods and does not need to be passed
-/// via an argument
+///   via an argument
 /// - `program_id` (known pubkey) this is the program id of the program which is passed to methods
 /// - `label("description"[, type])` a seed of name _label_ with the provided description and an
-/// optional type (if no type is provided `Pubkey` is assumed); this will be passed as an argument
+///   optional type (if no type is provided `Pubkey` is assumed); this will be passed as an argument
 ///
 /// Below is an example of each:
 ///
@@ -114,7 +114,9 @@ mod instruction;
 ///     pub_key_implicit("desc of the key"), // a public key which needs to be provided
 ///     pub_key("desc of the key", Pubkey),  // same as the above, explicitly declaring as pubkey
 ///     id("desc of byte", u8),              // a byte
-///     name("desc of name", String)         // a string
+///     index("desc of index", u64),         // an integer, u16, u32, u64 or i64
+///     name("desc of name", String),        // a string, String or str
+///     role("desc of role", Role)           // a fieldless Copy enum, by name or path
 /// )]
 /// struct AccountStructWithSeeds {
 ///     count: u8,
@@ -128,16 +130,157 @@ mod instruction;
 /// AccountName::shank_seeds_with_bump<'a>(.., bump: &'a [u8; 1]) -> [&'a [u8]; Nusize]
 ///
 /// AccountName::shank_pda(program_id: Pubkey, ..) -> (Pubkey, u8)
-/// AccountName::shank_pda_with_bump(program_id: Pubkey, bump: u8, ..) -> (Pubkey, u8)
+/// AccountName::shank_pda_with_bump(program_id: Pubkey, .., bump: u8) -> Result<(Pubkey, u8), PubkeyError>
+/// AccountName::shank_find_pda(..) -> (Pubkey, u8)
+///
+/// AccountName::shank_create_pda(program_id: Pubkey, .., bump: u8) -> Result<Pubkey, PubkeyError>
+/// AccountName::shank_verify_pda(pda: &Pubkey, program_id: Pubkey, .., bump: u8) -> bool
+/// ```
+///
+/// `shank_find_pda` derives the PDA for the program the account belongs to, which is `crate::ID`
+/// unless the seeds declare another `program`, see below.
+///
+/// `shank_pda_with_bump`, `shank_create_pda` and `shank_verify_pda` create the PDA from a known
+/// bump via `create_program_address` which is much cheaper on-chain than finding the bump via
+/// `shank_pda`. They fail if the seeds and bump don't result in a valid PDA.
+/// When the account stores the bump of its PDA, `bump = <field>` names that `u8` field and derives
+/// the following methods which use it:
+///
+/// ```
+/// #[derive(ShankAccount)]
+/// #[seeds("vault", owner("The owner of the vault"), bump = bump)]
+/// struct Vault {
+///     owner: Pubkey,
+///     bump: u8,
+/// }
+///
+/// vault.shank_stored_pda(program_id: Pubkey, ..) -> Result<Pubkey, PubkeyError>
+/// vault.shank_verify_stored_pda(pda: &Pubkey, program_id: Pubkey, ..) -> bool
+/// ```
+///
+/// `shank_pda` takes integer and enum seeds by value and converts them to bytes, integers via
+/// `to_le_bytes()` and enums to their discriminant via `as u8`. Strings are included via
+/// `as_bytes()`. Thus any seed type other than pubkeys, account infos, integers and strings
+/// needs to be a fieldless enum which is `Copy`. Structs and newtypes aren't supported and fail
+/// to compile with a non-primitive cast error. Since the seeds array borrows its items, `shank_seeds` takes the converted bytes
+/// instead, i.e. `&[u8; 8]` for a `u64` seed.
+///
+/// Since a PDA is derived from at most 16 seeds of at most 32 bytes each, an account can declare
+/// at most 15 seeds, leaving room for the bump, and literals can be at most 32 bytes long. Seeds
+/// of fixed size types are verified via `const` assertions, i.e. that an enum seed fits into a
+/// single byte.
+///
+/// Accounts owned by another program, i.e. an associated token account, are derived for that
+/// program by adding `program = <path>` or `program = "<base58 pubkey>"` to the seeds:
+///
+/// ```
+/// #[derive(ShankAccount)]
+/// #[seeds(
+///     owner("The owner of the token account"),
+///     token_program("The token program"),
+///     mint("The mint of the token account"),
+///     program = spl_associated_token_account::ID,
+/// )]
+/// struct AssociatedToken {
+///     amount: u64,
+/// }
+/// ```
+///
+/// In that case `shank_pda` and `shank_pda_with_bump` don't take a `program_id`, a `program_id`
+/// seed resolves to the provided program and the IDL records it as the `seedsProgram` of the
+/// account.
+///
+/// # Loaders
+///
+/// Adding `#[shank(loaders)]` derives methods which load the account from its `AccountInfo`
+/// after verifying it. The data is deserialized via `BorshDeserialize` and thus the account needs
+/// to derive it.
+///
+/// - `discriminator`: a `u8` or a byte string the account data needs to start with, i.e. the
+///   serialized _key_ of the account
+/// - `min_len`: the minimum length of the account data which defaults to the discriminator length
+///
+/// ```
+/// #[derive(BorshDeserialize, ShankAccount)]
+/// #[shank(loaders(discriminator = 1, min_len = 34))]
+/// #[seeds("vault", owner("The owner of the vault"))]
+/// pub struct Vault {
+///     pub key: Key,
+///     pub owner: Pubkey,
+///     pub bump: u8,
+/// }
 /// ```
 ///
+/// This derives the following _static_ methods, where `verify_pda` is only included when the
+/// account has seeds:
+///
+/// ```
+/// AccountName::from_account_info(account: &AccountInfo) -> Result<Self, ProgramError>
+/// AccountName::from_bytes(data: &[u8]) -> Result<Self, ProgramError>
+/// AccountName::verify_pda(account: &AccountInfo, ..) -> Result<u8, ProgramError>
+/// ```
+///
+/// Accounts annotated with `#[shank(zero_copy)]` are read via `bytemuck::try_pod_read_unaligned`
+/// instead and their data needs to be at least the size of the struct.
+///
+/// `from_account_info` returns `ProgramError::IllegalOwner` unless the account is owned by
+/// `crate::ID`. `from_bytes` returns `ProgramError::AccountDataTooSmall` if the data is shorter
+/// than `min_len` and `ProgramError::InvalidAccountData` if the discriminator doesn't match or
+/// deserialization fails. `verify_pda` derives the PDA for `crate::ID` and the seed args,
+/// returning `ProgramError::InvalidSeeds` if the key of the account doesn't match and the bump
+/// otherwise.
+///
+/// When the seeds declare `bump = <field>`, `verify_pda` takes `&self` instead and creates the PDA
+/// from the stored bump via `shank_verify_stored_pda`, which is much cheaper on-chain than finding
+/// the bump:
+///
+/// ```
+/// vault.verify_pda(account: &AccountInfo, ..) -> Result<u8, ProgramError>
+/// ```
+///
+/// # Zero Copy
+///
+/// Accounts annotated with `#[shank(zero_copy)]` are `#[repr(C)]` structs read via bytemuck. The
+/// IDL includes the offset of each field as well as the size of the account. See _ShankType_ for
+/// more details.
+///
+/// # Crate Paths
+///
+/// The derived code refers to `::solana_program` by default. Programs built on other crates
+/// configure the paths via `#[shank(crate_paths(..))]`, either by picking a `preset` or by
+/// providing individual paths which override the ones of the preset. The same attribute is
+/// supported by _ShankContext_ and _ShankBuilder_.
+///
+/// - `preset`: `"solana_program"` (default), `"solana_sdk_split"` for the `solana-pubkey`,
+///   `solana-account-info`, `solana-program-error`, `solana-instruction`, `solana-cpi` and
+///   `solana-sdk-ids` crates or `"pinocchio"`
+/// - `pubkey`, `find_program_address`, `create_program_address`, `pubkey_error`, `account_info`,
+///   `program_error`, `instruction`, `account_meta`, `invoke_signed`, `system_program` and
+///   `sysvar_instructions`: the path of the respective item
+///
+/// ```
+/// #[derive(BorshDeserialize, ShankAccount)]
+/// #[shank(loaders, crate_paths(preset = "solana_sdk_split"))]
+/// #[seeds("vault", owner("The owner of the vault"))]
+/// pub struct Vault {
+///     pub owner: Pubkey,
+/// }
+/// ```
+///
+/// The `pinocchio` preset also accesses the `AccountInfo` via its methods, i.e. `key()`, and
+/// `#[shank(pinocchio)]` is short for it. It only supports the seeds, PDA helpers and loaders of
+/// _ShankAccount_ as well as _ShankContext_. _ShankBuilder_ rejects it with a compile error, for
+/// the builders as well as the `*Cpi` structs, since pinocchio instructions borrow their accounts
+/// and data. Thus the `instruction`, `account_meta` and `invoke_signed` paths have no effect with
+/// pinocchio.
+///
 ///# Note
 ///
 /// The fields of a _ShankAccount_ struct can reference other types as long as they are annotated
 /// with `ShankType`, `BorshSerialize` or `BorshDeserialize`.
 #[proc_macro_derive(
     ShankAccount,
-    attributes(padding, seeds, idl_type, idl_name, skip, pod_sentinel)
+    attributes(padding, seeds, idl_type, idl_name, skip, pod_sentinel, shank)
 )]
 pub fn shank_account(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -162,7 +305,7 @@ pub fn shank_account(input: TokenStream) -> TokenStream {
 /// They take the following general form:
 ///
 /// ```
-/// #[account(index?, writable?, (signer|optional_signer)?, optional?, name="<account_name>", desc?="optional description")]
+/// #[account(index?, writable?, (signer|optional_signer)?, optional?, name="<account_name>", desc?="optional description", (default|address)?="<pubkey>", (pda=<AccountType>, seeds(<seed>, ..))?)]
 /// ```
 ///
 /// - `index`: optionally provides the account index in the provided accounts array which needs to
@@ -174,6 +317,16 @@ pub fn shank_account(input: TokenStream) -> TokenStream {
 /// - `optional | option | opt`: indicates that this account is optional
 /// - `name`: (required) provides the name for the account
 /// - `desc` | `description` | `docs`: allows to provide a description of the account
+/// - `default`: the path of the pubkey the account defaults to in the _ShankBuilder_ builders, i.e.
+///   `default = "spl_token::ID"`. The IDL includes the address of well known program and sysvar
+///   ids referenced this way
+/// - `address`: the base58 encoded address of the account which the builders default to and
+///   which is included in the IDL, i.e. `address = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"`
+/// - `pda`: the account type whose `#[seeds]` derive the account, along with `seeds(..)` naming the
+///   accounts and args of the instruction providing them, i.e. `pda = Metadata, seeds(mint)`.
+///   The builders derive the account via `Metadata::shank_find_pda` when it isn't set.
+///
+/// Optional accounts cannot have a `default`, an `address` nor a `pda`.
 ///
 /// When the `optional` attribute is added to an account, shank will mark it such that its value should default
 /// to the `progam_id` if it is not provided by the client. Thus the position of optional accounts is static and
@@ -201,6 +354,62 @@ pub fn shank_account(input: TokenStream) -> TokenStream {
 /// will is expected to be properly respected by code generator tools like
 /// [kinobi](https://github.com/metaplex-foundation/kinobi) and [solita](https://github.com/metaplex-foundation/solita).
 ///
+/// # Instruction Data
+///
+/// Adding `#[shank(instruction_data)]` to the enum derives the serialization of instructions as
+/// described by the IDL:
+///
+/// - a `<VARIANT>_DISCRIMINANT: u8` const per variant, i.e. `VaultInstruction::DEPOSIT_DISCRIMINANT`
+/// - `discriminant(&self) -> u8` returning the discriminant of the variant
+/// - `data(&self) -> Vec<u8>` returning the discriminant followed by the borsh serialized args
+///
+/// # Processor
+///
+/// Adding `#[shank(processor)]` to the enum derives the code dispatching instructions at runtime:
+///
+/// - `unpack(data: &[u8]) -> Result<Self, ProgramError>` which reads the `u8` discriminant of the
+///   variant followed by its borsh serialized args
+/// - a `processor::Processor` trait with a `process_<variant>` method per variant receiving the
+///   program id, the `*Accounts` context and the args of the instruction
+/// - `processor::dispatch::<P: Processor>(program_id, accounts, data)` which unpacks the
+///   instruction and invokes the method of `P` matching it
+///
+/// The accounts contexts are derived via _ShankContext_ which thus needs to be derived as well.
+/// They are created via `context_checked` if the enum is annotated with `#[shank(context_checked)]`
+/// and via `context` otherwise.
+/// Variants without accounts receive the context of a unit `*Accounts` _struct_ whose
+/// `remaining_accounts` are all the provided accounts.
+///
+/// ```ignore
+/// #[derive(ShankInstruction, ShankContext, BorshDeserialize)]
+/// #[shank(processor)]
+/// pub enum VaultInstruction {
+///     #[account(0, writable, name = "vault")]
+///     #[account(1, signer, name = "owner")]
+///     Deposit { amount: u64 },
+/// }
+///
+/// struct Vault;
+/// impl processor::Processor for Vault {
+///     fn process_deposit<'a>(
+///         program_id: &Pubkey,
+///         ctx: accounts::Context<'a, accounts::DepositAccounts<'a>>,
+///         amount: u64,
+///     ) -> ProgramResult {
+///         ..
+///     }
+/// }
+///
+/// entrypoint!(process_instruction);
+/// fn process_instruction<'a>(
+///     program_id: &Pubkey,
+///     accounts: &'a [AccountInfo<'a>],
+///     data: &[u8],
+/// ) -> ProgramResult {
+///     processor::dispatch::<Vault>(program_id, accounts, data)
+/// }
+/// ```
+///
 /// # Examples
 ///
 /// ```
@@ -241,7 +450,7 @@ pub fn shank_account(input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_derive(
     ShankInstruction,
-    attributes(account, legacy_optional_accounts_strategy)
+    attributes(account, legacy_optional_accounts_strategy, shank)
 )]
 pub fn shank_instruction(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -291,7 +500,35 @@ pub fn shank_instruction(input: TokenStream) -> TokenStream {
 ///    .build(additional_accounts)
 ///    .instruction();
 /// ```
-#[proc_macro_derive(ShankBuilder, attributes(account, args))]
+///
+/// # CPI
+///
+/// A `CreateCpi` _struct_ is generated as well, which programs use to invoke the instruction via
+/// CPI. It takes the account infos in place of the pubkeys, the account info of the invoked program
+/// as `__program` and the instruction data. Custom `#[args]` are omitted since they only feed the
+/// builders:
+///
+/// ```
+/// CreateCpi {
+///     __program: vault_program_info,
+///     vault: vault_info,
+///     authority: authority_info,
+///     payer: payer_info,
+///     system_program: system_program_info,
+///     args: CreateOrUpdateArgs { amount },
+/// }
+/// .invoke_signed(&[&[b"authority", &[bump]]])?;
+/// ```
+///
+/// Optional accounts are `Option`s which are replaced by the program id when not provided and
+/// optional signers have a `<account>_signer` flag, just like the builders.
+///
+/// The builders use the `Pubkey`, `Instruction` and `AccountMeta` of `solana_program` unless
+/// configured otherwise via `#[shank(crate_paths(..))]`, see _ShankAccount_. The CPI _structs_
+/// additionally use its `AccountInfo`, `ProgramError` and `invoke_signed`. Only the
+/// `solana_program` and `solana_sdk_split` presets are supported, the `pinocchio` preset is
+/// rejected with a compile error.
+#[proc_macro_derive(ShankBuilder, attributes(account, args, shank))]
 pub fn shank_builder(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derive_builder(input)
@@ -348,7 +585,63 @@ pub fn shank_builder(input: TokenStream) -> TokenStream {
 ///     ...
 /// }
 /// ```
-#[proc_macro_derive(ShankContext, attributes(account))]
+///
+/// # Checked Context
+///
+/// `context` only verifies that enough accounts were provided. Annotating the enum with
+/// `#[shank(context_checked)]` also derives `context_checked` which additionally verifies that
+/// each `signer` account signed and each `writable` account is writable, failing with a
+/// `ContextError` which names the offending account and wraps `MissingRequiredSignature` or
+/// `InvalidAccountData` respectively:
+///
+/// ```
+/// #[derive(ShankContext, ShankInstruction)]
+/// #[shank(context_checked)]
+/// pub enum Instruction {
+///     #[account(0, writable, name = "vault", desc = "Vault account")]
+///     #[account(1, signer, name = "authority", desc = "Authority of the vault")]
+///     Create(CreateArgs)
+/// }
+///
+/// let context = CreateAccounts::context_checked(accounts).map_err(|err| {
+///     msg!("Invalid {} account", err.account);
+///     err.error
+/// })?;
+/// ```
+///
+/// Accounts marked `optional_signer` may or may not sign and optional accounts are only checked
+/// when they are provided. `ContextError` converts into the `ProgramError` it wraps, thus `?`
+/// works in processors returning a `ProgramResult` as well.
+///
+/// The accounts refer to the `AccountInfo` and `ProgramError` of `solana_program` unless
+/// configured otherwise via `#[shank(crate_paths(..))]`, see _ShankAccount_.
+///
+/// # Pinocchio
+///
+/// Annotating the enum with `#[shank(pinocchio)]` generates the same accounts _structs_ over
+/// slices of pinocchio's `AccountInfo` instead. They don't allocate nor depend on `std` and thus
+/// work in `no_std` programs. Optional accounts are `None` when the program id is passed in their
+/// place and any accounts past the expected ones are provided as `remaining_accounts`.
+///
+/// The same enum can drive both implementations by only enabling the attribute for the pinocchio
+/// build:
+///
+/// ```
+/// #[derive(ShankContext, ShankInstruction)]
+/// #[cfg_attr(feature = "pinocchio", shank(pinocchio))]
+/// pub enum Instruction {
+///     #[account(0, writable, name = "vault", desc = "Vault account")]
+///     #[account(1, optional, name = "delegate", desc = "Optional delegate")]
+///     Create(CreateArgs)
+/// }
+///
+/// pub fn process_create(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+///     let context = CreateAccounts::context(accounts)?;
+///     let vault_key = context.accounts.vault.key();
+///     ...
+/// }
+/// ```
+#[proc_macro_derive(ShankContext, attributes(account, shank))]
 pub fn shank_context(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derive_context(input)
@@ -404,13 +697,34 @@ pub fn shank_context(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## `#[shank(zero_copy)]` attribute
+///
+/// Indicates that the struct is a `#[repr(C)]` type read via bytemuck instead of Borsh. shank-idl
+/// then computes the offset of each field including implicit padding as well as the size of the
+/// type and marks it with `"serialization": "bytemuck"`. All fields need a fixed layout, thus
+/// `String`, `Vec`, `Option`, tuples, maps and sets aren't supported and referenced custom types
+/// need to be `zero_copy` as well. `bool` isn't `Pod` and thus rejected too. The struct needs to be
+/// `#[repr(C)]` without `packed` or `align` modifiers since the offsets assume that layout.
+///
+/// ```
+/// use shank::ShankType;
+///
+/// #[derive(Clone, Copy, Pod, Zeroable, ShankType)]
+/// #[shank(zero_copy)]
+/// #[repr(C)]
+/// pub struct Position {
+///     pub active: u8,
+///     pub amount: u64,
+/// }
+/// ```
+///
 ///# Note
 ///
 /// The fields of a _ShankType_ struct or enum can reference other types as long as they are annotated
 /// with `ShankType`, `BorshSerialize` or `BorshDeserialize`.
 #[proc_macro_derive(
     ShankType,
-    attributes(padding, idl_name, idl_type, skip, pod_sentinel)
+    attributes(padding, idl_name, idl_type, skip, pod_sentinel, shank)
 )]
 pub fn shank_type(_input: TokenStream) -> TokenStream {
     // returns the token stream that was passed in (the macro is only an annotation for shank-idl
@@ -1,27 +1,45 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use shank_macro_impl::builder::Builder;
-use shank_macro_impl::syn::Result as ParseResult;
+use shank_macro_impl::syn::{Error as ParseError, Result as ParseResult};
 
 mod render_builders;
+mod render_cpi;
 use self::render_builders::generate_builders;
+use self::render_cpi::generate_cpi;
 
 pub fn render_builders_impl(
     builder_item: &Builder,
 ) -> ParseResult<TokenStream> {
+    let crate_paths = &builder_item.crate_paths;
+    if crate_paths.is_pinocchio() {
+        return Err(ParseError::new(
+            Span::call_site(),
+            "ShankBuilder only supports the solana_program and solana_sdk_split crate paths presets, not pinocchio whose instructions borrow their accounts and data",
+        ));
+    }
     let builders = builder_item
         .variants
         .iter()
-        .map(|variant| generate_builders(&builder_item.ident, variant))
+        .map(|variant| {
+            let builders =
+                generate_builders(&builder_item.ident, variant, crate_paths);
+            let cpi = generate_cpi(&builder_item.ident, variant, crate_paths);
+            quote! {
+                #builders
+                #cpi
+            }
+        })
         .collect::<Vec<TokenStream>>();
 
+    let instruction = &crate_paths.instruction;
     Ok(quote! {
             pub mod builders {
                 use super::*;
 
                 /// Trait that defines the interface for creating an instruction.
                 pub trait InstructionBuilder {
-                    fn instruction(&self) -> solana_program::instruction::Instruction;
+                    fn instruction(&self) -> #instruction;
                 }
 
                 #(#builders)*
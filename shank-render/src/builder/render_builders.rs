@@ -2,36 +2,163 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use shank_macro_impl::{
     builder::BuilderVariant,
-    instruction::InstructionVariantFields,
+    instruction::{
+        AccountDefault, InstructionAccount, InstructionVariantFields,
+    },
+    parsed_struct::{CratePaths, PUBKEY_TY},
     syn::{parse_str, Expr, ExprPath, Ident},
+    types::{Primitive, RustType, TypeKind, Value},
+    well_known::WELL_KNOWN_ACCOUNTS,
 };
 use std::collections::HashMap;
 
-const DEFAULT_PUBKEYS: [(&str, &str); 7] = [
-    ("system_program", "solana_program::system_program::ID"),
-    ("spl_token_program", "spl_token::ID"),
-    ("spl_token_2022_program", "spl_token_2022::ID"),
-    ("spl_ata_program", "spl_associated_token_account::ID"),
-    (
-        "sysvar_instructions",
-        "solana_program::sysvar::instructions::ID",
-    ),
-    ("token_metadata_program", "mpl_token_metadata::ID"),
-    ("authorization_rules_program", "mpl_token_auth_rules::ID"),
-];
+/// Renders the pubkey an account declared via `#[account(default = "..")]` or
+/// `#[account(address = "..")]` defaults to.
+fn render_account_default(
+    default: &AccountDefault,
+    crate_paths: &CratePaths,
+) -> TokenStream {
+    match default {
+        AccountDefault::Path(path) => {
+            let path = parse_str::<ExprPath>(path).unwrap();
+            quote! { #path }
+        }
+        AccountDefault::Address(_) => {
+            let pubkey = &crate_paths.pubkey;
+            let bytes = default.address_bytes().unwrap();
+            quote! { #pubkey::new_from_array([#(#bytes),*]) }
+        }
+        AccountDefault::Pda { .. } => {
+            unreachable!("pda accounts are derived via render_account_pda")
+        }
+    }
+}
 
-pub(crate) fn generate_builders(
-    item: &Ident,
+/// Renders deriving the account declared via `#[account(pda = Type, seeds(..))]`
+/// via the `shank_find_pda` fn of the account type, which derives it for the
+/// program the account belongs to.
+///
+/// Seeds naming accounts resolve to the values of those accounts while the
+/// ones naming args are taken from the args of the `build` fn.
+fn render_account_pda(
+    account_ty: &str,
+    seeds: &[String],
     variant: &BuilderVariant,
+    default_pubkeys: &HashMap<String, ExprPath>,
+    crate_paths: &CratePaths,
 ) -> TokenStream {
-    let default_pubkeys = DEFAULT_PUBKEYS
-        .iter()
-        .map(|(name, pubkey)| {
-            (name.to_string(), parse_str::<ExprPath>(pubkey).unwrap())
-        })
-        .collect::<HashMap<String, ExprPath>>();
+    let account_ty = parse_str::<ExprPath>(account_ty).unwrap();
+    let seed_args = seeds.iter().map(|seed| {
+        if let Some(seed_account) = variant
+            .accounts
+            .iter()
+            .find(|account| &account.name == seed)
+        {
+            let value = render_required_account(
+                seed_account,
+                None,
+                default_pubkeys,
+                crate_paths,
+            );
+            return quote! { &#value };
+        }
+        let arg = parse_str::<Ident>(seed).unwrap();
+        let by_value = match &variant.field_tys {
+            InstructionVariantFields::Named(fields) => fields
+                .iter()
+                .any(|(name, ty)| name == seed && is_seed_by_value(ty)),
+            InstructionVariantFields::Unnamed(_) => false,
+        };
+        if by_value {
+            quote! { #arg }
+        } else {
+            quote! { &#arg }
+        }
+    });
+    quote! { #account_ty::shank_find_pda(#(#seed_args),*).0 }
+}
+
+/// shank_find_pda takes integer and enum seeds by value in order to convert them
+/// to bytes, all other seeds are passed by reference.
+fn is_seed_by_value(ty: &RustType) -> bool {
+    match &ty.kind {
+        TypeKind::Primitive(primitive) => matches!(
+            primitive,
+            Primitive::U8
+                | Primitive::U16
+                | Primitive::U32
+                | Primitive::U64
+                | Primitive::I64
+        ),
+        TypeKind::Value(Value::Custom(_)) => ty.ident != PUBKEY_TY,
+        _ => false,
+    }
+}
+
+/// Renders the value of a non-optional account, falling back to its default
+/// if the account wasn't set.
+///
+/// PDAs are only derived when the `variant` is provided, otherwise they need
+/// to be set, which prevents deriving PDAs from each other.
+fn render_required_account(
+    account: &InstructionAccount,
+    variant: Option<&BuilderVariant>,
+    default_pubkeys: &HashMap<String, ExprPath>,
+    crate_paths: &CratePaths,
+) -> TokenStream {
+    let account_name = parse_str::<Ident>(&account.name).unwrap();
+    match (&account.default, variant) {
+        // does the account declare its pda?
+        (
+            Some(AccountDefault::Pda {
+                account: account_ty,
+                seeds,
+            }),
+            Some(variant),
+        ) => {
+            let pda = render_account_pda(
+                account_ty,
+                seeds,
+                variant,
+                default_pubkeys,
+                crate_paths,
+            );
+            quote! {
+                match self.#account_name {
+                    Some(#account_name) => #account_name,
+                    None => #pda,
+                }
+            }
+        }
+        (Some(AccountDefault::Pda { .. }), None) => quote! {
+            self.#account_name.ok_or(concat!(stringify!(#account_name), " is not set"))?
+        },
+        // does the account declare its default?
+        (Some(default), _) => {
+            let pubkey = render_account_default(default, crate_paths);
+            quote! {
+                self.#account_name.unwrap_or(#pubkey)
+            }
+        }
+        // are we dealing with a default pubkey?
+        (None, _) if default_pubkeys.contains_key(&account.name) => {
+            let pubkey = default_pubkeys.get(&account.name).unwrap();
+            // we add the default key as the fallback value
+            quote! {
+                self.#account_name.unwrap_or(#pubkey)
+            }
+        }
+        // if not a default pubkey, we will need to have it set
+        (None, _) => quote! {
+            self.#account_name.ok_or(concat!(stringify!(#account_name), " is not set"))?
+        },
+    }
+}
 
-    let field_names: Vec<Ident> = match &variant.field_tys {
+/// The names of the instruction fields, i.e. `args` and `args1` for tuple
+/// variants.
+pub(crate) fn instruction_field_names(variant: &BuilderVariant) -> Vec<Ident> {
+    match &variant.field_tys {
         InstructionVariantFields::Named(field_tys) => field_tys
             .iter()
             .map(|(name, _)| parse_str::<Ident>(name).unwrap())
@@ -51,7 +178,44 @@ pub(crate) fn generate_builders(
                 .unwrap()
             })
             .collect(),
-    };
+    }
+}
+
+pub(crate) fn generate_builders(
+    item: &Ident,
+    variant: &BuilderVariant,
+    crate_paths: &CratePaths,
+) -> TokenStream {
+    // Accounts named like a well known program or sysvar default to its id,
+    // the system program and instructions sysvar ids are taken from the
+    // crate paths instead
+    let mut default_pubkeys = WELL_KNOWN_ACCOUNTS
+        .iter()
+        .map(|account| {
+            (
+                account.name.to_string(),
+                parse_str::<ExprPath>(account.path).unwrap(),
+            )
+        })
+        .collect::<HashMap<String, ExprPath>>();
+    for (name, path) in [
+        ("system_program", &crate_paths.system_program),
+        ("sysvar_instructions", &crate_paths.sysvar_instructions),
+    ] {
+        default_pubkeys.insert(
+            name.to_string(),
+            ExprPath {
+                attrs: vec![],
+                qself: None,
+                path: path.clone(),
+            },
+        );
+    }
+    let pubkey = &crate_paths.pubkey;
+    let account_meta = &crate_paths.account_meta;
+    let instruction = &crate_paths.instruction;
+
+    let field_names = instruction_field_names(variant);
 
     // instruction struct
 
@@ -60,11 +224,11 @@ pub(crate) fn generate_builders(
         let account_name = parse_str::<Ident>(&account.name).unwrap();
         if account.optional {
             quote! {
-                pub #account_name: Option<solana_program::pubkey::Pubkey>
+                pub #account_name: Option<#pubkey>
             }
         } else {
             quote! {
-                pub #account_name: solana_program::pubkey::Pubkey
+                pub #account_name: #pubkey
             }
         }
     });
@@ -105,7 +269,7 @@ pub(crate) fn generate_builders(
     let builder_accounts = variant.accounts.iter().map(|account| {
         let account_name = parse_str::<Ident>(&account.name).unwrap();
         quote! {
-            pub #account_name: Option<solana_program::pubkey::Pubkey>
+            pub #account_name: Option<#pubkey>
         }
     });
 
@@ -176,7 +340,7 @@ pub(crate) fn generate_builders(
             if account.optional_signer {
                 let optional_signer = parse_str::<Ident>(&format!("{}_signer", account.name)).unwrap();
                 quote! {
-                    pub fn #account_name(&mut self, #account_name: solana_program::pubkey::Pubkey, signer: bool) -> &mut Self {
+                    pub fn #account_name(&mut self, #account_name: #pubkey, signer: bool) -> &mut Self {
                         self.#account_name = Some(#account_name);
                         self.#optional_signer = signer;
                         self
@@ -184,7 +348,7 @@ pub(crate) fn generate_builders(
                 }
             } else {
             quote! {
-                pub fn #account_name(&mut self, #account_name: solana_program::pubkey::Pubkey) -> &mut Self {
+                pub fn #account_name(&mut self, #account_name: #pubkey) -> &mut Self {
                     self.#account_name = Some(#account_name);
                     self
                 }
@@ -213,29 +377,24 @@ pub(crate) fn generate_builders(
 
     // required accounts
     let required_accounts = variant.accounts.iter().map(|account| {
-            let account_name = parse_str::<Ident>(&account.name).unwrap();
+        let account_name = parse_str::<Ident>(&account.name).unwrap();
 
-            if account.optional {
-                quote! {
-                    #account_name: self.#account_name
-                }
-            } else {
-                // are we dealing with a default pubkey?
-                if default_pubkeys.contains_key(&account.name) {
-                    let pubkey = default_pubkeys.get(&account.name).unwrap();
-                    // we add the default key as the fallback value
-                    quote! {
-                        #account_name: self.#account_name.unwrap_or(#pubkey)
-                    }
-                }
-                else {
-                    // if not a default pubkey, we will need to have it set
-                    quote! {
-                        #account_name: self.#account_name.ok_or(concat!(stringify!(#account_name), " is not set"))?
-                    }
-                }
+        if account.optional {
+            quote! {
+                #account_name: self.#account_name
             }
-        });
+        } else {
+            let value = render_required_account(
+                account,
+                Some(variant),
+                &default_pubkeys,
+                crate_paths,
+            );
+            quote! {
+                #account_name: #value
+            }
+        }
+    });
 
     // required optional signers
     let required_optional_signers = variant
@@ -340,31 +499,31 @@ pub(crate) fn generate_builders(
             if account.writable {
                 quote! {
                     if let Some(#account_name) = self.#account_name {
-                        solana_program::instruction::AccountMeta::new(#account_name, #signer)
+                        #account_meta::new(#account_name, #signer)
                     } else {
-                        solana_program::instruction::AccountMeta::new_readonly(crate::ID, false)
+                        #account_meta::new_readonly(crate::ID, false)
                     }
                 }
             } else if account.signer {
                 quote! {
                     if let Some(#account_name) = self.#account_name {
-                        solana_program::instruction::AccountMeta::new_readonly(#account_name, #signer)
+                        #account_meta::new_readonly(#account_name, #signer)
                     } else {
-                        solana_program::instruction::AccountMeta::new_readonly(crate::ID, false)
+                        #account_meta::new_readonly(crate::ID, false)
                     }
                 }
             } else {
                 quote!{
-                    solana_program::instruction::AccountMeta::new_readonly(self.#account_name.unwrap_or(crate::ID), false)
+                    #account_meta::new_readonly(self.#account_name.unwrap_or(crate::ID), false)
                 }
             }
         } else if account.writable {
             quote! {
-                solana_program::instruction::AccountMeta::new(self.#account_name, #signer)
+                #account_meta::new(self.#account_name, #signer)
             }
         } else {
             quote!{
-                solana_program::instruction::AccountMeta::new_readonly(self.#account_name, #signer)
+                #account_meta::new_readonly(self.#account_name, #signer)
             }
         }
     }).collect();
@@ -373,34 +532,17 @@ pub(crate) fn generate_builders(
     let name = &variant.ident;
     let builder_name = parse_str::<Ident>(&format!("{}Builder", name)).unwrap();
 
-    // instruction args list
-    let struct_instruction_args: Vec<TokenStream> = match &variant.field_tys {
-        InstructionVariantFields::Named(field_tys) => field_tys
-            .iter()
-            .enumerate()
-            .map(|(idx, _)| {
-                let name = field_names.get(idx).unwrap();
-                quote! { self.#name.clone() }
-            })
-            .collect(),
-        InstructionVariantFields::Unnamed(field_tys) => field_tys
-            .iter()
-            .enumerate()
-            .map(|(idx, _)| {
-                let name = field_names.get(idx).unwrap();
-                quote! { self.#name.clone() }
-            })
-            .collect(),
-    };
-
-    let instruction_data = if struct_instruction_args.is_empty() {
-        quote! {
+    // instruction data, named variants are built via their field names
+    let instruction_data = match &variant.field_tys {
+        _ if field_names.is_empty() => quote! {
             #item::#name.try_to_vec().unwrap()
-        }
-    } else {
-        quote! {
-            #item::#name(#(#struct_instruction_args,)*).try_to_vec().unwrap()
-        }
+        },
+        InstructionVariantFields::Named(_) => quote! {
+            #item::#name { #(#field_names: self.#field_names.clone(),)* }.try_to_vec().unwrap()
+        },
+        InstructionVariantFields::Unnamed(_) => quote! {
+            #item::#name(#(self.#field_names.clone(),)*).try_to_vec().unwrap()
+        },
     };
 
     // default instruction builder (only generated if the instruction builder does
@@ -408,8 +550,8 @@ pub(crate) fn generate_builders(
     let default_instruction_builder = if variant.arguments.is_empty() {
         quote! {
             impl InstructionBuilder for #name {
-                fn instruction(&self) -> solana_program::instruction::Instruction {
-                    solana_program::instruction::Instruction {
+                fn instruction(&self) -> #instruction {
+                    #instruction {
                         program_id: crate::ID,
                         accounts: vec![
                             #(#account_metas,)*
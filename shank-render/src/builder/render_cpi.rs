@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_macro_impl::{
+    builder::BuilderVariant,
+    instruction::InstructionVariantFields,
+    parsed_struct::CratePaths,
+    syn::{parse_str, Ident},
+};
+
+use super::render_builders::instruction_field_names;
+
+/// Generates the `*Cpi` struct of the instruction which invokes it from
+/// another program given the account infos and the instruction args.
+///
+/// Custom `#[args]` are omitted since they only feed the builders and aren't
+/// part of the instruction data.
+pub(crate) fn generate_cpi(
+    item: &Ident,
+    variant: &BuilderVariant,
+    crate_paths: &CratePaths,
+) -> TokenStream {
+    let account_info = &crate_paths.account_info;
+    let account_meta = &crate_paths.account_meta;
+    let instruction = &crate_paths.instruction;
+    let invoke_signed = &crate_paths.invoke_signed;
+    let program_error = &crate_paths.program_error;
+
+    let field_names = instruction_field_names(variant);
+    let field_tys = match &variant.field_tys {
+        InstructionVariantFields::Named(field_tys) => field_tys
+            .iter()
+            .map(|(_, ty)| &ty.ident)
+            .collect::<Vec<_>>(),
+        InstructionVariantFields::Unnamed(field_tys) => {
+            field_tys.iter().map(|ty| &ty.ident).collect::<Vec<_>>()
+        }
+    };
+
+    // accounts
+    let struct_accounts = variant.accounts.iter().map(|account| {
+        let account_name = parse_str::<Ident>(&account.name).unwrap();
+        if account.optional {
+            quote! {
+                pub #account_name: Option<&'b #account_info<'a>>
+            }
+        } else {
+            quote! {
+                pub #account_name: &'b #account_info<'a>
+            }
+        }
+    });
+
+    // optional signers
+    let struct_optional_signers = variant
+        .accounts
+        .iter()
+        .filter(|account| account.optional_signer)
+        .map(|account| {
+            let optional_signer =
+                parse_str::<Ident>(&format!("{}_signer", account.name))
+                    .unwrap();
+            quote! {
+                pub #optional_signer: bool
+            }
+        });
+
+    // instruction args
+    let struct_args =
+        field_names.iter().zip(field_tys.iter()).map(|(name, ty)| {
+            quote! {
+                pub #name: #ty
+            }
+        });
+
+    // account metas, optional accounts which aren't provided are replaced
+    // with the program id
+    let account_metas = variant.accounts.iter().map(|account| {
+        let account_name = parse_str::<Ident>(&account.name).unwrap();
+        let signer = if account.optional_signer {
+            let optional_signer =
+                parse_str::<Ident>(&format!("{}_signer", account.name))
+                    .unwrap();
+            quote! { self.#optional_signer }
+        } else {
+            let signer = account.signer;
+            quote! { #signer }
+        };
+        let new_meta = if account.writable {
+            quote! { #account_meta::new }
+        } else {
+            quote! { #account_meta::new_readonly }
+        };
+
+        if account.optional {
+            quote! {
+                if let Some(#account_name) = self.#account_name {
+                    #new_meta(*#account_name.key, #signer)
+                } else {
+                    #account_meta::new_readonly(crate::ID, false)
+                }
+            }
+        } else {
+            quote! {
+                #new_meta(*self.#account_name.key, #signer)
+            }
+        }
+    });
+
+    // account infos, the program is included to back omitted optional accounts
+    let account_infos = variant.accounts.iter().map(|account| {
+        let account_name = parse_str::<Ident>(&account.name).unwrap();
+        if account.optional {
+            quote! {
+                if let Some(#account_name) = self.#account_name {
+                    account_infos.push(#account_name.clone());
+                }
+            }
+        } else {
+            quote! {
+                account_infos.push(self.#account_name.clone());
+            }
+        }
+    });
+
+    let name = &variant.ident;
+    let cpi_name = parse_str::<Ident>(&format!("{}Cpi", name)).unwrap();
+    let expected = variant.accounts.len() + 1;
+
+    let instruction_data = match &variant.field_tys {
+        _ if field_names.is_empty() => quote! {
+            #item::#name.try_to_vec().unwrap()
+        },
+        InstructionVariantFields::Named(_) => quote! {
+            #item::#name { #(#field_names: self.#field_names.clone(),)* }.try_to_vec().unwrap()
+        },
+        InstructionVariantFields::Unnamed(_) => quote! {
+            #item::#name(#(self.#field_names.clone(),)*).try_to_vec().unwrap()
+        },
+    };
+
+    quote! {
+        pub struct #cpi_name<'a, 'b> {
+            /// The account info of the program being invoked.
+            pub __program: &'b #account_info<'a>,
+            #(#struct_accounts,)*
+            #(#struct_optional_signers,)*
+            #(#struct_args,)*
+        }
+
+        impl<'a, 'b> #cpi_name<'a, 'b> {
+            pub fn instruction(&self) -> #instruction {
+                #instruction {
+                    program_id: crate::ID,
+                    accounts: vec![
+                        #(#account_metas,)*
+                    ],
+                    data: #instruction_data,
+                }
+            }
+
+            pub fn invoke(&self) -> Result<(), #program_error> {
+                self.invoke_signed(&[])
+            }
+
+            pub fn invoke_signed(
+                &self,
+                signers_seeds: &[&[&[u8]]],
+            ) -> Result<(), #program_error> {
+                let mut account_infos = Vec::with_capacity(#expected);
+                account_infos.push(self.__program.clone());
+                #(#account_infos)*
+
+                #invoke_signed(&self.instruction(), &account_infos, signers_seeds)
+            }
+        }
+    }
+}
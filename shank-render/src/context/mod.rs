@@ -5,25 +5,59 @@ use shank_macro_impl::syn::Result as ParseResult;
 
 mod render_context;
 use self::render_context::generate_context;
+pub(crate) use self::render_context::{account_info_ty, accounts_ty};
 
 pub fn render_contexts_impl(
     instruction: &Instruction,
 ) -> ParseResult<TokenStream> {
+    let crate_paths = &instruction.crate_paths;
     let contexts = instruction
         .variants
         .iter()
-        .map(generate_context)
+        .map(|variant| {
+            generate_context(variant, crate_paths, instruction.context_checked)
+        })
         .collect::<Vec<TokenStream>>();
 
+    let account_info = account_info_ty(crate_paths);
+    let program_error = &crate_paths.program_error;
+    let context_error = if instruction.context_checked {
+        quote! {
+            /// The error returned by `context_checked` naming the account which
+            /// failed validation.
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct ContextError {
+                pub account: &'static str,
+                pub error: #program_error,
+            }
+
+            impl ContextError {
+                pub fn new(account: &'static str, error: #program_error) -> Self {
+                    Self { account, error }
+                }
+            }
+
+            impl From<ContextError> for #program_error {
+                fn from(error: ContextError) -> Self {
+                    error.error
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
     Ok(quote! {
         pub mod accounts {
             use super::*;
 
             pub struct Context<'a, T> {
                 pub accounts: T,
-                pub remaining_accounts: &'a [solana_program::account_info::AccountInfo<'a>],
+                pub remaining_accounts: &'a [#account_info],
             }
 
+            #context_error
+
             #(#contexts)*
         }
     })
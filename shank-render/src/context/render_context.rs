@@ -1,51 +1,153 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use shank_macro_impl::{instruction::InstructionVariant, syn};
+use quote::{format_ident, quote};
+use shank_macro_impl::{
+    instruction::InstructionVariant, parsed_struct::CratePaths, syn,
+};
+
+/// The `AccountInfo` type the contexts refer to, which only has a lifetime
+/// outside of pinocchio.
+pub(crate) fn account_info_ty(crate_paths: &CratePaths) -> TokenStream {
+    let account_info = &crate_paths.account_info;
+    if crate_paths.is_pinocchio() {
+        quote! { #account_info }
+    } else {
+        quote! { #account_info<'a> }
+    }
+}
+
+/// The `*Accounts` type of the variant, which has no lifetime if the variant
+/// doesn't declare any accounts.
+pub(crate) fn accounts_ty(variant: &InstructionVariant) -> TokenStream {
+    let name = format_ident!("{}Accounts", variant.ident);
+    if variant.accounts.is_empty() {
+        quote! { #name }
+    } else {
+        quote! { #name<'a> }
+    }
+}
+
+pub(crate) fn generate_context(
+    variant: &InstructionVariant,
+    crate_paths: &CratePaths,
+    context_checked: bool,
+) -> TokenStream {
+    if variant.accounts.is_empty() {
+        return generate_empty_context(variant, crate_paths, context_checked);
+    }
+
+    let account_info = account_info_ty(crate_paths);
+    let program_error = &crate_paths.program_error;
 
-pub(crate) fn generate_context(variant: &InstructionVariant) -> TokenStream {
     // accounts fields
     let struct_fields = variant.accounts.iter().map(|account| {
+        let account_name = syn::parse_str::<syn::Ident>(&account.name).unwrap();
+        if account.optional {
+            quote! {
+                pub #account_name: Option<&'a #account_info>
+            }
+        } else {
+            quote! {
+                pub #account_name:&'a #account_info
+            }
+        }
+    });
+
+    // accounts initialization
+    let account_fields = variant.accounts.iter().enumerate().map(|(index, account)| {
             let account_name = syn::parse_str::<syn::Ident>(&account.name).unwrap();
             if account.optional {
+                let account_key = crate_paths.render_account_key(&quote! { accounts[#index] });
                 quote! {
-                    pub #account_name: Option<&'a solana_program::account_info::AccountInfo<'a>>
+                    #account_name: if #account_key == &crate::ID { None } else { Some(&accounts[#index]) }
                 }
             } else {
                 quote! {
-                    pub #account_name:&'a solana_program::account_info::AccountInfo<'a>
+                    #account_name: &accounts[#index]
                 }
             }
-        });
+        }).collect::<Vec<TokenStream>>();
 
-    // accounts initialization
-    let account_fields = variant.accounts.iter().enumerate().map(|(index, account)| {
-            let account_name = syn::parse_str::<syn::Ident>(&account.name).unwrap();
-            if account.optional {
+    // account checks, optional accounts are only checked when provided
+    let account_checks = variant.accounts.iter().enumerate().filter_map(|(index, account)| {
+            let name = &account.name;
+            let info = quote! { accounts[#index] };
+            let signer_check = if account.signer && !account.optional_signer {
+                let is_signer = crate_paths.render_account_is_signer(&info);
                 quote! {
-                    #account_name: if accounts[#index].key == &crate::ID { None } else { Some(&accounts[#index]) }
+                    if !#is_signer {
+                        return Err(ContextError::new(#name, #program_error::MissingRequiredSignature));
+                    }
                 }
             } else {
+                TokenStream::new()
+            };
+            let writable_check = if account.writable {
+                let is_writable = crate_paths.render_account_is_writable(&info);
                 quote! {
-                    #account_name: &accounts[#index]
+                    if !#is_writable {
+                        return Err(ContextError::new(#name, #program_error::InvalidAccountData));
+                    }
                 }
+            } else {
+                TokenStream::new()
+            };
+            if signer_check.is_empty() && writable_check.is_empty() {
+                None
+            } else if account.optional {
+                let account_key = crate_paths.render_account_key(&info);
+                Some(quote! {
+                    if #account_key != &crate::ID {
+                        #signer_check
+                        #writable_check
+                    }
+                })
+            } else {
+                Some(quote! {
+                    #signer_check
+                    #writable_check
+                })
             }
         });
 
+    let account_names = variant.accounts.iter().map(|account| &account.name);
     let expected = variant.accounts.len(); // number of expected accounts
     let name =
         syn::parse_str::<syn::Ident>(&format!("{}Accounts", variant.ident))
             .unwrap();
 
+    let context_checked = if context_checked {
+        quote! {
+            /// Creates the context after verifying that the accounts are signers and writable
+            /// as declared by the instruction, naming the first account which isn't.
+            pub fn context_checked(
+                accounts: &'a [#account_info]
+            ) -> Result<Context<'a, Self>, ContextError> {
+                if accounts.len() < #expected {
+                    let names: [&'static str; #expected] = [#(#account_names),*];
+                    return Err(ContextError::new(names[accounts.len()], #program_error::NotEnoughAccountKeys));
+                }
+                #(#account_checks)*
+
+                Ok(Context {
+                    accounts: Self { #(#account_fields,)* },
+                    remaining_accounts: &accounts[#expected..],
+                })
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
     quote! {
         pub struct #name<'a> {
             #(#struct_fields,)*
         }
         impl<'a> #name<'a> {
             pub fn context(
-                accounts: &'a [solana_program::account_info::AccountInfo<'a>]
-            ) -> Result<Context<'a, Self>, solana_program::sysvar::slot_history::ProgramError> {
+                accounts: &'a [#account_info]
+            ) -> Result<Context<'a, Self>, #program_error> {
                 if accounts.len() < #expected {
-                    return Err(solana_program::sysvar::slot_history::ProgramError::NotEnoughAccountKeys);
+                    return Err(#program_error::NotEnoughAccountKeys);
                 }
 
                 Ok(Context {
@@ -53,6 +155,53 @@ pub(crate) fn generate_context(variant: &InstructionVariant) -> TokenStream {
                     remaining_accounts: &accounts[#expected..],
                 })
             }
+
+            #context_checked
+        }
+    }
+}
+
+/// Generates the context of a variant without accounts as a unit struct since
+/// a struct without fields can't hold on to the lifetime of the accounts. All
+/// provided accounts are passed on as `remaining_accounts`.
+fn generate_empty_context(
+    variant: &InstructionVariant,
+    crate_paths: &CratePaths,
+    context_checked: bool,
+) -> TokenStream {
+    let account_info = account_info_ty(crate_paths);
+    let program_error = &crate_paths.program_error;
+    let name = format_ident!("{}Accounts", variant.ident);
+
+    let context_checked = if context_checked {
+        quote! {
+            /// Creates the context, there are no accounts to verify.
+            pub fn context_checked<'a>(
+                accounts: &'a [#account_info]
+            ) -> Result<Context<'a, Self>, ContextError> {
+                Ok(Context {
+                    accounts: Self,
+                    remaining_accounts: accounts,
+                })
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        pub struct #name;
+        impl #name {
+            pub fn context<'a>(
+                accounts: &'a [#account_info]
+            ) -> Result<Context<'a, Self>, #program_error> {
+                Ok(Context {
+                    accounts: Self,
+                    remaining_accounts: accounts,
+                })
+            }
+
+            #context_checked
         }
     }
 }
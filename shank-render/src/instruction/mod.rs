@@ -0,0 +1,167 @@
+use std::convert::TryFrom;
+
+use heck::ShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use shank_macro_impl::{
+    instruction::{Instruction, InstructionVariant, InstructionVariantFields},
+    syn::{Error as ParseError, Ident, Result as ParseResult},
+};
+
+/// The names and types of the args of the instruction variant, unnamed args
+/// are named like the args of the builders.
+pub(crate) fn variant_args(
+    variant: &InstructionVariant,
+) -> Vec<(Ident, TokenStream)> {
+    match &variant.field_tys {
+        InstructionVariantFields::Named(field_tys) => field_tys
+            .iter()
+            .map(|(name, ty)| (format_ident!("{}", name), ty.render()))
+            .collect(),
+        InstructionVariantFields::Unnamed(field_tys) => field_tys
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let name = if idx == 0 {
+                    format_ident!("args")
+                } else {
+                    format_ident!("args{}", idx)
+                };
+                (name, ty.render())
+            })
+            .collect(),
+    }
+}
+
+/// Renders the pattern matching the variant and binding its args.
+pub(crate) fn render_variant_pattern(
+    item: &Ident,
+    variant: &InstructionVariant,
+) -> TokenStream {
+    let name = &variant.ident;
+    let args = variant_args(variant);
+    let arg_names = args.iter().map(|(name, _)| name);
+    match &variant.field_tys {
+        _ if args.is_empty() => quote! { #item::#name },
+        InstructionVariantFields::Named(_) => {
+            quote! { #item::#name { #(#arg_names),* } }
+        }
+        InstructionVariantFields::Unnamed(_) => {
+            quote! { #item::#name(#(#arg_names),*) }
+        }
+    }
+}
+
+/// The discriminant of the variant which the instruction data starts with,
+/// matching the one of the IDL.
+pub(crate) fn variant_discriminant(
+    variant: &InstructionVariant,
+) -> ParseResult<u8> {
+    u8::try_from(variant.discriminant).map_err(|_| {
+        ParseError::new_spanned(
+            &variant.ident,
+            format!(
+                "Instruction variant discriminants have to be <= u8::MAX ({}), \
+                 but the discriminant of variant '{}' is {}",
+                u8::MAX,
+                variant.ident,
+                variant.discriminant
+            ),
+        )
+    })
+}
+
+/// Name of the const holding the discriminant of the variant, i.e.
+/// `CREATE_VAULT_DISCRIMINANT`.
+fn discriminant_const(variant: &InstructionVariant) -> Ident {
+    format_ident!(
+        "{}_DISCRIMINANT",
+        variant.ident.to_string().to_shouty_snake_case()
+    )
+}
+
+/// Renders the discriminant consts of the variants along with `discriminant`
+/// and `data` which serializes the instruction as described by the IDL, i.e.
+/// the discriminant followed by the borsh serialized args.
+pub fn render_instruction_data_impl(
+    instruction: &Instruction,
+) -> ParseResult<TokenStream> {
+    let item = &instruction.ident;
+
+    let mut discriminant_consts =
+        Vec::with_capacity(instruction.variants.len());
+    for variant in &instruction.variants {
+        let discriminant = variant_discriminant(variant)?;
+        let discriminant_const = discriminant_const(variant);
+        let doc = format!(
+            " The discriminant of the `{}` instruction.",
+            variant.ident
+        );
+        discriminant_consts.push(quote! {
+            #[doc = #doc]
+            pub const #discriminant_const: u8 = #discriminant;
+        });
+    }
+
+    let discriminant_variants = instruction.variants.iter().map(|variant| {
+        let name = &variant.ident;
+        let discriminant_const = discriminant_const(variant);
+        let pattern = match &variant.field_tys {
+            _ if variant_args(variant).is_empty() => quote! { #item::#name },
+            InstructionVariantFields::Named(_) => {
+                quote! { #item::#name { .. } }
+            }
+            InstructionVariantFields::Unnamed(_) => quote! { #item::#name(..) },
+        };
+        quote! { #pattern => Self::#discriminant_const }
+    });
+
+    // Instructions without any args consist of the discriminant only
+    let has_args = instruction
+        .variants
+        .iter()
+        .any(|variant| !variant_args(variant).is_empty());
+    let data = if has_args {
+        let serialize_variants = instruction.variants.iter().map(|variant| {
+            let pattern = render_variant_pattern(item, variant);
+            let arg_names =
+                variant_args(variant).into_iter().map(|(name, _)| name);
+            quote! {
+                #pattern => {
+                    #(
+                        ::borsh::BorshSerialize::serialize(#arg_names, &mut data)
+                            .expect("serializing into a Vec does not fail");
+                    )*
+                }
+            }
+        });
+        quote! {
+            let mut data = vec![self.discriminant()];
+            match self {
+                #(#serialize_variants,)*
+            }
+            data
+        }
+    } else {
+        quote! { vec![self.discriminant()] }
+    };
+
+    Ok(quote! {
+        impl #item {
+            #(#discriminant_consts)*
+
+            /// The discriminant the data of the instruction starts with.
+            pub fn discriminant(&self) -> u8 {
+                match self {
+                    #(#discriminant_variants,)*
+                }
+            }
+
+            /// The data of the instruction, i.e. its discriminant followed by the
+            /// borsh serialized args.
+            pub fn data(&self) -> Vec<u8> {
+                #data
+            }
+        }
+    })
+}
@@ -1,4 +1,7 @@
 pub mod builder;
 pub mod consts;
 pub mod context;
+pub mod instruction;
+pub mod loaders;
 pub mod pda;
+pub mod processor;
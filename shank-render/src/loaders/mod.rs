@@ -0,0 +1,107 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use shank_macro_impl::{
+    parsed_struct::StructAttrs,
+    syn::{Ident, Result as ParseResult},
+};
+
+use crate::pda::{render_verify_pda_fn, try_process_seeds};
+
+/// Renders the `from_account_info` and `from_bytes` loaders of an account
+/// annotated with `#[shank(loaders)]` as well as `verify_pda` if it has seeds.
+pub fn render_loaders_impl(
+    struct_attrs: &StructAttrs,
+    account_type_ident: &Ident,
+) -> ParseResult<TokenStream> {
+    let loaders = match struct_attrs.loaders() {
+        Some(loaders) => loaders,
+        None => return Ok(TokenStream::new()),
+    };
+
+    let crate_paths = struct_attrs.crate_paths();
+    let account_info = &crate_paths.account_info;
+    let program_error = &crate_paths.program_error;
+    let is_not_owned_by_program = crate_paths
+        .render_is_not_owned_by(&quote! { account }, &quote! { &crate::ID });
+
+    let min_len = Literal::usize_unsuffixed(loaders.min_len());
+    let min_len_check = if loaders.min_len() > 0 {
+        quote! {
+            if data.len() < #min_len {
+                return Err(#program_error::AccountDataTooSmall);
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let discriminator_check = if loaders.discriminator.is_empty() {
+        TokenStream::new()
+    } else {
+        let len = Literal::usize_unsuffixed(loaders.discriminator.len());
+        let bytes = loaders
+            .discriminator
+            .iter()
+            .map(|byte| Literal::u8_unsuffixed(*byte));
+        quote! {
+            if data[..#len] != [#(#bytes),*] {
+                return Err(#program_error::InvalidAccountData);
+            }
+        }
+    };
+
+    // Zero-copy accounts are read via bytemuck which requires the data to
+    // cover the entire struct while Borsh accounts can have variable length
+    let deserialize = if struct_attrs.is_zero_copy() {
+        quote! {
+            let len = ::core::mem::size_of::<Self>();
+            if data.len() < len {
+                return Err(#program_error::AccountDataTooSmall);
+            }
+            ::bytemuck::try_pod_read_unaligned(&data[..len])
+                .map_err(|_| #program_error::InvalidAccountData)
+        }
+    } else {
+        quote! {
+            ::borsh::BorshDeserialize::deserialize(&mut &data[..])
+                .map_err(|_| #program_error::InvalidAccountData)
+        }
+    };
+
+    let processed_seeds = try_process_seeds(struct_attrs)?;
+    let verify_pda_fn = render_verify_pda_fn(
+        &processed_seeds,
+        &Ident::new("shank_pda", Span::call_site()),
+        &Ident::new("verify_pda", Span::call_site()),
+        struct_attrs.seeds_bump(),
+        struct_attrs.seeds_program(),
+        &crate_paths,
+    );
+
+    Ok(quote! {
+        impl #account_type_ident {
+            /// Verifies that the account is owned by this program and deserializes its data.
+            #[allow(unused)]
+            pub fn from_account_info(
+                account: &#account_info,
+            ) -> Result<Self, #program_error> {
+                if #is_not_owned_by_program {
+                    return Err(#program_error::IllegalOwner);
+                }
+                let data = account.try_borrow_data()?;
+                Self::from_bytes(&data)
+            }
+
+            /// Verifies the length and discriminator of the data and deserializes it,
+            /// ignoring any trailing bytes.
+            #[allow(unused)]
+            pub fn from_bytes(data: &[u8]) -> Result<Self, #program_error> {
+                #min_len_check
+                #discriminator_check
+                #deserialize
+            }
+
+            #verify_pda_fn
+        }
+    })
+}
@@ -22,6 +22,7 @@ pub fn render_pda_and_seeds_impl(
     if processed_seeds.is_empty() {
         return Ok(TokenStream::new());
     }
+    let crate_paths = struct_attrs.crate_paths();
 
     let seeds_fn_ident = Ident::new("shank_seeds", Span::call_site());
     let seeds_fn_with_bump_ident =
@@ -29,12 +30,16 @@ pub fn render_pda_and_seeds_impl(
     let pda_fn_ident = Ident::new("shank_pda", Span::call_site());
     let pda_fn_with_bump_ident =
         Ident::new("shank_pda_with_bump", Span::call_site());
+    let find_pda_fn_ident = Ident::new("shank_find_pda", Span::call_site());
+    let create_pda_fn_ident = Ident::new("shank_create_pda", Span::call_site());
+    let verify_pda_fn_ident = Ident::new("shank_verify_pda", Span::call_site());
 
     let pub_seeds_fn = try_render_seeds_fn(
         &processed_seeds,
         &seeds_fn_ident,
         &seeds_fn_with_bump_ident,
         include_comments,
+        &crate_paths,
     )?;
     let pub_pda_fn = render_pda_fn(
         &processed_seeds,
@@ -43,16 +48,63 @@ pub fn render_pda_and_seeds_impl(
         &pda_fn_ident,
         &pda_fn_with_bump_ident,
         include_comments,
+        struct_attrs.seeds_program(),
+        &crate_paths,
     );
+    let pub_find_pda_fn = render_find_pda_fn(
+        &processed_seeds,
+        &pda_fn_ident,
+        &find_pda_fn_ident,
+        include_comments,
+        struct_attrs.seeds_program(),
+        &crate_paths,
+    );
+    let pub_create_pda_fn = render_create_pda_fn(
+        &processed_seeds,
+        &seeds_fn_with_bump_ident,
+        &create_pda_fn_ident,
+        &verify_pda_fn_ident,
+        include_comments,
+        struct_attrs.seeds_program(),
+        &crate_paths,
+    );
+    let pub_stored_bump_pda_fn =
+        struct_attrs.seeds_bump().and_then(|bump_field| {
+            render_stored_bump_pda_fn(
+                &processed_seeds,
+                &create_pda_fn_ident,
+                &verify_pda_fn_ident,
+                bump_field,
+                struct_attrs.seeds_program(),
+                &crate_paths,
+            )
+        });
 
     if let (Some(pub_seeds_fn), Some(pub_pda_fn)) = (pub_seeds_fn, pub_pda_fn) {
         Ok(quote! {
             impl #account_type_ident {
                 #pub_seeds_fn
                 #pub_pda_fn
+                #pub_find_pda_fn
+                #pub_create_pda_fn
+                #pub_stored_bump_pda_fn
             }
         })
     } else {
         Ok(TokenStream::new())
     }
 }
+
+/// Renders the compile time assertions of the seed lengths of an account,
+/// see [render_seeds_len_assertions].
+pub fn render_seeds_assertions_impl(
+    struct_attrs: &StructAttrs,
+    account_type_ident: &Ident,
+) -> ParseResult<TokenStream> {
+    let processed_seeds = try_process_seeds(struct_attrs)?;
+    Ok(render_seeds_len_assertions(
+        &processed_seeds,
+        account_type_ident,
+        &struct_attrs.crate_paths(),
+    ))
+}
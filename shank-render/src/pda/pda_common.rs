@@ -1,6 +1,12 @@
+use proc_macro2::TokenStream;
+use quote::quote;
 use shank_macro_impl::{
-    parsed_struct::{ProcessedSeed, SeedArg, StructAttr, StructAttrs},
-    syn::Result as ParseResult,
+    parsed_struct::{
+        CratePaths, ProcessedSeed, SeedArg, SeedsProgram, StructAttr,
+        StructAttrs, FULL_ACCOUNT_INFO_TY, FULL_PUBKEY_TY,
+    },
+    syn::{parse_str, ExprPath, Result as ParseResult},
+    types::{RustType, TypeKind, Value},
 };
 
 pub fn try_process_seeds(
@@ -11,7 +17,7 @@ pub fn try_process_seeds(
         .iter()
         .filter_map(|attr| match attr {
             StructAttr::Seeds(seeds) => Some(seeds),
-            StructAttr::PodSentinel(_) => None,
+            _ => None,
         })
         .collect::<Vec<_>>();
 
@@ -43,3 +49,45 @@ pub fn render_args_comments(
         })
         .collect()
 }
+
+/// Replaces the `solana_program` types of seed args with the ones at the
+/// configured crate paths.
+pub fn with_crate_paths(ty: RustType, crate_paths: &CratePaths) -> RustType {
+    let path = match &ty.kind {
+        TypeKind::Value(Value::Custom(x)) if x == FULL_PUBKEY_TY => {
+            &crate_paths.pubkey
+        }
+        TypeKind::Value(Value::Custom(x)) if x == FULL_ACCOUNT_INFO_TY => {
+            &crate_paths.account_info
+        }
+        _ => return ty,
+    };
+    RustType {
+        kind: TypeKind::Value(Value::Custom(quote! { #path }.to_string())),
+        ..ty
+    }
+}
+
+/// Renders the id of the program the PDA is derived for when it isn't the
+/// program itself.
+pub fn render_seeds_program(
+    program: &SeedsProgram,
+    crate_paths: &CratePaths,
+) -> TokenStream {
+    match program {
+        SeedsProgram::Path(path) => {
+            let path = parse_str::<ExprPath>(path).unwrap();
+            quote! { #path }
+        }
+        SeedsProgram::Address(_) => {
+            let bytes = program.address_bytes().unwrap();
+            // pinocchio pubkeys are plain byte arrays
+            if crate_paths.is_pinocchio() {
+                quote! { [#(#bytes),*] }
+            } else {
+                let pubkey = &crate_paths.pubkey;
+                quote! { #pubkey::new_from_array([#(#bytes),*]) }
+            }
+        }
+    }
+}
@@ -1,14 +1,15 @@
 use proc_macro2::{Span, TokenStream};
-use quote::{quote, ToTokens};
+use quote::quote;
 use shank_macro_impl::{
-    parsed_struct::{ProcessedSeed, Seed},
+    parsed_struct::{
+        CratePaths, ProcessedSeed, Seed, SeedEncoding, SeedsProgram,
+    },
     syn::Ident,
 };
 
-use crate::consts::solana_program_pubkey;
-
-use super::render_args_comments;
+use super::{render_args_comments, render_seeds_program, with_crate_paths};
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_pda_fn(
     processed_seeds: &[ProcessedSeed],
     seeds_fn_name: &Ident,
@@ -16,44 +17,48 @@ pub fn render_pda_fn(
     pda_fn_name: &Ident,
     pda_fn_with_bump_name: &Ident,
     include_comments: bool,
+    seeds_program: Option<&SeedsProgram>,
+    crate_paths: &CratePaths,
 ) -> Option<TokenStream> {
+    if processed_seeds.is_empty() {
+        return None;
+    }
     let RenderedPdaParts {
         seed_param_assigns,
         seed_fn_args,
         pda_fn_args,
-    } = render_pda_parts(processed_seeds);
-    if pda_fn_args.is_empty() {
-        return None;
-    }
+    } = render_pda_parts(processed_seeds, seeds_program, crate_paths);
 
-    let pubkey = solana_program_pubkey();
-    let seed_bump_arg = if seed_fn_args.is_empty() {
-        quote! { bump_arg }
-    } else {
-        quote! { , bump_arg }
-    };
+    let pubkey = &crate_paths.pubkey;
+    let pubkey_error = &crate_paths.pubkey_error;
+    let find_program_address = &crate_paths.find_program_address;
+    let create_program_address = &crate_paths.create_program_address;
     let (pda_comments, pda_with_bump_comments) = if include_comments {
         let args_comments = render_args_comments(processed_seeds, true);
+        let program_id_comment = render_program_id_comment(seeds_program);
         (
             format!(
                 r#"
                 /// Derives the PDA for this account.
-                ///
-                /// * **program_id**: The id of the program
+                {}
                 {}"#,
+                program_id_comment,
                 args_comments.join("\n")
             )
-            .to_token_stream(),
+            .parse::<TokenStream>()
+            .expect("doc comments should always be valid tokens"),
             format!(
                 r#"
-                /// Derives the PDA for this account allowing to provide a bump seed.
-                ///
-                /// * **program_id**: The id of the program
+                /// Creates the PDA for this account from the provided bump seed, failing if
+                /// the seeds and bump don't result in a valid PDA.
                 {}
-                /// * **bump**: the bump seed to pass when deriving the PDA"#,
+                {}
+                /// * **bump**: the bump seed of the PDA"#,
+                program_id_comment,
                 args_comments.join("\n")
             )
-            .to_token_stream(),
+            .parse::<TokenStream>()
+            .expect("doc comments should always be valid tokens"),
         )
     } else {
         (TokenStream::new(), TokenStream::new())
@@ -65,19 +70,306 @@ pub fn render_pda_fn(
         pub fn #pda_fn_name(#(#pda_fn_args),*) -> (#pubkey, u8)  {
             #(#seed_param_assigns)*
             let seeds = Self::#seeds_fn_name(#(#seed_fn_args),*);
-            #pubkey::find_program_address(&seeds, program_id)
+            #find_program_address(&seeds, program_id)
         }
         #pda_with_bump_comments
         #[allow(unused)]
-        pub fn #pda_fn_with_bump_name(#(#pda_fn_args),*, bump: u8) -> (#pubkey, u8)  {
+        pub fn #pda_fn_with_bump_name(#(#pda_fn_args,)* bump: u8) -> Result<(#pubkey, u8), #pubkey_error>  {
+            #(#seed_param_assigns)*
+            let bump_arg = &[bump];
+            let seeds = Self::#seeds_fn_with_bump_name(#(#seed_fn_args,)* bump_arg);
+            let pda = #create_program_address(&seeds, program_id)?;
+            Ok((pda, bump))
+        }
+    })
+}
+
+/// Renders a fn deriving the PDA for the program the account belongs to,
+/// which is `crate::ID` unless it is derived for another program.
+///
+/// It takes the same seed args either way and thus gives the builders a
+/// single way to derive the PDA of an account.
+pub fn render_find_pda_fn(
+    processed_seeds: &[ProcessedSeed],
+    pda_fn_name: &Ident,
+    find_pda_fn_name: &Ident,
+    include_comments: bool,
+    seeds_program: Option<&SeedsProgram>,
+    crate_paths: &CratePaths,
+) -> Option<TokenStream> {
+    if processed_seeds.is_empty() {
+        return None;
+    }
+    let RenderedPdaParts { pda_fn_args, .. } =
+        render_pda_parts(processed_seeds, seeds_program, crate_paths);
+    let pda_fn_arg_idents =
+        render_pda_fn_arg_idents(processed_seeds, seeds_program);
+
+    // Unless the PDA is derived for another program the program id is the
+    // first arg and is provided via `crate::ID`
+    let (program_id_arg, seed_args, seed_arg_idents) = match seeds_program {
+        Some(_) => (None, &pda_fn_args[..], &pda_fn_arg_idents[..]),
+        None => (
+            Some(quote! { &crate::ID, }),
+            &pda_fn_args[1..],
+            &pda_fn_arg_idents[1..],
+        ),
+    };
+
+    let pubkey = &crate_paths.pubkey;
+    let find_comments = if include_comments {
+        let args_comments = render_args_comments(processed_seeds, true);
+        format!(
+            r#"
+            /// Derives the PDA for this account for the program it belongs to.
+            ///
+            {}"#,
+            args_comments.join("\n")
+        )
+        .parse::<TokenStream>()
+        .expect("doc comments should always be valid tokens")
+    } else {
+        TokenStream::new()
+    };
+
+    Some(quote! {
+        #find_comments
+        #[allow(unused)]
+        pub fn #find_pda_fn_name(#(#seed_args),*) -> (#pubkey, u8) {
+            Self::#pda_fn_name(#program_id_arg #(#seed_arg_idents),*)
+        }
+    })
+}
+
+/// Renders the fns creating the PDA from the provided bump via
+/// `create_program_address` and verifying a PDA that way, which is much
+/// cheaper on-chain than finding the bump.
+pub fn render_create_pda_fn(
+    processed_seeds: &[ProcessedSeed],
+    seeds_fn_with_bump_name: &Ident,
+    create_pda_fn_name: &Ident,
+    verify_pda_fn_name: &Ident,
+    include_comments: bool,
+    seeds_program: Option<&SeedsProgram>,
+    crate_paths: &CratePaths,
+) -> Option<TokenStream> {
+    if processed_seeds.is_empty() {
+        return None;
+    }
+    let RenderedPdaParts {
+        seed_param_assigns,
+        seed_fn_args,
+        pda_fn_args,
+    } = render_pda_parts(processed_seeds, seeds_program, crate_paths);
+    let pda_fn_arg_idents =
+        render_pda_fn_arg_idents(processed_seeds, seeds_program);
+
+    let pubkey = &crate_paths.pubkey;
+    let pubkey_error = &crate_paths.pubkey_error;
+    let create_program_address = &crate_paths.create_program_address;
+    let (create_comments, verify_comments) = if include_comments {
+        let args_comments = render_args_comments(processed_seeds, true);
+        let program_id_comment = render_program_id_comment(seeds_program);
+        (
+            format!(
+                r#"
+                /// Creates the PDA for this account from the provided bump seed which is
+                /// cheaper than deriving it.
+                {}
+                {}
+                /// * **bump**: the bump seed of the PDA"#,
+                program_id_comment,
+                args_comments.join("\n")
+            )
+            .parse::<TokenStream>()
+            .expect("doc comments should always be valid tokens"),
+            format!(
+                r#"
+                /// Verifies that the provided PDA was created from the seeds and bump.
+                ///
+                /// * **pda**: the PDA to verify
+                {}
+                {}
+                /// * **bump**: the bump seed of the PDA"#,
+                program_id_comment,
+                args_comments.join("\n")
+            )
+            .parse::<TokenStream>()
+            .expect("doc comments should always be valid tokens"),
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
+    Some(quote! {
+        #create_comments
+        #[allow(unused)]
+        pub fn #create_pda_fn_name(#(#pda_fn_args,)* bump: u8) -> Result<#pubkey, #pubkey_error> {
             #(#seed_param_assigns)*
             let bump_arg = &[bump];
-            let seeds = Self::#seeds_fn_with_bump_name(#(#seed_fn_args),* #seed_bump_arg);
-            #pubkey::find_program_address(&seeds, program_id)
+            let seeds = Self::#seeds_fn_with_bump_name(#(#seed_fn_args,)* bump_arg);
+            #create_program_address(&seeds, program_id)
+        }
+        #verify_comments
+        #[allow(unused)]
+        pub fn #verify_pda_fn_name(pda: &#pubkey, #(#pda_fn_args,)* bump: u8) -> bool {
+            match Self::#create_pda_fn_name(#(#pda_fn_arg_idents,)* bump) {
+                Ok(address) => &address == pda,
+                Err(_) => false,
+            }
+        }
+    })
+}
+
+/// Renders the methods creating and verifying the PDA of an account via the
+/// bump stored in its `#[seeds(.., bump = field)]`.
+pub fn render_stored_bump_pda_fn(
+    processed_seeds: &[ProcessedSeed],
+    create_pda_fn_name: &Ident,
+    verify_pda_fn_name: &Ident,
+    bump_field: &Ident,
+    seeds_program: Option<&SeedsProgram>,
+    crate_paths: &CratePaths,
+) -> Option<TokenStream> {
+    if processed_seeds.is_empty() {
+        return None;
+    }
+    let RenderedPdaParts { pda_fn_args, .. } =
+        render_pda_parts(processed_seeds, seeds_program, crate_paths);
+    let pda_fn_arg_idents =
+        render_pda_fn_arg_idents(processed_seeds, seeds_program);
+
+    let pubkey = &crate_paths.pubkey;
+    let pubkey_error = &crate_paths.pubkey_error;
+    let create_doc = format!(
+        " Creates the PDA of this account from the bump stored in `{}`.",
+        bump_field
+    );
+    let verify_doc = format!(
+        " Verifies that the provided PDA was created from the seeds and the bump stored in `{}`.",
+        bump_field
+    );
+    Some(quote! {
+        #[doc = #create_doc]
+        #[allow(unused)]
+        pub fn shank_stored_pda(&self, #(#pda_fn_args),*) -> Result<#pubkey, #pubkey_error> {
+            Self::#create_pda_fn_name(#(#pda_fn_arg_idents,)* self.#bump_field)
+        }
+        #[doc = #verify_doc]
+        #[allow(unused)]
+        pub fn shank_verify_stored_pda(&self, pda: &#pubkey, #(#pda_fn_args),*) -> bool {
+            Self::#verify_pda_fn_name(pda, #(#pda_fn_arg_idents,)* self.#bump_field)
+        }
+    })
+}
+
+/// Renders a fn verifying that the key of the provided account is the PDA
+/// derived for this program and the provided seeds, returning the bump.
+///
+/// When the account stores its bump via `#[seeds(.., bump = field)]` it is
+/// rendered as a method which verifies the PDA via that bump instead, which
+/// avoids the much more expensive search for the bump.
+pub fn render_verify_pda_fn(
+    processed_seeds: &[ProcessedSeed],
+    pda_fn_name: &Ident,
+    verify_pda_fn_name: &Ident,
+    seeds_bump: Option<&Ident>,
+    seeds_program: Option<&SeedsProgram>,
+    crate_paths: &CratePaths,
+) -> Option<TokenStream> {
+    if processed_seeds.is_empty() {
+        return None;
+    }
+    let RenderedPdaParts { pda_fn_args, .. } =
+        render_pda_parts(processed_seeds, seeds_program, crate_paths);
+
+    // Unless the PDA is derived for another program the program id is the
+    // first arg and is provided via `crate::ID`
+    let (program_id_arg, seed_args) = match seeds_program {
+        Some(_) => (None, &pda_fn_args[..]),
+        None => (Some(quote! { &crate::ID, }), &pda_fn_args[1..]),
+    };
+    let seed_arg_idents = processed_seeds
+        .iter()
+        .filter_map(|seed| match &seed.seed {
+            Seed::Param(name, _, _) => {
+                Some(Ident::new(name.as_str(), Span::call_site()))
+            }
+            Seed::Literal(_) | Seed::ProgramId => None,
+        })
+        .collect::<Vec<Ident>>();
+
+    let account_info = &crate_paths.account_info;
+    let program_error = &crate_paths.program_error;
+    let account_key = crate_paths.render_account_key(&quote! { account });
+    if let Some(bump_field) = seeds_bump {
+        let verify_doc = format!(
+            " Verifies that the account is the PDA created from the provided seeds and the bump stored in `{}` and returns that bump.",
+            bump_field
+        );
+        return Some(quote! {
+            #[doc = #verify_doc]
+            #[allow(unused)]
+            pub fn #verify_pda_fn_name(
+                &self,
+                account: &#account_info,
+                #(#seed_args),*
+            ) -> Result<u8, #program_error> {
+                if !self.shank_verify_stored_pda(#account_key, #program_id_arg #(#seed_arg_idents),*) {
+                    return Err(#program_error::InvalidSeeds);
+                }
+                Ok(self.#bump_field)
+            }
+        });
+    }
+    Some(quote! {
+        /// Verifies that the account is the PDA derived from the provided seeds and
+        /// returns its bump.
+        #[allow(unused)]
+        pub fn #verify_pda_fn_name(
+            account: &#account_info,
+            #(#seed_args),*
+        ) -> Result<u8, #program_error> {
+            let (pda, bump) = Self::#pda_fn_name(#program_id_arg #(#seed_arg_idents),*);
+            if #account_key != &pda {
+                return Err(#program_error::InvalidSeeds);
+            }
+            Ok(bump)
         }
     })
 }
 
+fn render_program_id_comment(seeds_program: Option<&SeedsProgram>) -> String {
+    match seeds_program {
+        Some(SeedsProgram::Path(program))
+        | Some(SeedsProgram::Address(program)) => {
+            format!("/// Derived for the program `{}`.\n///", program)
+        }
+        None => "///\n/// * **program_id**: The id of the program".to_string(),
+    }
+}
+
+/// The idents of the args the PDA fns take, used to forward them from one of
+/// those fns to another.
+fn render_pda_fn_arg_idents(
+    processed_seeds: &[ProcessedSeed],
+    seeds_program: Option<&SeedsProgram>,
+) -> Vec<Ident> {
+    let program_id = match seeds_program {
+        Some(_) => None,
+        None => Some(Ident::new("program_id", Span::call_site())),
+    };
+    program_id
+        .into_iter()
+        .chain(processed_seeds.iter().filter_map(|seed| match &seed.seed {
+            Seed::Param(name, _, _) => {
+                Some(Ident::new(name.as_str(), Span::call_site()))
+            }
+            Seed::Literal(_) | Seed::ProgramId => None,
+        }))
+        .collect()
+}
+
 #[derive(Debug)]
 struct RenderedPdaParts {
     seed_param_assigns: Vec<TokenStream>,
@@ -85,27 +377,40 @@ struct RenderedPdaParts {
     pda_fn_args: Vec<TokenStream>,
 }
 
-fn render_pda_parts(processed_seeds: &[ProcessedSeed]) -> RenderedPdaParts {
+fn render_pda_parts(
+    processed_seeds: &[ProcessedSeed],
+    seeds_program: Option<&SeedsProgram>,
+    crate_paths: &CratePaths,
+) -> RenderedPdaParts {
     // -----------------
     // Incoming Args
     // -----------------
     let mut pda_fn_args = processed_seeds
         .iter()
-        .map(render_pda_function_arg)
+        .map(|seed| render_pda_function_arg(seed, crate_paths))
         .collect::<Vec<Option<TokenStream>>>()
         .into_iter()
         .filter(Option::is_some)
         .flatten()
         .collect::<Vec<TokenStream>>();
 
-    let pubkey_ty = solana_program_pubkey();
-    let program_id_arg = quote! { program_id : &#pubkey_ty };
-    pda_fn_args.insert(0, program_id_arg);
-
     // -----------------
     // Args to get seeds array
     // -----------------
     let mut seed_param_assigns = Vec::new();
+    match seeds_program {
+        // The PDA of another program is always derived for that program
+        Some(program) => {
+            let program = render_seeds_program(program, crate_paths);
+            seed_param_assigns.push(quote! { let program_id = &#program; });
+        }
+        None => {
+            let pubkey_ty = &crate_paths.pubkey;
+            let program_id_arg = quote! { program_id : &#pubkey_ty };
+            pda_fn_args.insert(0, program_id_arg);
+        }
+    }
+
     let mut seed_fn_args = Vec::new();
 
     for seed in processed_seeds {
@@ -125,7 +430,10 @@ fn render_pda_parts(processed_seeds: &[ProcessedSeed]) -> RenderedPdaParts {
     }
 }
 
-fn render_pda_function_arg(seed: &ProcessedSeed) -> Option<TokenStream> {
+fn render_pda_function_arg(
+    seed: &ProcessedSeed,
+    crate_paths: &CratePaths,
+) -> Option<TokenStream> {
     match &seed.seed {
         Seed::Literal(_) => {
             // Literal items don't need to be passed to the function
@@ -140,7 +448,7 @@ fn render_pda_function_arg(seed: &ProcessedSeed) -> Option<TokenStream> {
             // NOTE: for a param seed shank-macro-impl:src/parsed_struct/seeds.rs always ensures
             // that the arg is set
             let ty = seed.arg.as_ref().unwrap().ty.clone();
-            let arg = ty.render_param(name);
+            let arg = with_crate_paths(ty, crate_paths).render_param(name);
             Some(arg)
         }
     }
@@ -152,6 +460,7 @@ fn render_pda_function_arg(seed: &ProcessedSeed) -> Option<TokenStream> {
 /// Specifically:
 ///   - it filters out literals which don't need to be passed
 ///   - it wraps u8s in a &[u8] and passes that reference to the seed fn
+///   - it converts integers via `to_le_bytes` and enums to their discriminant byte
 fn render_seed_param(
     seed: &ProcessedSeed,
 ) -> (Option<TokenStream>, Option<Ident>) {
@@ -160,24 +469,21 @@ fn render_seed_param(
         Seed::ProgramId => {
             (None, Some(Ident::new("program_id", Span::call_site())))
         }
-        Seed::Param(name, _, seed_ty) => {
+        Seed::Param(name, _, _) => {
             let ident =
                 Ident::new(name.as_str(), proc_macro2::Span::call_site());
-            match &seed_ty {
-                Some(ty) if ty == "u8" => {
-                    // We pass a byte array ref (&[u8]) to the seed function and need to assign it so
-                    // it lives long enough to be included in the seeds array used to calculate the PDA
-                    let ident_arg = Ident::new(
-                        format!("{}_arg", name).as_str(),
-                        Span::call_site(),
-                    );
-                    (
-                        Some(quote! { let #ident_arg = &[#ident]; }),
-                        Some(ident_arg),
-                    )
-                }
-                _ => (None, Some(ident)),
-            }
+            // We pass a byte array ref (&[u8]) to the seed function and need to assign it so
+            // it lives long enough to be included in the seeds array used to calculate the PDA
+            let ident_arg =
+                Ident::new(format!("{}_arg", name).as_str(), Span::call_site());
+            // NOTE: for a param seed the arg is always set
+            let bytes = match seed.arg.as_ref().unwrap().encoding() {
+                SeedEncoding::Bytes => return (None, Some(ident)),
+                SeedEncoding::Byte => quote! { &[#ident] },
+                SeedEncoding::LeBytes(_) => quote! { &#ident.to_le_bytes() },
+                SeedEncoding::EnumDiscriminant => quote! { &[#ident as u8] },
+            };
+            (Some(quote! { let #ident_arg = #bytes; }), Some(ident_arg))
         }
     }
 }
@@ -192,7 +498,7 @@ mod tests {
         let processed_seeds = Seeds(seeds.to_vec())
             .process()
             .expect("should process seeds without error");
-        render_pda_parts(&processed_seeds)
+        render_pda_parts(&processed_seeds, None, &CratePaths::default())
     }
 
     fn assert_tokenstream_eq(actual: &TokenStream, expected: &str) {
@@ -1,29 +1,31 @@
-use quote::{quote, ToTokens};
+use quote::quote;
 use std::str::FromStr;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use shank_macro_impl::{
     parsed_struct::{
-        ProcessedSeed, Seed, ACCOUNT_INFO_TY, FULL_ACCOUNT_INFO_TY,
-        FULL_PUBKEY_TY, PUBKEY_TY,
+        CratePaths, ProcessedSeed, Seed, SeedArg, SeedEncoding,
+        ACCOUNT_INFO_TY, FULL_ACCOUNT_INFO_TY, FULL_PUBKEY_TY, MAX_SEED_LEN,
+        PUBKEY_TY,
     },
     syn::{Error as ParseError, Result as ParseResult},
     types::{Composite, ParsedReference, Primitive, RustType, TypeKind, Value},
 };
 
-use super::render_args_comments;
+use super::{render_args_comments, with_crate_paths};
 
 pub fn try_render_seeds_fn(
     processed_seeds: &[ProcessedSeed],
     seeds_fn_name: &Ident,
     seeds_fn_with_bump_name: &Ident,
     include_comments: bool,
+    crate_paths: &CratePaths,
 ) -> ParseResult<Option<TokenStream>> {
     let lifetime = "a";
     let RenderedSeedsParts {
         seed_array_items,
         seed_fn_args,
-    } = try_render_seeds_parts(processed_seeds, lifetime)?;
+    } = try_render_seeds_parts(processed_seeds, lifetime, crate_paths)?;
     if seed_array_items.is_empty() {
         return Ok(None);
     }
@@ -47,7 +49,7 @@ pub fn try_render_seeds_fn(
                 {}"#,
                 args_comments.join("\n")
             )
-            .to_token_stream(),
+            .parse::<TokenStream>()?,
             format!(
                 r#"
                 /// Derives the seeds for this account allowing to provide a bump seed.
@@ -56,7 +58,7 @@ pub fn try_render_seeds_fn(
                 /// * **bump**: the bump seed to pass when deriving the PDA"#,
                 args_comments.join("\n")
             )
-            .to_token_stream(),
+            .parse::<TokenStream>()?,
         )
     } else {
         (TokenStream::new(), TokenStream::new())
@@ -75,6 +77,57 @@ pub fn try_render_seeds_fn(
     }))
 }
 
+/// Renders `const` assertions verifying that the seeds of fixed size types
+/// fit into their seed at compile time, i.e. that a custom pubkey type is at
+/// most 32 bytes or that an enum fits into a single byte.
+pub fn render_seeds_len_assertions(
+    processed_seeds: &[ProcessedSeed],
+    account_type_ident: &Ident,
+    crate_paths: &CratePaths,
+) -> TokenStream {
+    let assertions = processed_seeds
+        .iter()
+        .filter_map(|seed| match (&seed.seed, &seed.arg) {
+            (Seed::Param(name, _, _), Some(arg)) => Some((name, arg)),
+            _ => None,
+        })
+        .filter_map(|(name, arg)| {
+            let max_len = match (arg.encoding().fixed_len(), &arg.ty.kind) {
+                (Some(len), _) => len,
+                (None, TypeKind::Value(Value::Custom(x)))
+                    if x == FULL_PUBKEY_TY =>
+                {
+                    MAX_SEED_LEN
+                }
+                // Strings and account infos are only known at runtime
+                _ => return None,
+            };
+            let ty = with_crate_paths(arg.ty.clone().as_owned(), crate_paths)
+                .render();
+            let len = match max_len {
+                1 => "a single byte".to_string(),
+                len => format!("{} bytes", len),
+            };
+            let message = format!(
+                "The {} seed of {} needs to fit into {}",
+                name, account_type_ident, len
+            );
+            Some(quote! {
+                assert!(::core::mem::size_of::<#ty>() <= #max_len, #message);
+            })
+        })
+        .collect::<Vec<TokenStream>>();
+
+    if assertions.is_empty() {
+        return TokenStream::new();
+    }
+    quote! {
+        const _: () = {
+            #(#assertions)*
+        };
+    }
+}
+
 #[derive(Debug)]
 struct RenderedSeedsParts {
     seed_array_items: Vec<TokenStream>,
@@ -84,10 +137,11 @@ struct RenderedSeedsParts {
 fn try_render_seeds_parts(
     processed_seeds: &[ProcessedSeed],
     lifetime: &str,
+    crate_paths: &CratePaths,
 ) -> ParseResult<RenderedSeedsParts> {
     let seed_fn_args = processed_seeds
         .iter()
-        .map(|x| render_seed_function_arg(x, lifetime))
+        .map(|x| render_seed_function_arg(x, lifetime, crate_paths))
         .collect::<ParseResult<Vec<Option<TokenStream>>>>()?
         .into_iter()
         .filter(Option::is_some)
@@ -113,6 +167,7 @@ fn try_render_seeds_parts(
 fn render_seed_function_arg(
     seed: &ProcessedSeed,
     lifetime: &str,
+    crate_paths: &CratePaths,
 ) -> ParseResult<Option<TokenStream>> {
     match &seed.seed {
         Seed::Literal(_) => {
@@ -120,13 +175,10 @@ fn render_seed_function_arg(
             Ok(None)
         }
         Seed::ProgramId => {
-            let arg = seed
-                .arg
-                .as_ref()
-                // SAFETY: we can unwrap here since we control creation of this data
-                // and know that for `Seed::ProgramId` the type arg is always set
-                .unwrap()
-                .ty
+            // SAFETY: we can unwrap here since we control creation of this data
+            // and know that for `Seed::ProgramId` the type arg is always set
+            let ty = seed.arg.as_ref().unwrap().ty.clone();
+            let arg = with_crate_paths(ty, crate_paths)
                 .try_with_lifetime(lifetime)?
                 .render_param("program_id");
             Ok(Some(arg))
@@ -134,21 +186,30 @@ fn render_seed_function_arg(
         Seed::Param(name, _, _) => {
             // NOTE: for a param seed shank-macro-impl:src/parsed_struct/seeds.rs always ensures
             // that the arg is set
-            let ty = seed.arg.as_ref().unwrap().ty.clone();
-            let arg = adapt_seed_function_arg_type_kind(ty)
-                .try_with_lifetime(lifetime)?
-                .render_param(name);
+            let seed_arg = seed.arg.as_ref().unwrap();
+            let arg = adapt_seed_function_arg_type_kind(
+                with_crate_paths(seed_arg.ty.clone(), crate_paths),
+                seed_arg.encoding(),
+            )
+            .try_with_lifetime(lifetime)?
+            .render_param(name);
             Ok(Some(arg))
         }
     }
 }
 
-fn adapt_seed_function_arg_type_kind(ty: RustType) -> RustType {
-    match ty.kind {
-        TypeKind::Primitive(Primitive::U8) => {
+/// Seeds whose bytes are converted from a value, i.e. integers and enums, are
+/// passed to the seeds fn as a reference to the converted byte array since
+/// the seeds array cannot reference values created inside the seeds fn.
+fn adapt_seed_function_arg_type_kind(
+    ty: RustType,
+    encoding: SeedEncoding,
+) -> RustType {
+    match encoding.fixed_len() {
+        Some(len) => {
             let kind = TypeKind::Composite(
-                Composite::Array(1),
-                vec![ty.clone().as_owned()],
+                Composite::Array(len),
+                vec![RustType::owned_primitive("u8", Primitive::U8)],
             );
             RustType {
                 kind,
@@ -156,13 +217,7 @@ fn adapt_seed_function_arg_type_kind(ty: RustType) -> RustType {
                 ..ty
             }
         }
-        // TODO(thlorenz): most of the below are not supported and some already error when seeds
-        // are processed. We could add some more check here to detect invalid inputs.
-        TypeKind::Primitive(_) => ty,
-        TypeKind::Value(_) => ty,
-        TypeKind::Composite(_, _) => ty,
-        TypeKind::Unit => ty,
-        TypeKind::Unknown => ty,
+        None => ty,
     }
 }
 
@@ -177,30 +232,34 @@ fn render_seed_array_item(seed: &ProcessedSeed) -> ParseResult<TokenStream> {
         }
         Seed::ProgramId => {
             let item =
-                seed_array_item("program_id", &seed.arg.as_ref().unwrap().ty)?;
+                seed_array_item("program_id", seed.arg.as_ref().unwrap())?;
             Ok(item)
         }
         Seed::Param(name, _, _) => {
             // NOTE: for a param seed shank-macro-impl:src/parsed_struct/seeds.rs always ensures
             // that the arg is set
             let item =
-                seed_array_item(name.as_str(), &seed.arg.as_ref().unwrap().ty)?;
+                seed_array_item(name.as_str(), seed.arg.as_ref().unwrap())?;
             Ok(item)
         }
     }
 }
 
-fn seed_array_item(name: &str, ty: &RustType) -> ParseResult<TokenStream> {
+fn seed_array_item(name: &str, arg: &SeedArg) -> ParseResult<TokenStream> {
     let ident = Ident::new(name, Span::call_site());
+    // Converted seeds are passed as byte arrays already
+    if arg.encoding().fixed_len().is_some() {
+        return Ok(quote! { #ident });
+    }
+    let ty = &arg.ty;
     match &ty.kind {
         TypeKind::Primitive(p) if p == &Primitive::Bool => {
             Ok(quote! { &[if #ident { 1 } else { 0 } ] })
         }
-        TypeKind::Primitive(Primitive::U8) => Ok(quote! { #ident }),
         TypeKind::Primitive(prim) => Err(ParseError::new(
             Span::call_site(),
             format!(
-                "Unsupported primitive type: {}, only u8 is supported. Consider using String or str instead.",
+                "Unsupported primitive type: {}, only u8, u16, u32, u64 and i64 are supported. Consider using String or str instead.",
                 prim
             ),
         )),
@@ -246,7 +305,7 @@ mod tests {
         let processed_seeds = Seeds(seeds.to_vec())
             .process()
             .expect("should process seeds without error");
-        try_render_seeds_parts(&processed_seeds, "a")
+        try_render_seeds_parts(&processed_seeds, "a", &CratePaths::default())
             .expect("Should render seeds without error")
     }
 
@@ -0,0 +1,163 @@
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use shank_macro_impl::{
+    instruction::{Instruction, InstructionVariant, InstructionVariantFields},
+    syn::Result as ParseResult,
+};
+
+use crate::{
+    context::{account_info_ty, accounts_ty},
+    instruction::{render_variant_pattern, variant_args, variant_discriminant},
+};
+
+/// Renders `unpack` which reads the discriminant of the instruction followed
+/// by the borsh serialized args of the matching variant.
+fn render_unpack(instruction: &Instruction) -> ParseResult<TokenStream> {
+    let item = &instruction.ident;
+    let program_error = &instruction.crate_paths.program_error;
+
+    let mut has_args = false;
+    let mut unpack_variants = Vec::with_capacity(instruction.variants.len());
+    for variant in &instruction.variants {
+        let discriminant = variant_discriminant(variant)?;
+        let name = &variant.ident;
+        let args = variant_args(variant);
+        has_args = has_args || !args.is_empty();
+
+        let deserialize_args = args.iter().map(|(name, ty)| {
+            let value = quote! {
+                <#ty as ::borsh::BorshDeserialize>::deserialize(&mut data)
+                    .map_err(|_| #program_error::InvalidInstructionData)?
+            };
+            (name, value)
+        });
+        let unpacked = match &variant.field_tys {
+            _ if args.is_empty() => quote! { Self::#name },
+            InstructionVariantFields::Named(_) => {
+                let fields = deserialize_args
+                    .map(|(name, value)| quote! { #name: #value });
+                quote! { Self::#name { #(#fields),* } }
+            }
+            InstructionVariantFields::Unnamed(_) => {
+                let values = deserialize_args.map(|(_, value)| value);
+                quote! { Self::#name(#(#values),*) }
+            }
+        };
+        unpack_variants.push(quote! { #discriminant => #unpacked });
+    }
+    let data = if has_args {
+        quote! { mut data }
+    } else {
+        quote! { data }
+    };
+
+    Ok(quote! {
+        impl #item {
+            /// Unpacks the instruction from its data, i.e. the discriminant of the
+            /// variant followed by its borsh serialized args.
+            pub fn unpack(data: &[u8]) -> Result<Self, #program_error> {
+                let (discriminant, #data) = data
+                    .split_first()
+                    .ok_or(#program_error::InvalidInstructionData)?;
+                let instruction = match discriminant {
+                    #(#unpack_variants,)*
+                    _ => return Err(#program_error::InvalidInstructionData),
+                };
+                if !data.is_empty() {
+                    return Err(#program_error::InvalidInstructionData);
+                }
+                Ok(instruction)
+            }
+        }
+    })
+}
+
+/// Renders the `Processor` trait with a method per instruction variant along
+/// with `dispatch` which invokes the method of the unpacked instruction.
+///
+/// The methods receive the context of the `*Accounts` derived via
+/// `ShankContext` which thus needs to be derived as well. The context is
+/// created via `context_checked` if the instruction opts into it via
+/// `#[shank(context_checked)]`.
+fn render_processor(instruction: &Instruction) -> TokenStream {
+    let item = &instruction.ident;
+    let crate_paths = &instruction.crate_paths;
+    let pubkey = &crate_paths.pubkey;
+    let program_error = &crate_paths.program_error;
+    let account_info = account_info_ty(crate_paths);
+
+    let process_fn = |variant: &InstructionVariant| {
+        format_ident!("process_{}", variant.ident.to_string().to_snake_case())
+    };
+
+    let process_fns = instruction.variants.iter().map(|variant| {
+        let process_fn = process_fn(variant);
+        let accounts_ty = accounts_ty(variant);
+        let args = variant_args(variant)
+            .into_iter()
+            .map(|(name, ty)| quote! { #name: #ty });
+        quote! {
+            fn #process_fn<'a>(
+                program_id: &#pubkey,
+                ctx: accounts::Context<'a, accounts::#accounts_ty>,
+                #(#args,)*
+            ) -> Result<(), #program_error>;
+        }
+    });
+
+    let context_fn = if instruction.context_checked {
+        format_ident!("context_checked")
+    } else {
+        format_ident!("context")
+    };
+    let dispatch_variants = instruction.variants.iter().map(|variant| {
+        let pattern = render_variant_pattern(item, variant);
+        let process_fn = process_fn(variant);
+        let accounts_name = format_ident!("{}Accounts", variant.ident);
+        let arg_names = variant_args(variant).into_iter().map(|(name, _)| name);
+        quote! {
+            #pattern => P::#process_fn(
+                program_id,
+                accounts::#accounts_name::#context_fn(accounts)?,
+                #(#arg_names,)*
+            )
+        }
+    });
+
+    quote! {
+        pub mod processor {
+            use super::*;
+
+            /// Processes the instructions of the program, each method receives the
+            /// accounts and the args of its instruction.
+            #[allow(clippy::too_many_arguments)]
+            pub trait Processor {
+                #(#process_fns)*
+            }
+
+            /// Unpacks the instruction data and invokes the matching method of the
+            /// processor with the accounts of the instruction.
+            pub fn dispatch<'a, P: Processor>(
+                program_id: &#pubkey,
+                accounts: &'a [#account_info],
+                data: &[u8],
+            ) -> Result<(), #program_error> {
+                match #item::unpack(data)? {
+                    #(#dispatch_variants,)*
+                }
+            }
+        }
+    }
+}
+
+pub fn render_processor_impl(
+    instruction: &Instruction,
+) -> ParseResult<TokenStream> {
+    let unpack = render_unpack(instruction)?;
+    let processor = render_processor(instruction);
+    Ok(quote! {
+        #unpack
+        #processor
+    })
+}
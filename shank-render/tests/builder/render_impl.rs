@@ -0,0 +1,265 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_macro_impl::{
+    builder::Builder,
+    syn::{self, ItemEnum},
+};
+use shank_render::builder::render_builders_impl;
+
+use crate::utils;
+
+fn render_impl(code: TokenStream) -> TokenStream {
+    let item_enum = syn::parse2::<ItemEnum>(code).expect("Should parse enum");
+    let builder = Builder::try_from_item_enum(&item_enum, true)
+        .expect("Should parse builder")
+        .unwrap();
+    render_builders_impl(&builder).unwrap()
+}
+
+#[allow(unused)]
+fn render_and_dump(code: &TokenStream) {
+    let rendered = render_impl(code.clone());
+    eprintln!("{}", utils::pretty_print(rendered));
+}
+
+fn assert_rendered_impl_fn(code: TokenStream, expected: TokenStream) {
+    let rendered = render_impl(code);
+    assert_eq!(utils::pretty_print(rendered), utils::pretty_print(expected));
+}
+
+#[test]
+fn cpi_impl() {
+    let code = quote! {
+        #[derive(ShankBuilder)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            #[account(1, optional, writable, name = "delegate")]
+            #[account(2, optional_signer, name = "payer")]
+            Withdraw(u64),
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            pub mod builders {
+                use super::*;
+                /// Trait that defines the interface for creating an instruction.
+                pub trait InstructionBuilder {
+                    fn instruction(&self) -> ::solana_program::instruction::Instruction;
+                }
+                pub struct Withdraw {
+                    pub vault: ::solana_program::pubkey::Pubkey,
+                    pub delegate: Option<::solana_program::pubkey::Pubkey>,
+                    pub payer: ::solana_program::pubkey::Pubkey,
+                    pub payer_signer: bool,
+                    pub args: u64,
+                }
+                impl InstructionBuilder for Withdraw {
+                    fn instruction(&self) -> ::solana_program::instruction::Instruction {
+                        ::solana_program::instruction::Instruction {
+                            program_id: crate::ID,
+                            accounts: vec![
+                                ::solana_program::instruction::AccountMeta::new(self.vault, false),
+                                if let Some(delegate) = self.delegate {
+                                    ::solana_program::instruction::AccountMeta::new(delegate, false)
+                                } else {
+                                    ::solana_program::instruction::AccountMeta::new_readonly(crate::ID, false)
+                                },
+                                ::solana_program::instruction::AccountMeta::new_readonly(self.payer, self.payer_signer),
+                            ],
+                            data: VaultInstruction::Withdraw(self.args.clone()).try_to_vec().unwrap(),
+                        }
+                    }
+                }
+                pub struct WithdrawBuilder {
+                    pub vault: Option<::solana_program::pubkey::Pubkey>,
+                    pub delegate: Option<::solana_program::pubkey::Pubkey>,
+                    pub payer: Option<::solana_program::pubkey::Pubkey>,
+                    pub payer_signer: bool,
+                }
+                impl WithdrawBuilder {
+                    pub fn new() -> Box<WithdrawBuilder> {
+                        Box::new(WithdrawBuilder {
+                            vault: None,
+                            delegate: None,
+                            payer: None,
+                            payer_signer: false,
+                        })
+                    }
+                    pub fn vault(&mut self, vault: ::solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.vault = Some(vault);
+                        self
+                    }
+                    pub fn delegate(
+                        &mut self,
+                        delegate: ::solana_program::pubkey::Pubkey,
+                    ) -> &mut Self {
+                        self.delegate = Some(delegate);
+                        self
+                    }
+                    pub fn payer(
+                        &mut self,
+                        payer: ::solana_program::pubkey::Pubkey,
+                        signer: bool,
+                    ) -> &mut Self {
+                        self.payer = Some(payer);
+                        self.payer_signer = signer;
+                        self
+                    }
+                    pub fn build(
+                        &mut self,
+                        args: u64,
+                    ) -> Result<Box<Withdraw>, Box<dyn std::error::Error>> {
+                        Ok(
+                            Box::new(Withdraw {
+                                vault: self.vault.ok_or(concat!(stringify!(vault), " is not set"))?,
+                                delegate: self.delegate,
+                                payer: self.payer.ok_or(concat!(stringify!(payer), " is not set"))?,
+                                payer_signer: self.payer_signer,
+                                args,
+                            }),
+                        )
+                    }
+                }
+                pub struct WithdrawCpi<'a, 'b> {
+                    /// The account info of the program being invoked.
+                    pub __program: &'b ::solana_program::account_info::AccountInfo<'a>,
+                    pub vault: &'b ::solana_program::account_info::AccountInfo<'a>,
+                    pub delegate: Option<&'b ::solana_program::account_info::AccountInfo<'a>>,
+                    pub payer: &'b ::solana_program::account_info::AccountInfo<'a>,
+                    pub payer_signer: bool,
+                    pub args: u64,
+                }
+                impl<'a, 'b> WithdrawCpi<'a, 'b> {
+                    pub fn instruction(&self) -> ::solana_program::instruction::Instruction {
+                        ::solana_program::instruction::Instruction {
+                            program_id: crate::ID,
+                            accounts: vec![
+                                ::solana_program::instruction::AccountMeta::new(*self.vault.key, false),
+                                if let Some(delegate) = self.delegate {
+                                    ::solana_program::instruction::AccountMeta::new(*delegate.key, false)
+                                } else {
+                                    ::solana_program::instruction::AccountMeta::new_readonly(crate::ID, false)
+                                },
+                                ::solana_program::instruction::AccountMeta::new_readonly(*self.payer.key, self.payer_signer),
+                            ],
+                            data: VaultInstruction::Withdraw(self.args.clone()).try_to_vec().unwrap(),
+                        }
+                    }
+                    pub fn invoke(
+                        &self,
+                    ) -> Result<(), ::solana_program::program_error::ProgramError> {
+                        self.invoke_signed(&[])
+                    }
+                    pub fn invoke_signed(
+                        &self,
+                        signers_seeds: &[&[&[u8]]],
+                    ) -> Result<(), ::solana_program::program_error::ProgramError> {
+                        let mut account_infos = Vec::with_capacity(4usize);
+                        account_infos.push(self.__program.clone());
+                        account_infos.push(self.vault.clone());
+                        if let Some(delegate) = self.delegate {
+                            account_infos.push(delegate.clone());
+                        }
+                        account_infos.push(self.payer.clone());
+                        ::solana_program::program::invoke_signed(
+                            &self.instruction(),
+                            &account_infos,
+                            signers_seeds,
+                        )
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn cpi_omits_custom_args() {
+    let code = quote! {
+        #[derive(ShankBuilder)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            #[args(additional_accounts: Vec<AccountMeta>)]
+            Create(u64),
+        }
+    };
+    let rendered = render_impl(code).to_string().replace(' ', "");
+    assert!(rendered.contains("pubstructCreateBuilder"));
+    // the custom args only feed the builder
+    let cpi = &rendered[rendered.find("pubstructCreateCpi").unwrap()..];
+    assert!(cpi.contains("pubargs:u64"));
+    assert!(!cpi.contains("additional_accounts"));
+}
+
+#[test]
+fn builder_account_defaults() {
+    let code = quote! {
+        #[derive(ShankBuilder)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            #[account(1, name = "rewards_program", default = "rewards::ID")]
+            #[account(2, name = "system_program", address = "11111111111111111111111111111112")]
+            Create,
+        }
+    };
+    let rendered = render_impl(code).to_string().replace(' ', "");
+    assert!(rendered.contains(
+        "rewards_program:self.rewards_program.unwrap_or(rewards::ID)"
+    ));
+    // the declared address takes precedence over the well known one
+    let system_program = format!(
+        "system_program:self.system_program.unwrap_or(::solana_program::pubkey::Pubkey::new_from_array([{}1u8]))",
+        "0u8,".repeat(31)
+    );
+    assert!(rendered.contains(&system_program));
+}
+
+#[test]
+fn builder_pda_account() {
+    let code = quote! {
+        #[derive(ShankBuilder)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault", pda = Vault, seeds(owner, kind))]
+            #[account(1, signer, name = "owner")]
+            Create { kind: u8 },
+            #[account(0, writable, name = "escrow", pda = Escrow, seeds(owner, index, role, label))]
+            #[account(1, signer, name = "owner")]
+            Open { index: u64, role: Role, label: String },
+        }
+    };
+    let rendered = render_impl(code).to_string().replace(' ', "");
+    // accounts are passed by reference while u8 args are passed by value
+    assert!(rendered.contains(
+        "vault:matchself.vault{Some(vault)=>vault,None=>Vault::shank_find_pda(&self.owner.ok_or(concat!(stringify!(owner),\"isnotset\"))?,kind).0,}"
+    ));
+    // integer and enum args are passed by value as well
+    assert!(rendered.contains(
+        "Escrow::shank_find_pda(&self.owner.ok_or(concat!(stringify!(owner),\"isnotset\"))?,index,role,&label).0"
+    ));
+    // named variants are built via their field names
+    assert!(rendered.contains(
+        "data:VaultInstruction::Create{kind:self.kind.clone(),}.try_to_vec().unwrap()"
+    ));
+}
+
+#[test]
+fn builder_pinocchio_preset_rejected() {
+    let code = quote! {
+        #[derive(ShankBuilder)]
+        #[shank(pinocchio)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            Create,
+        }
+    };
+    let item_enum = syn::parse2::<ItemEnum>(code).expect("Should parse enum");
+    let builder = Builder::try_from_item_enum(&item_enum, true)
+        .expect("Should parse builder")
+        .unwrap();
+    let err = render_builders_impl(&builder).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShankBuilder only supports the solana_program and solana_sdk_split crate paths presets, not pinocchio whose instructions borrow their accounts and data"
+    );
+}
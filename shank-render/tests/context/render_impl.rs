@@ -0,0 +1,281 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_macro_impl::{
+    instruction::Instruction,
+    syn::{self, ItemEnum},
+};
+use shank_render::context::render_contexts_impl;
+
+use crate::utils;
+
+fn render_impl(code: TokenStream) -> TokenStream {
+    let item_enum = syn::parse2::<ItemEnum>(code).expect("Should parse enum");
+    let instruction = Instruction::try_from_item_enum(&item_enum, true)
+        .expect("Should parse instruction")
+        .unwrap();
+    render_contexts_impl(&instruction).unwrap()
+}
+
+#[allow(unused)]
+fn render_and_dump(code: &TokenStream) {
+    let rendered = render_impl(code.clone());
+    eprintln!("{}", utils::pretty_print(rendered));
+}
+
+fn assert_rendered_impl_fn(code: TokenStream, expected: TokenStream) {
+    let rendered = render_impl(code);
+    assert_eq!(utils::pretty_print(rendered), utils::pretty_print(expected));
+}
+
+#[test]
+fn solana_program_context_impl() {
+    let code = quote! {
+        #[derive(ShankContext)]
+        #[shank(context_checked)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            #[account(1, signer, name = "authority")]
+            #[account(2, optional, writable, name = "delegate")]
+            #[account(3, optional_signer, name = "payer")]
+            Create,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            pub mod accounts {
+                use super::*;
+                pub struct Context<'a, T> {
+                    pub accounts: T,
+                    pub remaining_accounts: &'a [::solana_program::account_info::AccountInfo<'a>],
+                }
+                #[doc = r" The error returned by `context_checked` naming the account which"]
+                #[doc = r" failed validation."]
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct ContextError {
+                    pub account: &'static str,
+                    pub error: ::solana_program::program_error::ProgramError,
+                }
+                impl ContextError {
+                    pub fn new(
+                        account: &'static str,
+                        error: ::solana_program::program_error::ProgramError,
+                    ) -> Self {
+                        Self { account, error }
+                    }
+                }
+                impl From<ContextError> for ::solana_program::program_error::ProgramError {
+                    fn from(error: ContextError) -> Self {
+                        error.error
+                    }
+                }
+                pub struct CreateAccounts<'a> {
+                    pub vault: &'a ::solana_program::account_info::AccountInfo<'a>,
+                    pub authority: &'a ::solana_program::account_info::AccountInfo<'a>,
+                    pub delegate: Option<&'a ::solana_program::account_info::AccountInfo<'a>>,
+                    pub payer: &'a ::solana_program::account_info::AccountInfo<'a>,
+                }
+                impl<'a> CreateAccounts<'a> {
+                    pub fn context(
+                        accounts: &'a [::solana_program::account_info::AccountInfo<'a>],
+                    ) -> Result<Context<'a, Self>, ::solana_program::program_error::ProgramError> {
+                        if accounts.len() < 4usize {
+                            return Err(::solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+                        }
+                        Ok(Context {
+                            accounts: Self {
+                                vault: &accounts[0usize],
+                                authority: &accounts[1usize],
+                                delegate: if accounts[2usize].key == &crate::ID {
+                                    None
+                                } else {
+                                    Some(&accounts[2usize])
+                                },
+                                payer: &accounts[3usize],
+                            },
+                            remaining_accounts: &accounts[4usize..],
+                        })
+                    }
+                    #[doc = r" Creates the context after verifying that the accounts are signers and writable"]
+                    #[doc = r" as declared by the instruction, naming the first account which isn't."]
+                    pub fn context_checked(
+                        accounts: &'a [::solana_program::account_info::AccountInfo<'a>],
+                    ) -> Result<Context<'a, Self>, ContextError> {
+                        if accounts.len() < 4usize {
+                            let names: [&'static str; 4usize] = ["vault", "authority", "delegate", "payer"];
+                            return Err(ContextError::new(
+                                names[accounts.len()],
+                                ::solana_program::program_error::ProgramError::NotEnoughAccountKeys,
+                            ));
+                        }
+                        if !accounts[0usize].is_writable {
+                            return Err(ContextError::new(
+                                "vault",
+                                ::solana_program::program_error::ProgramError::InvalidAccountData,
+                            ));
+                        }
+                        if !accounts[1usize].is_signer {
+                            return Err(ContextError::new(
+                                "authority",
+                                ::solana_program::program_error::ProgramError::MissingRequiredSignature,
+                            ));
+                        }
+                        if accounts[2usize].key != &crate::ID {
+                            if !accounts[2usize].is_writable {
+                                return Err(ContextError::new(
+                                    "delegate",
+                                    ::solana_program::program_error::ProgramError::InvalidAccountData,
+                                ));
+                            }
+                        }
+                        Ok(Context {
+                            accounts: Self {
+                                vault: &accounts[0usize],
+                                authority: &accounts[1usize],
+                                delegate: if accounts[2usize].key == &crate::ID {
+                                    None
+                                } else {
+                                    Some(&accounts[2usize])
+                                },
+                                payer: &accounts[3usize],
+                            },
+                            remaining_accounts: &accounts[4usize..],
+                        })
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn pinocchio_context_impl() {
+    let code = quote! {
+        #[derive(ShankContext)]
+        #[shank(pinocchio, context_checked)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            #[account(1, optional, name = "delegate")]
+            Create,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            pub mod accounts {
+                use super::*;
+                pub struct Context<'a, T> {
+                    pub accounts: T,
+                    pub remaining_accounts: &'a [::pinocchio::account_info::AccountInfo],
+                }
+                #[doc = r" The error returned by `context_checked` naming the account which"]
+                #[doc = r" failed validation."]
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct ContextError {
+                    pub account: &'static str,
+                    pub error: ::pinocchio::program_error::ProgramError,
+                }
+                impl ContextError {
+                    pub fn new(
+                        account: &'static str,
+                        error: ::pinocchio::program_error::ProgramError,
+                    ) -> Self {
+                        Self { account, error }
+                    }
+                }
+                impl From<ContextError> for ::pinocchio::program_error::ProgramError {
+                    fn from(error: ContextError) -> Self {
+                        error.error
+                    }
+                }
+                pub struct CreateAccounts<'a> {
+                    pub vault: &'a ::pinocchio::account_info::AccountInfo,
+                    pub delegate: Option<&'a ::pinocchio::account_info::AccountInfo>,
+                }
+                impl<'a> CreateAccounts<'a> {
+                    pub fn context(
+                        accounts: &'a [::pinocchio::account_info::AccountInfo],
+                    ) -> Result<Context<'a, Self>, ::pinocchio::program_error::ProgramError> {
+                        if accounts.len() < 2usize {
+                            return Err(::pinocchio::program_error::ProgramError::NotEnoughAccountKeys);
+                        }
+                        Ok(Context {
+                            accounts: Self {
+                                vault: &accounts[0usize],
+                                delegate: if accounts[1usize].key() == &crate::ID {
+                                    None
+                                } else {
+                                    Some(&accounts[1usize])
+                                },
+                            },
+                            remaining_accounts: &accounts[2usize..],
+                        })
+                    }
+                    #[doc = r" Creates the context after verifying that the accounts are signers and writable"]
+                    #[doc = r" as declared by the instruction, naming the first account which isn't."]
+                    pub fn context_checked(
+                        accounts: &'a [::pinocchio::account_info::AccountInfo],
+                    ) -> Result<Context<'a, Self>, ContextError> {
+                        if accounts.len() < 2usize {
+                            let names: [&'static str; 2usize] = ["vault", "delegate"];
+                            return Err(ContextError::new(
+                                names[accounts.len()],
+                                ::pinocchio::program_error::ProgramError::NotEnoughAccountKeys,
+                            ));
+                        }
+                        if !accounts[0usize].is_writable() {
+                            return Err(ContextError::new(
+                                "vault",
+                                ::pinocchio::program_error::ProgramError::InvalidAccountData,
+                            ));
+                        }
+                        Ok(Context {
+                            accounts: Self {
+                                vault: &accounts[0usize],
+                                delegate: if accounts[1usize].key() == &crate::ID {
+                                    None
+                                } else {
+                                    Some(&accounts[1usize])
+                                },
+                            },
+                            remaining_accounts: &accounts[2usize..],
+                        })
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn context_impl_without_accounts() {
+    let code = quote! {
+        #[derive(ShankContext)]
+        pub enum VaultInstruction {
+            Ping,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            pub mod accounts {
+                use super::*;
+                pub struct Context<'a, T> {
+                    pub accounts: T,
+                    pub remaining_accounts: &'a [::solana_program::account_info::AccountInfo<'a>],
+                }
+                pub struct PingAccounts;
+                impl PingAccounts {
+                    pub fn context<'a>(
+                        accounts: &'a [::solana_program::account_info::AccountInfo<'a>],
+                    ) -> Result<Context<'a, Self>, ::solana_program::program_error::ProgramError> {
+                        Ok(Context {
+                            accounts: Self,
+                            remaining_accounts: accounts,
+                        })
+                    }
+                }
+            }
+        },
+    )
+}
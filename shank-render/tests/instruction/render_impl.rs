@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_macro_impl::{
+    instruction::Instruction,
+    syn::{self, ItemEnum},
+};
+use shank_render::instruction::render_instruction_data_impl;
+
+use crate::utils;
+
+fn render_impl(code: TokenStream) -> TokenStream {
+    let item_enum = syn::parse2::<ItemEnum>(code).expect("Should parse enum");
+    let instruction = Instruction::try_from_item_enum(&item_enum, true)
+        .expect("Should parse instruction")
+        .unwrap();
+    render_instruction_data_impl(&instruction).unwrap()
+}
+
+#[allow(unused)]
+fn render_and_dump(code: &TokenStream) {
+    let rendered = render_impl(code.clone());
+    eprintln!("{}", utils::pretty_print(rendered));
+}
+
+fn assert_rendered_impl_fn(code: TokenStream, expected: TokenStream) {
+    let rendered = render_impl(code);
+    assert_eq!(utils::pretty_print(rendered), utils::pretty_print(expected));
+}
+
+#[test]
+fn instruction_data_impl() {
+    let code = quote! {
+        #[derive(ShankInstruction)]
+        #[shank(instruction_data)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            CreateVault(VaultKind),
+            #[account(0, writable, name = "vault")]
+            Deposit { amount: u64, memo: Option<String> },
+            #[account(0, writable, name = "vault")]
+            Close = 5,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl VaultInstruction {
+                #[doc = " The discriminant of the `CreateVault` instruction."]
+                pub const CREATE_VAULT_DISCRIMINANT: u8 = 0u8;
+                #[doc = " The discriminant of the `Deposit` instruction."]
+                pub const DEPOSIT_DISCRIMINANT: u8 = 1u8;
+                #[doc = " The discriminant of the `Close` instruction."]
+                pub const CLOSE_DISCRIMINANT: u8 = 5u8;
+                /// The discriminant the data of the instruction starts with.
+                pub fn discriminant(&self) -> u8 {
+                    match self {
+                        VaultInstruction::CreateVault(..) => Self::CREATE_VAULT_DISCRIMINANT,
+                        VaultInstruction::Deposit { .. } => Self::DEPOSIT_DISCRIMINANT,
+                        VaultInstruction::Close => Self::CLOSE_DISCRIMINANT,
+                    }
+                }
+                /// The data of the instruction, i.e. its discriminant followed by the
+                /// borsh serialized args.
+                pub fn data(&self) -> Vec<u8> {
+                    let mut data = vec![self.discriminant()];
+                    match self {
+                        VaultInstruction::CreateVault(args) => {
+                            ::borsh::BorshSerialize::serialize(args, &mut data)
+                                .expect("serializing into a Vec does not fail");
+                        }
+                        VaultInstruction::Deposit { amount, memo } => {
+                            ::borsh::BorshSerialize::serialize(amount, &mut data)
+                                .expect("serializing into a Vec does not fail");
+                            ::borsh::BorshSerialize::serialize(memo, &mut data)
+                                .expect("serializing into a Vec does not fail");
+                        }
+                        VaultInstruction::Close => {}
+                    }
+                    data
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn instruction_data_impl_without_args() {
+    let code = quote! {
+        #[derive(ShankInstruction)]
+        #[shank(instruction_data)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            Close,
+        }
+    };
+    let rendered = render_impl(code).to_string().replace(' ', "");
+    assert!(rendered
+        .contains("pubfndata(&self)->Vec<u8>{vec![self.discriminant()]}"));
+}
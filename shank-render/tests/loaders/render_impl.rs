@@ -0,0 +1,275 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_render::loaders::render_loaders_impl;
+
+use crate::utils;
+
+fn render_impl(code: TokenStream) -> TokenStream {
+    let (struct_ident, struct_attrs) = utils::parse_struct_attrs(code);
+    render_loaders_impl(&struct_attrs, &struct_ident).unwrap()
+}
+
+#[allow(unused)]
+fn render_and_dump(code: &TokenStream) {
+    let rendered = render_impl(code.clone());
+    eprintln!("{}", utils::pretty_print(rendered));
+}
+
+fn assert_rendered_impl_fn(code: TokenStream, expected: TokenStream) {
+    let rendered = render_impl(code);
+    assert_eq!(utils::pretty_print(rendered), utils::pretty_print(expected));
+}
+
+#[test]
+fn account_without_loaders() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds("lit:prefix")]
+        struct AccountStruct {
+            count: u8,
+        }
+    };
+    assert!(render_impl(code).is_empty());
+}
+
+#[test]
+fn default_loaders_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(loaders)]
+        struct AccountStruct {
+            count: u8,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl AccountStruct {
+                /// Verifies that the account is owned by this program and deserializes its data.
+                #[allow(unused)]
+                pub fn from_account_info(
+                    account: &::solana_program::account_info::AccountInfo,
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    if account.owner != &crate::ID {
+                        return Err(::solana_program::program_error::ProgramError::IllegalOwner);
+                    }
+                    let data = account.try_borrow_data()?;
+                    Self::from_bytes(&data)
+                }
+                /// Verifies the length and discriminator of the data and deserializes it,
+                /// ignoring any trailing bytes.
+                #[allow(unused)]
+                pub fn from_bytes(
+                    data: &[u8],
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    ::borsh::BorshDeserialize::deserialize(&mut &data[..])
+                        .map_err(|_| ::solana_program::program_error::ProgramError::InvalidAccountData)
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn loaders_with_discriminator_and_seeds_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(loaders(discriminator = b"vlt", min_len = 36))]
+        #[seeds(
+            "vault",
+            program_id,
+            owner("The owner of the vault"),
+            id("The id of the vault", u8),
+        )]
+        struct Vault {
+            key: [u8; 3],
+            owner: Pubkey,
+            id: u8,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl Vault {
+                /// Verifies that the account is owned by this program and deserializes its data.
+                #[allow(unused)]
+                pub fn from_account_info(
+                    account: &::solana_program::account_info::AccountInfo,
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    if account.owner != &crate::ID {
+                        return Err(::solana_program::program_error::ProgramError::IllegalOwner);
+                    }
+                    let data = account.try_borrow_data()?;
+                    Self::from_bytes(&data)
+                }
+                /// Verifies the length and discriminator of the data and deserializes it,
+                /// ignoring any trailing bytes.
+                #[allow(unused)]
+                pub fn from_bytes(
+                    data: &[u8],
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    if data.len() < 36 {
+                        return Err(::solana_program::program_error::ProgramError::AccountDataTooSmall);
+                    }
+                    if data[..3] != [118, 108, 116] {
+                        return Err(::solana_program::program_error::ProgramError::InvalidAccountData);
+                    }
+                    ::borsh::BorshDeserialize::deserialize(&mut &data[..])
+                        .map_err(|_| ::solana_program::program_error::ProgramError::InvalidAccountData)
+                }
+                /// Verifies that the account is the PDA derived from the provided seeds and
+                /// returns its bump.
+                #[allow(unused)]
+                pub fn verify_pda(
+                    account: &::solana_program::account_info::AccountInfo,
+                    owner: &::solana_program::pubkey::Pubkey,
+                    id: u8,
+                ) -> Result<u8, ::solana_program::program_error::ProgramError> {
+                    let (pda, bump) = Self::shank_pda(&crate::ID, owner, id);
+                    if account.key != &pda {
+                        return Err(::solana_program::program_error::ProgramError::InvalidSeeds);
+                    }
+                    Ok(bump)
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn loaders_with_foreign_program_seeds_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(loaders)]
+        #[seeds(owner("The owner"), program = spl_associated_token_account::ID)]
+        struct TokenAccount {
+            owner: Pubkey,
+        }
+    };
+    let rendered = render_impl(code).to_string().replace(' ', "");
+    // The program id isn't provided since the PDA is derived for the foreign program
+    assert!(rendered.contains(
+        "owner:&::solana_program::pubkey::Pubkey)->Result<u8,::solana_program::program_error::ProgramError>{let(pda,bump)=Self::shank_pda(owner);"
+    ));
+}
+
+#[test]
+fn loaders_with_seeds_bump_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(loaders)]
+        #[seeds("vault", owner("The owner"), bump = bump)]
+        struct Vault {
+            owner: Pubkey,
+            bump: u8,
+        }
+    };
+    let rendered = render_impl(code).to_string().replace(' ', "");
+    // The PDA is verified via the stored bump instead of searching for it
+    assert!(rendered.contains(
+        "pubfnverify_pda(&self,account:&::solana_program::account_info::AccountInfo,owner:&::solana_program::pubkey::Pubkey)->Result<u8,::solana_program::program_error::ProgramError>{if!self.shank_verify_stored_pda(account.key,&crate::ID,owner){returnErr(::solana_program::program_error::ProgramError::InvalidSeeds);}Ok(self.bump)}"
+    ));
+    assert!(!rendered.contains("shank_pda("));
+}
+
+#[test]
+fn zero_copy_loaders_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(zero_copy, loaders(discriminator = 2))]
+        #[repr(C)]
+        struct Market {
+            key: u8,
+            authority: Pubkey,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl Market {
+                /// Verifies that the account is owned by this program and deserializes its data.
+                #[allow(unused)]
+                pub fn from_account_info(
+                    account: &::solana_program::account_info::AccountInfo,
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    if account.owner != &crate::ID {
+                        return Err(::solana_program::program_error::ProgramError::IllegalOwner);
+                    }
+                    let data = account.try_borrow_data()?;
+                    Self::from_bytes(&data)
+                }
+                /// Verifies the length and discriminator of the data and deserializes it,
+                /// ignoring any trailing bytes.
+                #[allow(unused)]
+                pub fn from_bytes(
+                    data: &[u8],
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    if data.len() < 1 {
+                        return Err(::solana_program::program_error::ProgramError::AccountDataTooSmall);
+                    }
+                    if data[..1] != [2] {
+                        return Err(::solana_program::program_error::ProgramError::InvalidAccountData);
+                    }
+                    let len = ::core::mem::size_of::<Self>();
+                    if data.len() < len {
+                        return Err(::solana_program::program_error::ProgramError::AccountDataTooSmall);
+                    }
+                    ::bytemuck::try_pod_read_unaligned(&data[..len])
+                        .map_err(|_| ::solana_program::program_error::ProgramError::InvalidAccountData)
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn pinocchio_loaders_with_seeds_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(loaders, crate_paths(preset = "pinocchio"))]
+        #[seeds("vault", owner("The owner of the vault"))]
+        struct Vault {
+            owner: Pubkey,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl Vault {
+                /// Verifies that the account is owned by this program and deserializes its data.
+                #[allow(unused)]
+                pub fn from_account_info(
+                    account: &::pinocchio::account_info::AccountInfo,
+                ) -> Result<Self, ::pinocchio::program_error::ProgramError> {
+                    if !account.is_owned_by(&crate::ID) {
+                        return Err(::pinocchio::program_error::ProgramError::IllegalOwner);
+                    }
+                    let data = account.try_borrow_data()?;
+                    Self::from_bytes(&data)
+                }
+                /// Verifies the length and discriminator of the data and deserializes it,
+                /// ignoring any trailing bytes.
+                #[allow(unused)]
+                pub fn from_bytes(
+                    data: &[u8],
+                ) -> Result<Self, ::pinocchio::program_error::ProgramError> {
+                    ::borsh::BorshDeserialize::deserialize(&mut &data[..])
+                        .map_err(|_| ::pinocchio::program_error::ProgramError::InvalidAccountData)
+                }
+                /// Verifies that the account is the PDA derived from the provided seeds and
+                /// returns its bump.
+                #[allow(unused)]
+                pub fn verify_pda(
+                    account: &::pinocchio::account_info::AccountInfo,
+                    owner: &::pinocchio::pubkey::Pubkey,
+                ) -> Result<u8, ::pinocchio::program_error::ProgramError> {
+                    let (pda, bump) = Self::shank_pda(&crate::ID, owner);
+                    if account.key() != &pda {
+                        return Err(::pinocchio::program_error::ProgramError::InvalidSeeds);
+                    }
+                    Ok(bump)
+                }
+            }
+        },
+    )
+}
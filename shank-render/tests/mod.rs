@@ -1,2 +1,7 @@
+mod builder;
+mod context;
+mod instruction;
+mod loaders;
 mod pda;
+mod processor;
 mod utils;
@@ -1,3 +1,5 @@
+mod render_comments;
 mod render_impl;
 mod render_pda_fn;
+mod render_seeds_assertions;
 mod render_seeds_fn;
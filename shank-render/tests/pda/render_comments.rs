@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_macro_impl::syn::{self, ImplItem, ItemImpl};
+use shank_render::pda::render_pda_and_seeds_impl;
+
+use crate::utils;
+
+fn render_commented_impl(code: TokenStream) -> ItemImpl {
+    let (struct_ident, struct_attrs) = utils::parse_struct_attrs(code);
+    let rendered =
+        render_pda_and_seeds_impl(&struct_attrs, &struct_ident, true).unwrap();
+    syn::parse2::<ItemImpl>(rendered)
+        .expect("commented impl should be valid Rust")
+}
+
+#[test]
+fn commented_impl_renders_doc_attributes() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            "lit:prefix",
+            program_id,
+            some_pubkey("description of some pubkey"),
+            some_byte("description of byte", u8),
+        )]
+        struct AccountStructWithSeed {
+            count: u8,
+        }
+    };
+    let item_impl = render_commented_impl(code);
+
+    let fns = item_impl
+        .items
+        .iter()
+        .map(|item| match item {
+            ImplItem::Method(method) => method,
+            _ => panic!("should only render fns"),
+        })
+        .collect::<Vec<_>>();
+    assert!(!fns.is_empty());
+    for method in fns {
+        let docs = method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .count();
+        assert!(docs > 0, "{} should be documented", method.sig.ident);
+    }
+}
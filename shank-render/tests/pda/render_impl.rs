@@ -83,7 +83,32 @@ fn literal_pubkeys_and_u8_byte_impl() {
                     some_pubkey: &::solana_program::pubkey::Pubkey,
                     some_byte: u8,
                     bump: u8,
+                ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                    let some_byte_arg = &[some_byte];
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(
+                        program_id,
+                        some_pubkey,
+                        some_byte_arg,
+                        bump_arg,
+                    );
+                    let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda(
+                    some_pubkey: &::solana_program::pubkey::Pubkey,
+                    some_byte: u8,
                 ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    Self::shank_pda(&crate::ID, some_pubkey, some_byte)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    some_pubkey: &::solana_program::pubkey::Pubkey,
+                    some_byte: u8,
+                    bump: u8,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
                     let some_byte_arg = &[some_byte];
                     let bump_arg = &[bump];
                     let seeds = Self::shank_seeds_with_bump(
@@ -92,7 +117,20 @@ fn literal_pubkeys_and_u8_byte_impl() {
                         some_byte_arg,
                         bump_arg,
                     );
-                    ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                    ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_program::pubkey::Pubkey,
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    some_pubkey: &::solana_program::pubkey::Pubkey,
+                    some_byte: u8,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(program_id, some_pubkey, some_byte, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
                 }
             }
         },
@@ -156,7 +194,37 @@ fn candy_guard_mint_limit_impl() {
                     candy_guard_key: &::solana_program::pubkey::Pubkey,
                     candy_machine_key: &::solana_program::pubkey::Pubkey,
                     bump: u8,
+                ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                    let id_arg = &[id];
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(
+                        id_arg,
+                        user,
+                        candy_guard_key,
+                        candy_machine_key,
+                        bump_arg,
+                    );
+                    let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda(
+                    id: u8,
+                    user: &::solana_program::pubkey::Pubkey,
+                    candy_guard_key: &::solana_program::pubkey::Pubkey,
+                    candy_machine_key: &::solana_program::pubkey::Pubkey,
                 ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    Self::shank_pda(&crate::ID, id, user, candy_guard_key, candy_machine_key)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    id: u8,
+                    user: &::solana_program::pubkey::Pubkey,
+                    candy_guard_key: &::solana_program::pubkey::Pubkey,
+                    candy_machine_key: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
                     let id_arg = &[id];
                     let bump_arg = &[bump];
                     let seeds = Self::shank_seeds_with_bump(
@@ -166,7 +234,22 @@ fn candy_guard_mint_limit_impl() {
                         candy_machine_key,
                         bump_arg,
                     );
-                    ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                    ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_program::pubkey::Pubkey,
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    id: u8,
+                    user: &::solana_program::pubkey::Pubkey,
+                    candy_guard_key: &::solana_program::pubkey::Pubkey,
+                    candy_machine_key: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(program_id, id, user, candy_guard_key, candy_machine_key, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
                 }
             }
         },
@@ -197,6 +280,87 @@ fn literal_pubkeys_and_u8_byte_impl_commented() {
     render_and_dump_commented(&code);
 }
 
+#[test]
+fn solana_sdk_split_crate_paths_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(crate_paths(preset = "solana_sdk_split"))]
+        #[seeds("vault", program_id, owner("The owner of the vault"))]
+        struct Vault {
+            owner: Pubkey,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl Vault {
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds<'a>(
+                    program_id: &'a ::solana_pubkey::Pubkey,
+                    owner: &'a ::solana_pubkey::Pubkey,
+                ) -> [&'a [u8]; 3usize] {
+                    [b"vault", program_id.as_ref(), owner.as_ref()]
+                }
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds_with_bump<'a>(
+                    program_id: &'a ::solana_pubkey::Pubkey,
+                    owner: &'a ::solana_pubkey::Pubkey,
+                    bump: &'a [u8; 1],
+                ) -> [&'a [u8]; 4usize] {
+                    [b"vault", program_id.as_ref(), owner.as_ref(), bump]
+                }
+                #[allow(unused)]
+                pub fn shank_pda(
+                    program_id: &::solana_pubkey::Pubkey,
+                    owner: &::solana_pubkey::Pubkey,
+                ) -> (::solana_pubkey::Pubkey, u8) {
+                    let seeds = Self::shank_seeds(program_id, owner);
+                    ::solana_pubkey::Pubkey::find_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_pda_with_bump(
+                    program_id: &::solana_pubkey::Pubkey,
+                    owner: &::solana_pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<(::solana_pubkey::Pubkey, u8), ::solana_pubkey::PubkeyError> {
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(program_id, owner, bump_arg);
+                    let pda = ::solana_pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda(
+                    owner: &::solana_pubkey::Pubkey,
+                ) -> (::solana_pubkey::Pubkey, u8) {
+                    Self::shank_pda(&crate::ID, owner)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    program_id: &::solana_pubkey::Pubkey,
+                    owner: &::solana_pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<::solana_pubkey::Pubkey, ::solana_pubkey::PubkeyError> {
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(program_id, owner, bump_arg);
+                    ::solana_pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_pubkey::Pubkey,
+                    program_id: &::solana_pubkey::Pubkey,
+                    owner: &::solana_pubkey::Pubkey,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(program_id, owner, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
+                }
+            }
+        },
+    )
+}
+
 // -----------------
 // Edge Cases
 // -----------------
@@ -255,11 +419,319 @@ fn struct_with_one_seed_impl() {
                 pub fn shank_pda_with_bump(
                     program_id: &::solana_program::pubkey::Pubkey,
                     bump: u8,
-                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(bump_arg);
+                    let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda() -> (::solana_program::pubkey::Pubkey, u8) {
+                    Self::shank_pda(&crate::ID)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
                     let bump_arg = &[bump];
                     let seeds = Self::shank_seeds_with_bump(bump_arg);
+                    ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_program::pubkey::Pubkey,
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(program_id, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn foreign_program_path_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            wallet("The owner of the token account"),
+            token_program("The token program"),
+            mint("The mint of the token account"),
+            program = spl_associated_token_account::ID,
+        )]
+        struct AssociatedTokenAccount {
+            count: u8,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl AssociatedTokenAccount {
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds<'a>(
+                    wallet: &'a ::solana_program::pubkey::Pubkey,
+                    token_program: &'a ::solana_program::pubkey::Pubkey,
+                    mint: &'a ::solana_program::pubkey::Pubkey,
+                ) -> [&'a [u8]; 3usize] {
+                    [wallet.as_ref(), token_program.as_ref(), mint.as_ref()]
+                }
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds_with_bump<'a>(
+                    wallet: &'a ::solana_program::pubkey::Pubkey,
+                    token_program: &'a ::solana_program::pubkey::Pubkey,
+                    mint: &'a ::solana_program::pubkey::Pubkey,
+                    bump: &'a [u8; 1],
+                ) -> [&'a [u8]; 4usize] {
+                    [wallet.as_ref(), token_program.as_ref(), mint.as_ref(), bump]
+                }
+                #[allow(unused)]
+                pub fn shank_pda(
+                    wallet: &::solana_program::pubkey::Pubkey,
+                    token_program: &::solana_program::pubkey::Pubkey,
+                    mint: &::solana_program::pubkey::Pubkey,
+                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    let program_id = &spl_associated_token_account::ID;
+                    let seeds = Self::shank_seeds(wallet, token_program, mint);
+                    ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_pda_with_bump(
+                    wallet: &::solana_program::pubkey::Pubkey,
+                    token_program: &::solana_program::pubkey::Pubkey,
+                    mint: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                    let program_id = &spl_associated_token_account::ID;
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(wallet, token_program, mint, bump_arg);
+                    let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda(
+                    wallet: &::solana_program::pubkey::Pubkey,
+                    token_program: &::solana_program::pubkey::Pubkey,
+                    mint: &::solana_program::pubkey::Pubkey,
+                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    Self::shank_pda(wallet, token_program, mint)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    wallet: &::solana_program::pubkey::Pubkey,
+                    token_program: &::solana_program::pubkey::Pubkey,
+                    mint: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
+                    let program_id = &spl_associated_token_account::ID;
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(wallet, token_program, mint, bump_arg);
+                    ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_program::pubkey::Pubkey,
+                    wallet: &::solana_program::pubkey::Pubkey,
+                    token_program: &::solana_program::pubkey::Pubkey,
+                    mint: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(wallet, token_program, mint, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn foreign_program_address_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            "metadata",
+            program_id,
+            mint("The mint of the metadata"),
+            program = "11111111111111111111111111111112",
+        )]
+        struct Metadata {
+            count: u8,
+        }
+    };
+    let program_bytes = {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        bytes
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl Metadata {
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds<'a>(
+                    program_id: &'a ::solana_program::pubkey::Pubkey,
+                    mint: &'a ::solana_program::pubkey::Pubkey,
+                ) -> [&'a [u8]; 3usize] {
+                    [b"metadata", program_id.as_ref(), mint.as_ref()]
+                }
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds_with_bump<'a>(
+                    program_id: &'a ::solana_program::pubkey::Pubkey,
+                    mint: &'a ::solana_program::pubkey::Pubkey,
+                    bump: &'a [u8; 1],
+                ) -> [&'a [u8]; 4usize] {
+                    [b"metadata", program_id.as_ref(), mint.as_ref(), bump]
+                }
+                #[allow(unused)]
+                pub fn shank_pda(
+                    mint: &::solana_program::pubkey::Pubkey,
+                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    let program_id = &::solana_program::pubkey::Pubkey::new_from_array([#(#program_bytes),*]);
+                    let seeds = Self::shank_seeds(program_id, mint);
+                    ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_pda_with_bump(
+                    mint: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                    let program_id = &::solana_program::pubkey::Pubkey::new_from_array([#(#program_bytes),*]);
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(program_id, mint, bump_arg);
+                    let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda(
+                    mint: &::solana_program::pubkey::Pubkey,
+                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    Self::shank_pda(mint)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    mint: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
+                    let program_id = &::solana_program::pubkey::Pubkey::new_from_array([#(#program_bytes),*]);
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(program_id, mint, bump_arg);
+                    ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_program::pubkey::Pubkey,
+                    mint: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(mint, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn stored_bump_impl() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds("vault", owner("The owner of the vault"), bump = bump)]
+        struct Vault {
+            owner: Pubkey,
+            bump: u8,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl Vault {
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds<'a>(
+                    owner: &'a ::solana_program::pubkey::Pubkey,
+                ) -> [&'a [u8]; 2usize] {
+                    [b"vault", owner.as_ref()]
+                }
+                #[allow(unused, clippy::needless_lifetimes)]
+                pub fn shank_seeds_with_bump<'a>(
+                    owner: &'a ::solana_program::pubkey::Pubkey,
+                    bump: &'a [u8; 1],
+                ) -> [&'a [u8]; 3usize] {
+                    [b"vault", owner.as_ref(), bump]
+                }
+                #[allow(unused)]
+                pub fn shank_pda(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    owner: &::solana_program::pubkey::Pubkey,
+                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    let seeds = Self::shank_seeds(owner);
                     ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
                 }
+                #[allow(unused)]
+                pub fn shank_pda_with_bump(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    owner: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(owner, bump_arg);
+                    let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                    Ok((pda, bump))
+                }
+                #[allow(unused)]
+                pub fn shank_find_pda(
+                    owner: &::solana_program::pubkey::Pubkey,
+                ) -> (::solana_program::pubkey::Pubkey, u8) {
+                    Self::shank_pda(&crate::ID, owner)
+                }
+                #[allow(unused)]
+                pub fn shank_create_pda(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    owner: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
+                    let bump_arg = &[bump];
+                    let seeds = Self::shank_seeds_with_bump(owner, bump_arg);
+                    ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)
+                }
+                #[allow(unused)]
+                pub fn shank_verify_pda(
+                    pda: &::solana_program::pubkey::Pubkey,
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    owner: &::solana_program::pubkey::Pubkey,
+                    bump: u8,
+                ) -> bool {
+                    match Self::shank_create_pda(program_id, owner, bump) {
+                        Ok(address) => &address == pda,
+                        Err(_) => false,
+                    }
+                }
+                #[doc = " Creates the PDA of this account from the bump stored in `bump`."]
+                #[allow(unused)]
+                pub fn shank_stored_pda(
+                    &self,
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    owner: &::solana_program::pubkey::Pubkey,
+                ) -> Result<::solana_program::pubkey::Pubkey, ::solana_program::pubkey::PubkeyError> {
+                    Self::shank_create_pda(program_id, owner, self.bump)
+                }
+                #[doc = " Verifies that the provided PDA was created from the seeds and the bump stored in `bump`."]
+                #[allow(unused)]
+                pub fn shank_verify_stored_pda(
+                    &self,
+                    pda: &::solana_program::pubkey::Pubkey,
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    owner: &::solana_program::pubkey::Pubkey,
+                ) -> bool {
+                    Self::shank_verify_pda(pda, program_id, owner, self.bump)
+                }
             }
         },
     );
@@ -1,6 +1,6 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use shank_macro_impl::syn::Ident;
+use shank_macro_impl::{parsed_struct::CratePaths, syn::Ident};
 use shank_render::pda::render_pda_fn;
 
 use crate::utils;
@@ -19,6 +19,8 @@ fn render_pda(code: TokenStream, include_comments: bool) -> TokenStream {
         &Ident::new("shank_pda", Span::call_site()),
         &Ident::new("shank_pda_with_bump", Span::call_site()),
         include_comments,
+        None,
+        &CratePaths::default(),
     )
     .unwrap()
 }
@@ -69,7 +71,7 @@ fn literal_pubkeys_and_u8_byte_pda() {
                 some_pubkey: &::solana_program::pubkey::Pubkey,
                 some_byte: u8,
                 bump: u8,
-            ) -> (::solana_program::pubkey::Pubkey, u8) {
+            ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
                 let some_byte_arg = &[some_byte];
                 let bump_arg = &[bump];
                 let seeds = Self::shank_seeds_with_bump(
@@ -78,7 +80,8 @@ fn literal_pubkeys_and_u8_byte_pda() {
                     some_byte_arg,
                     bump_arg,
                 );
-                ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                Ok((pda, bump))
             }
         },
     );
@@ -127,7 +130,7 @@ fn candy_guard_edition_marker_pda() {
                 edition: &str,
                 edition_marker_number: &String,
                 bump: u8,
-            ) -> (::solana_program::pubkey::Pubkey, u8) {
+            ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
                 let bump_arg = &[bump];
                 let seeds = Self::shank_seeds_with_bump(
                     prefix,
@@ -137,7 +140,8 @@ fn candy_guard_edition_marker_pda() {
                     edition_marker_number,
                     bump_arg,
                 );
-                ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                Ok((pda, bump))
             }
         },
     );
@@ -180,7 +184,7 @@ fn candy_guard_mint_limit_pda() {
                 candy_guard_key: &::solana_program::pubkey::Pubkey,
                 candy_machine_key: &::solana_program::pubkey::Pubkey,
                 bump: u8,
-            ) -> (::solana_program::pubkey::Pubkey, u8) {
+            ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
                 let id_arg = &[id];
                 let bump_arg = &[bump];
                 let seeds = Self::shank_seeds_with_bump(
@@ -190,7 +194,8 @@ fn candy_guard_mint_limit_pda() {
                     candy_machine_key,
                     bump_arg,
                 );
-                ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+                let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                Ok((pda, bump))
             }
         },
     );
@@ -222,11 +227,118 @@ fn struct_with_one_literal_pda_fn() {
             pub fn shank_pda_with_bump(
                 program_id: &::solana_program::pubkey::Pubkey,
                 bump: u8,
-            ) -> (::solana_program::pubkey::Pubkey, u8) {
+            ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
                 let bump_arg = &[bump];
                 let seeds = Self::shank_seeds_with_bump(bump_arg);
+                let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                Ok((pda, bump))
+            }
+        },
+    );
+}
+
+#[test]
+fn integer_string_and_enum_pda() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            "escrow",
+            index("The index", u16),
+            amount("The amount", u64),
+            offset("The offset", i64),
+            label("The label", str),
+            role("The role", state::Role),
+        )]
+        struct Escrow {
+            count: u8,
+        }
+    };
+    assert_rendered_pda_fn(
+        code,
+        quote! {
+            #[allow(unused)]
+            pub fn shank_pda(
+                program_id: &::solana_program::pubkey::Pubkey,
+                index: u16,
+                amount: u64,
+                offset: i64,
+                label: &str,
+                role: state::Role,
+            ) -> (::solana_program::pubkey::Pubkey, u8) {
+                let index_arg = &index.to_le_bytes();
+                let amount_arg = &amount.to_le_bytes();
+                let offset_arg = &offset.to_le_bytes();
+                let role_arg = &[role as u8];
+                let seeds = Self::shank_seeds(index_arg, amount_arg, offset_arg, label, role_arg);
                 ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
             }
+            #[allow(unused)]
+            pub fn shank_pda_with_bump(
+                program_id: &::solana_program::pubkey::Pubkey,
+                index: u16,
+                amount: u64,
+                offset: i64,
+                label: &str,
+                role: state::Role,
+                bump: u8,
+            ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                let index_arg = &index.to_le_bytes();
+                let amount_arg = &amount.to_le_bytes();
+                let offset_arg = &offset.to_le_bytes();
+                let role_arg = &[role as u8];
+                let bump_arg = &[bump];
+                let seeds = Self::shank_seeds_with_bump(
+                    index_arg,
+                    amount_arg,
+                    offset_arg,
+                    label,
+                    role_arg,
+                    bump_arg,
+                );
+                let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                Ok((pda, bump))
+            }
+        },
+    );
+}
+
+#[test]
+fn fully_qualified_pubkey_pda() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            "vault",
+            owner("The owner", solana_program::pubkey::Pubkey),
+            mint_info("The mint", solana_program::account_info::AccountInfo),
+        )]
+        struct Vault {
+            count: u8,
+        }
+    };
+    assert_rendered_pda_fn(
+        code,
+        quote! {
+            #[allow(unused)]
+            pub fn shank_pda(
+                program_id: &::solana_program::pubkey::Pubkey,
+                owner: &::solana_program::pubkey::Pubkey,
+                mint_info: &::solana_program::account_info::AccountInfo,
+            ) -> (::solana_program::pubkey::Pubkey, u8) {
+                let seeds = Self::shank_seeds(owner, mint_info);
+                ::solana_program::pubkey::Pubkey::find_program_address(&seeds, program_id)
+            }
+            #[allow(unused)]
+            pub fn shank_pda_with_bump(
+                program_id: &::solana_program::pubkey::Pubkey,
+                owner: &::solana_program::pubkey::Pubkey,
+                mint_info: &::solana_program::account_info::AccountInfo,
+                bump: u8,
+            ) -> Result<(::solana_program::pubkey::Pubkey, u8), ::solana_program::pubkey::PubkeyError> {
+                let bump_arg = &[bump];
+                let seeds = Self::shank_seeds_with_bump(owner, mint_info, bump_arg);
+                let pda = ::solana_program::pubkey::Pubkey::create_program_address(&seeds, program_id)?;
+                Ok((pda, bump))
+            }
         },
     );
 }
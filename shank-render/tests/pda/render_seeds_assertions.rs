@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_render::pda::render_seeds_assertions_impl;
+
+use crate::utils;
+
+fn assert_rendered_assertions(code: TokenStream, expected: TokenStream) {
+    let (struct_ident, struct_attrs) = utils::parse_struct_attrs(code);
+    let rendered =
+        render_seeds_assertions_impl(&struct_attrs, &struct_ident).unwrap();
+    assert_eq!(utils::pretty_print(rendered), utils::pretty_print(expected));
+}
+
+#[test]
+fn fixed_size_seeds_assertions() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            "vault",
+            program_id,
+            owner("The owner"),
+            name("The name", String),
+            index("The index", u64),
+            role("The role", state::Role),
+        )]
+        struct Vault {
+            count: u8,
+        }
+    };
+    assert_rendered_assertions(
+        code,
+        quote! {
+            const _: () = {
+                assert!(
+                    ::core::mem::size_of::<::solana_program::pubkey::Pubkey>() <= 32usize,
+                    "The owner seed of Vault needs to fit into 32 bytes"
+                );
+                assert!(
+                    ::core::mem::size_of::<u64>() <= 8usize,
+                    "The index seed of Vault needs to fit into 8 bytes"
+                );
+                assert!(
+                    ::core::mem::size_of::<state::Role>() <= 1usize,
+                    "The role seed of Vault needs to fit into a single byte"
+                );
+            };
+        },
+    );
+}
+
+#[test]
+fn crate_paths_pubkey_seed_assertions() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[shank(pinocchio)]
+        #[seeds("vault", owner("The owner"))]
+        struct Vault {
+            count: u8,
+        }
+    };
+    assert_rendered_assertions(
+        code,
+        quote! {
+            const _: () = {
+                assert!(
+                    ::core::mem::size_of::<::pinocchio::pubkey::Pubkey>() <= 32usize,
+                    "The owner seed of Vault needs to fit into 32 bytes"
+                );
+            };
+        },
+    );
+}
+
+#[test]
+fn variable_size_seeds_without_assertions() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds("vault", program_id, name("The name", String))]
+        struct Vault {
+            count: u8,
+        }
+    };
+    assert_rendered_assertions(code, TokenStream::new());
+}
@@ -1,7 +1,7 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use shank_macro_impl::syn::Ident;
+use shank_macro_impl::{parsed_struct::CratePaths, syn::Ident};
 use shank_render::pda::try_render_seeds_fn;
 
 use crate::utils;
@@ -18,6 +18,7 @@ fn render_seeds(code: TokenStream) -> TokenStream {
         &Ident::new("shank_seeds", Span::call_site()),
         &Ident::new("shank_seeds_with_bump", Span::call_site()),
         false,
+        &CratePaths::default(),
     )
     .expect("Should render seeds")
     .unwrap()
@@ -197,3 +198,50 @@ fn struct_with_one_literal_seeds_fn() {
         },
     );
 }
+
+#[test]
+fn integer_string_and_enum_seeds() {
+    let code = quote! {
+        #[derive(ShankAccount)]
+        #[seeds(
+            "escrow",
+            index("The index", u16),
+            epoch("The epoch", u32),
+            amount("The amount", u64),
+            offset("The offset", i64),
+            label("The label", String),
+            role("The role", state::Role),
+        )]
+        struct Escrow {
+            count: u8,
+        }
+    };
+    assert_rendered_seeds_fn(
+        code,
+        quote! {
+            #[allow(unused, clippy::needless_lifetimes)]
+            pub fn shank_seeds<'a>(
+                index: &'a [u8; 2usize],
+                epoch: &'a [u8; 4usize],
+                amount: &'a [u8; 8usize],
+                offset: &'a [u8; 8usize],
+                label: &'a String,
+                role: &'a [u8; 1usize],
+            ) -> [&'a [u8]; 7usize] {
+                [b"escrow", index, epoch, amount, offset, label.as_bytes(), role]
+            }
+            #[allow(unused, clippy::needless_lifetimes)]
+            pub fn shank_seeds_with_bump<'a>(
+                index: &'a [u8; 2usize],
+                epoch: &'a [u8; 4usize],
+                amount: &'a [u8; 8usize],
+                offset: &'a [u8; 8usize],
+                label: &'a String,
+                role: &'a [u8; 1usize],
+                bump: &'a [u8; 1],
+            ) -> [&'a [u8]; 8usize] {
+                [b"escrow", index, epoch, amount, offset, label.as_bytes(), role, bump]
+            }
+        },
+    );
+}
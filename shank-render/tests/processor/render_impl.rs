@@ -0,0 +1,168 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use shank_macro_impl::{
+    instruction::Instruction,
+    syn::{self, ItemEnum, Result as ParseResult},
+};
+use shank_render::processor::render_processor_impl;
+
+use crate::utils;
+
+fn render_impl(code: TokenStream) -> ParseResult<TokenStream> {
+    let item_enum = syn::parse2::<ItemEnum>(code).expect("Should parse enum");
+    let instruction = Instruction::try_from_item_enum(&item_enum, true)
+        .expect("Should parse instruction")
+        .unwrap();
+    render_processor_impl(&instruction)
+}
+
+#[allow(unused)]
+fn render_and_dump(code: &TokenStream) {
+    let rendered = render_impl(code.clone()).unwrap();
+    eprintln!("{}", utils::pretty_print(rendered));
+}
+
+fn assert_rendered_impl_fn(code: TokenStream, expected: TokenStream) {
+    let rendered = render_impl(code).unwrap();
+    assert_eq!(utils::pretty_print(rendered), utils::pretty_print(expected));
+}
+
+#[test]
+fn processor_impl() {
+    let code = quote! {
+        #[derive(ShankInstruction)]
+        #[shank(processor, context_checked)]
+        pub enum VaultInstruction {
+            #[account(0, writable, name = "vault")]
+            #[account(1, signer, name = "owner")]
+            Deposit { amount: u64, memo: Option<String> },
+            #[account(0, writable, name = "vault")]
+            Close(VaultKind),
+            #[account(0, name = "vault")]
+            Ping,
+        }
+    };
+    assert_rendered_impl_fn(
+        code,
+        quote! {
+            impl VaultInstruction {
+                /// Unpacks the instruction from its data, i.e. the discriminant of the
+                /// variant followed by its borsh serialized args.
+                pub fn unpack(
+                    data: &[u8],
+                ) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                    let (discriminant, mut data) = data
+                        .split_first()
+                        .ok_or(::solana_program::program_error::ProgramError::InvalidInstructionData)?;
+                    let instruction = match discriminant {
+                        0u8 => Self::Deposit {
+                            amount: <u64 as ::borsh::BorshDeserialize>::deserialize(&mut data)
+                                .map_err(|_| ::solana_program::program_error::ProgramError::InvalidInstructionData)?,
+                            memo: <Option<String> as ::borsh::BorshDeserialize>::deserialize(&mut data)
+                                .map_err(|_| ::solana_program::program_error::ProgramError::InvalidInstructionData)?
+                        },
+                        1u8 => Self::Close(
+                            <VaultKind as ::borsh::BorshDeserialize>::deserialize(&mut data)
+                                .map_err(|_| ::solana_program::program_error::ProgramError::InvalidInstructionData)?
+                        ),
+                        2u8 => Self::Ping,
+                        _ => return Err(::solana_program::program_error::ProgramError::InvalidInstructionData),
+                    };
+                    if !data.is_empty() {
+                        return Err(::solana_program::program_error::ProgramError::InvalidInstructionData);
+                    }
+                    Ok(instruction)
+                }
+            }
+            pub mod processor {
+                use super::*;
+                /// Processes the instructions of the program, each method receives the
+                /// accounts and the args of its instruction.
+                #[allow(clippy::too_many_arguments)]
+                pub trait Processor {
+                    fn process_deposit<'a>(
+                        program_id: &::solana_program::pubkey::Pubkey,
+                        ctx: accounts::Context<'a, accounts::DepositAccounts<'a>>,
+                        amount: u64,
+                        memo: Option<String>,
+                    ) -> Result<(), ::solana_program::program_error::ProgramError>;
+                    fn process_close<'a>(
+                        program_id: &::solana_program::pubkey::Pubkey,
+                        ctx: accounts::Context<'a, accounts::CloseAccounts<'a>>,
+                        args: VaultKind,
+                    ) -> Result<(), ::solana_program::program_error::ProgramError>;
+                    fn process_ping<'a>(
+                        program_id: &::solana_program::pubkey::Pubkey,
+                        ctx: accounts::Context<'a, accounts::PingAccounts<'a>>,
+                    ) -> Result<(), ::solana_program::program_error::ProgramError>;
+                }
+                /// Unpacks the instruction data and invokes the matching method of the
+                /// processor with the accounts of the instruction.
+                pub fn dispatch<'a, P: Processor>(
+                    program_id: &::solana_program::pubkey::Pubkey,
+                    accounts: &'a [::solana_program::account_info::AccountInfo<'a>],
+                    data: &[u8],
+                ) -> Result<(), ::solana_program::program_error::ProgramError> {
+                    match VaultInstruction::unpack(data)? {
+                        VaultInstruction::Deposit { amount, memo } => P::process_deposit(
+                            program_id,
+                            accounts::DepositAccounts::context_checked(accounts)?,
+                            amount,
+                            memo,
+                        ),
+                        VaultInstruction::Close(args) => P::process_close(
+                            program_id,
+                            accounts::CloseAccounts::context_checked(accounts)?,
+                            args,
+                        ),
+                        VaultInstruction::Ping => P::process_ping(
+                            program_id,
+                            accounts::PingAccounts::context_checked(accounts)?,
+                        ),
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn processor_impl_without_args() {
+    let code = quote! {
+        #[derive(ShankInstruction)]
+        #[shank(pinocchio, processor)]
+        pub enum VaultInstruction {
+            #[account(0, name = "vault")]
+            Ping,
+            Noop,
+        }
+    };
+    let rendered = render_impl(code).unwrap().to_string().replace(' ', "");
+    // data is only read mutably when args are deserialized
+    assert!(rendered.contains("let(discriminant,data)=data"));
+    assert!(rendered
+        .contains("accounts:&'a[::pinocchio::account_info::AccountInfo],"));
+    // the checked context is opt-in via #[shank(context_checked)]
+    assert!(rendered.contains("accounts::PingAccounts::context(accounts)?"));
+    // variants without accounts have a context without lifetime
+    assert!(
+        rendered.contains("ctx:accounts::Context<'a,accounts::NoopAccounts>,")
+    );
+}
+
+#[test]
+fn processor_impl_with_discriminant_exceeding_u8() {
+    let code = quote! {
+        #[derive(ShankInstruction)]
+        #[shank(processor)]
+        pub enum VaultInstruction {
+            #[account(0, name = "vault")]
+            Ping = 256,
+        }
+    };
+    let err = render_impl(code).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Instruction variant discriminants have to be <= u8::MAX (255), but the discriminant of variant 'Ping' is 256"
+    );
+}
@@ -0,0 +1,159 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::{ShankAccount, ShankBuilder};
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+solana_program::declare_id!("Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8");
+
+#[derive(ShankAccount)]
+#[seeds(
+    "vault",
+    owner("The owner of the vault"),
+    kind("The kind of vault", u8)
+)]
+pub struct Vault {
+    pub owner: Pubkey,
+}
+
+#[derive(ShankAccount)]
+#[seeds(
+    "metadata",
+    program_id,
+    mint("The mint of the metadata"),
+    program = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+)]
+pub struct Metadata {
+    pub mint: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, ShankBuilder)]
+pub enum VaultInstruction {
+    #[account(0, writable, name = "vault")]
+    #[account(1, optional, writable, name = "delegate")]
+    #[account(2, optional_signer, name = "payer")]
+    Withdraw(u64),
+    #[account(0, writable, name = "vault")]
+    #[args(additional_accounts: Vec<AccountMeta>)]
+    Create(u64),
+    #[account(0, writable, name = "vault", pda = Vault, seeds(owner, kind))]
+    #[account(1, signer, name = "owner")]
+    Open { kind: u8 },
+    #[account(0, writable, name = "metadata", pda = Metadata, seeds(mint))]
+    #[account(1, name = "mint")]
+    CreateMetadata,
+}
+
+use builders::{
+    CreateCpi, CreateMetadataBuilder, InstructionBuilder, OpenBuilder,
+    WithdrawCpi,
+};
+
+/// Invokes the CPI structs with account infos borrowed for less than their
+/// lifetime as programs do when handed a slice of them.
+fn cpi_instructions<'a>(infos: &[AccountInfo<'a>]) -> Vec<Instruction> {
+    let withdraw = WithdrawCpi {
+        __program: &infos[0],
+        vault: &infos[1],
+        delegate: None,
+        payer: &infos[2],
+        payer_signer: true,
+        args: 42,
+    };
+    let create = CreateCpi {
+        __program: &infos[0],
+        vault: &infos[1],
+        args: 7,
+    };
+    vec![withdraw.instruction(), create.instruction()]
+}
+
+#[test]
+fn cpi_instructions_match_instruction_data() {
+    let keys = [ID, Pubkey::new_unique(), Pubkey::new_unique()];
+    let owner = Pubkey::new_unique();
+    let mut lamports = [0u64; 3];
+    let mut data = [[0u8; 0]; 3];
+    let infos = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(
+                key, false, false, lamports, data, &owner, false, 0,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let instructions = cpi_instructions(&infos);
+    assert_eq!(
+        instructions[0].accounts,
+        vec![
+            AccountMeta::new(keys[1], false),
+            AccountMeta::new_readonly(ID, false),
+            AccountMeta::new_readonly(keys[2], true),
+        ]
+    );
+    assert_eq!(
+        instructions[0].data,
+        VaultInstruction::Withdraw(42).try_to_vec().unwrap()
+    );
+    assert_eq!(
+        instructions[1].accounts,
+        vec![AccountMeta::new(keys[1], false)]
+    );
+    assert_eq!(
+        instructions[1].data,
+        VaultInstruction::Create(7).try_to_vec().unwrap()
+    );
+}
+
+#[test]
+fn builder_derives_pda_of_named_variant() {
+    let owner = Pubkey::new_unique();
+    let instruction = OpenBuilder::new()
+        .owner(owner)
+        .build(3)
+        .expect("Builder should have all accounts")
+        .instruction();
+
+    let (vault, _) = Vault::shank_pda(&ID, &owner, 3);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(owner, true),
+        ]
+    );
+    assert_eq!(
+        instruction.data,
+        VaultInstruction::Open { kind: 3 }.try_to_vec().unwrap()
+    );
+}
+
+#[test]
+fn builder_derives_pda_of_foreign_program() {
+    let mint = Pubkey::new_unique();
+    let instruction = CreateMetadataBuilder::new()
+        .mint(mint)
+        .build()
+        .expect("Builder should have all accounts")
+        .instruction();
+
+    let metadata_program =
+        solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+    let (metadata, _) = Pubkey::find_program_address(
+        &[b"metadata", metadata_program.as_ref(), mint.as_ref()],
+        &metadata_program,
+    );
+    assert_eq!(Metadata::shank_pda(&mint).0, metadata);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(mint, false),
+        ]
+    );
+}
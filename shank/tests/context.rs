@@ -0,0 +1,77 @@
+use shank::ShankContext;
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+};
+
+solana_program::declare_id!("Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8");
+
+#[derive(ShankContext)]
+#[shank(context_checked)]
+pub enum VaultInstruction {
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "authority")]
+    #[account(2, optional, writable, name = "delegate")]
+    Create,
+}
+
+use accounts::{ContextError, CreateAccounts};
+
+/// Creates the checked context of the first `len` accounts, the delegate is
+/// omitted by passing the program id, and returns whether it was omitted.
+fn create_context_checked(
+    vault_writable: bool,
+    authority_signer: bool,
+    len: usize,
+) -> Result<bool, ContextError> {
+    let keys = [Pubkey::new_unique(), Pubkey::new_unique(), ID];
+    let owner = Pubkey::new_unique();
+    let mut lamports = [0u64; 3];
+    let mut data = [[0u8; 0]; 3];
+    let infos = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .enumerate()
+        .map(|(idx, ((key, lamports), data))| {
+            AccountInfo::new(
+                key,
+                idx == 1 && authority_signer,
+                idx == 0 && vault_writable,
+                lamports,
+                data,
+                &owner,
+                false,
+                0,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let ctx = CreateAccounts::context_checked(&infos[..len])?;
+    assert_eq!(ctx.accounts.vault.key, &keys[0]);
+    assert!(ctx.remaining_accounts.is_empty());
+    Ok(ctx.accounts.delegate.is_none())
+}
+
+#[test]
+fn context_checked_validates_accounts() {
+    assert_eq!(
+        create_context_checked(true, true, 2),
+        Err(ContextError::new(
+            "delegate",
+            ProgramError::NotEnoughAccountKeys
+        ))
+    );
+    assert_eq!(
+        create_context_checked(false, true, 3),
+        Err(ContextError::new("vault", ProgramError::InvalidAccountData))
+    );
+    assert_eq!(
+        create_context_checked(true, false, 3),
+        Err(ContextError::new(
+            "authority",
+            ProgramError::MissingRequiredSignature
+        ))
+    );
+    // the omitted optional delegate isn't checked
+    assert_eq!(create_context_checked(true, true, 3), Ok(true));
+}
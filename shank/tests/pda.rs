@@ -0,0 +1,56 @@
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+solana_program::declare_id!("Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8");
+
+#[derive(ShankAccount)]
+#[seeds(
+    "vault",
+    owner("The owner of the vault"),
+    kind("The kind of vault", u8),
+    bump = bump
+)]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub bump: u8,
+}
+
+#[test]
+fn pdas_created_from_bump_match_derived_pda() {
+    let owner = Pubkey::new_unique();
+    let (pda, bump) = Vault::shank_pda(&ID, &owner, 3);
+
+    assert_eq!(Vault::shank_find_pda(&owner, 3), (pda, bump));
+    assert_eq!(
+        Vault::shank_pda_with_bump(&ID, &owner, 3, bump),
+        Ok((pda, bump))
+    );
+    assert_eq!(Vault::shank_create_pda(&ID, &owner, 3, bump), Ok(pda));
+    assert!(Vault::shank_verify_pda(&pda, &ID, &owner, 3, bump));
+
+    assert!(!Vault::shank_verify_pda(&pda, &ID, &owner, 4, bump));
+    assert!(!Vault::shank_verify_pda(
+        &Pubkey::new_unique(),
+        &ID,
+        &owner,
+        3,
+        bump
+    ));
+}
+
+#[test]
+fn pdas_created_from_stored_bump_match_derived_pda() {
+    let owner = Pubkey::new_unique();
+    let (pda, bump) = Vault::shank_pda(&ID, &owner, 3);
+    let vault = Vault { owner, bump };
+
+    assert_eq!(vault.shank_stored_pda(&ID, &owner, 3), Ok(pda));
+    assert!(vault.shank_verify_stored_pda(&pda, &ID, &owner, 3));
+    assert!(!vault.shank_verify_stored_pda(&pda, &ID, &owner, 4));
+
+    let other_bump = Vault {
+        owner,
+        bump: bump.wrapping_sub(1),
+    };
+    assert!(!other_bump.shank_verify_stored_pda(&pda, &ID, &owner, 3));
+}
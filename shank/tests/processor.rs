@@ -0,0 +1,90 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::{ShankContext, ShankInstruction};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+solana_program::declare_id!("Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8");
+
+#[derive(BorshSerialize, BorshDeserialize, ShankContext, ShankInstruction)]
+#[shank(processor)]
+pub enum VaultInstruction {
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "owner")]
+    Deposit {
+        amount: u64,
+    },
+    Ping,
+}
+
+struct Vault;
+impl processor::Processor for Vault {
+    fn process_deposit<'a>(
+        _program_id: &Pubkey,
+        ctx: accounts::Context<'a, accounts::DepositAccounts<'a>>,
+        amount: u64,
+    ) -> ProgramResult {
+        msg!("{} deposits {}", ctx.accounts.owner.key, amount);
+        if amount == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn process_ping<'a>(
+        _program_id: &Pubkey,
+        ctx: accounts::Context<'a, accounts::PingAccounts>,
+    ) -> ProgramResult {
+        if ctx.remaining_accounts.is_empty() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(())
+    }
+}
+
+fn process_instruction<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
+    processor::dispatch::<Vault>(program_id, accounts, data)
+}
+
+/// Dispatches the instruction to the processor given `len` accounts.
+fn dispatch(instruction: VaultInstruction, len: usize) -> ProgramResult {
+    let keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut lamports = [0u64; 2];
+    let mut data = [[0u8; 0]; 2];
+    let infos = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, false, true, lamports, data, &ID, false, 0)
+        })
+        .collect::<Vec<_>>();
+
+    let data = instruction.try_to_vec().unwrap();
+    process_instruction(&ID, &infos[..len], &data)
+}
+
+#[test]
+fn dispatch_invokes_processor() {
+    assert_eq!(dispatch(VaultInstruction::Deposit { amount: 1 }, 2), Ok(()));
+    assert_eq!(
+        dispatch(VaultInstruction::Deposit { amount: 0 }, 2),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert_eq!(
+        dispatch(VaultInstruction::Deposit { amount: 1 }, 1),
+        Err(ProgramError::NotEnoughAccountKeys)
+    );
+
+    // variants without accounts pass on all accounts as remaining accounts
+    assert_eq!(dispatch(VaultInstruction::Ping, 1), Ok(()));
+    assert_eq!(
+        dispatch(VaultInstruction::Ping, 0),
+        Err(ProgramError::NotEnoughAccountKeys)
+    );
+}